@@ -0,0 +1,40 @@
+use crate::game::Achievement;
+
+/// 进度条宽度 (字符数)
+const PROGRESS_BAR_WIDTH: u32 = 10;
+
+pub struct AchievementMenu;
+
+impl AchievementMenu {
+    /// 显示所有成就：已解锁的打 ✓，其余显示进度条
+    pub fn display_achievements(achievements: &[(Achievement, (u32, u32), bool)]) {
+        let unlocked = achievements.iter().filter(|(_, _, unlocked)| *unlocked).count();
+        println!("\n╔════════════════════════════════════════╗");
+        println!("║ {:<38} ║", format!("🏆 成就  ({}/{})", unlocked, achievements.len()));
+        println!("╠════════════════════════════════════════╣");
+        for (achievement, (current, target), unlocked) in achievements {
+            let mark = if *unlocked { "✓" } else { " " };
+            println!("║ {:<38} ║", format!("{} {}", mark, achievement.name));
+            println!("║ {:<38} ║", format!("   {}", achievement.description));
+            println!("║ {:<38} ║", format!("   {}", Self::progress_bar(*current, *target)));
+        }
+        println!("╚════════════════════════════════════════╝");
+    }
+
+    /// 显示新解锁的成就
+    pub fn display_unlocked(achievement: &Achievement) {
+        println!("\n🏆 解锁成就「{}」！{}", achievement.name, achievement.description);
+    }
+
+    /// 形如 `[██████░░░░] 6/10` 的进度条
+    fn progress_bar(current: u32, target: u32) -> String {
+        let filled = (current.min(target) * PROGRESS_BAR_WIDTH).checked_div(target).unwrap_or(PROGRESS_BAR_WIDTH);
+        format!(
+            "[{}{}] {}/{}",
+            "█".repeat(filled as usize),
+            "░".repeat((PROGRESS_BAR_WIDTH - filled) as usize),
+            current,
+            target
+        )
+    }
+}
@@ -0,0 +1,56 @@
+use crate::game::{BagSort, Item, Pocket};
+use std::io::{self, Write};
+
+pub struct BagMenu;
+
+impl BagMenu {
+    /// 显示背包中的一个口袋：口袋标签、排序方式、道具与说明
+    pub fn display_pocket(pocket: Pocket, sort: BagSort, items: &[(Item, u32)], money: u32) {
+        let tabs: Vec<String> = Pocket::ALL
+            .iter()
+            .map(|p| if *p == pocket { format!("[{}]", p.name()) } else { p.name().to_string() })
+            .collect();
+        println!("\n╔════════════════════════════════════════╗");
+        println!("║ {:<38} ║", format!("🎒 背包  (持有: ¥{})", money));
+        println!("║ {:<38} ║", tabs[..4].join(" "));
+        println!("║ {:<38} ║", tabs[4..].join(" "));
+        println!("╠════════════════════════════════════════╣");
+        if items.is_empty() {
+            println!("║ (这个口袋是空的)                       ║");
+        }
+        for (i, (item, count)) in items.iter().enumerate() {
+            println!("║ {:<38} ║", format!("{}. {} x{}", i + 1, item.name, count));
+            println!("║ {:<38} ║", format!("   {}", item.description));
+        }
+        println!("╠════════════════════════════════════════╣");
+        println!("║ {:<38} ║", format!("[A][D] 切换口袋  [S] 排序: 按{}", sort.name()));
+        println!("║ 0. 返回                                ║");
+        println!("╚════════════════════════════════════════╝");
+        print!("请选择: ");
+        io::stdout().flush().unwrap();
+    }
+
+    /// 选中道具后的操作
+    pub fn display_item_actions(item: &Item, count: u32) {
+        println!("\n{} x{}", item.name, count);
+        println!("{}", item.description);
+        println!("1. 使用");
+        if item.can_toss() {
+            println!("2. 丢弃");
+        }
+        println!("0. 返回");
+        print!("请选择: ");
+        io::stdout().flush().unwrap();
+    }
+
+    /// 询问丢弃的数量
+    pub fn prompt_toss_quantity(item_name: &str, count: u32) {
+        print!("要丢掉多少个 {}？(1-{}，0 取消): ", item_name, count);
+        io::stdout().flush().unwrap();
+    }
+
+    /// 显示现在不能使用的道具
+    pub fn display_cannot_use(item_name: &str) {
+        println!("\n现在不能使用 {}。", item_name);
+    }
+}
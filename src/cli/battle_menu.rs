@@ -16,7 +16,7 @@ impl BattleMenu {
     pub fn display_opponent_pokemon(battle: &Battle) {
         if let Some(opponent) = battle.get_opponent_pokemon() {
             let status = if opponent.is_fainted() { "[昏迷]" } else { "" };
-            println!("对手的宝可梦: {} (Lv.{}) {}", opponent.name, opponent.level, status);
+            println!("对手的宝可梦: {} (Lv.{}) {}", opponent.display_label(), opponent.level, status);
             BattleMenu::display_hp_bar(&opponent, false);
         }
     }
@@ -25,7 +25,7 @@ impl BattleMenu {
     pub fn display_player_pokemon(battle: &Battle) {
         if let Some(player) = battle.get_player_pokemon() {
             let status = if player.is_fainted() { "[昏迷]" } else { "" };
-            println!("你的宝可梦: {} (Lv.{}) {}", player.name, player.level, status);
+            println!("你的宝可梦: {} (Lv.{}) {}", player.display_label(), player.level, status);
             BattleMenu::display_hp_bar(&player, true);
         }
     }
@@ -69,7 +69,7 @@ impl BattleMenu {
             println!("\n--- 选择招式 ---");
             for (i, mv) in player.moves.iter().enumerate() {
                 let pp_color = if mv.pp == 0 { "❌" } else { "✓" };
-                println!("{}: {} (Pow:{}, PP:{}/{}) {}", i + 1, mv.name, mv.power, mv.pp, mv.max_pp, pp_color);
+                println!("{}: {} (Pow:{}, PP:{}/{}) {}", i + 1, mv.name, player.move_power(mv), mv.pp, mv.max_pp, pp_color);
             }
             println!("0. 返回");
             print!("选择 (0-{}): ", player.moves.len());
@@ -78,19 +78,12 @@ impl BattleMenu {
     }
 
     /// 显示道具菜单
-    pub fn display_item_menu(items: &std::collections::HashMap<String, u32>) {
+    pub fn display_item_menu(recovery_items: &[(crate::game::Item, u32)]) {
         println!("\n--- 可用的道具 ---");
 
-        let recovery_items = vec!["恢复药", "超级恢复药", "全复活", "完全恢复"];
-        let mut item_count = 0;
-
-        for (_i, item_name) in recovery_items.iter().enumerate() {
-            if let Some(count) = items.get(*item_name) {
-                if *count > 0 {
-                    item_count += 1;
-                    println!("{}: {} x{}", item_count, item_name, count);
-                }
-            }
+        let item_count = recovery_items.len();
+        for (i, (item, count)) in recovery_items.iter().enumerate() {
+            println!("{}: {} x{}", i + 1, item.name, count);
         }
 
         if item_count == 0 {
@@ -111,7 +104,7 @@ impl BattleMenu {
             if !pokemon.is_fainted() && i != battle.player_current_index {
                 valid_count += 1;
                 let status = if pokemon.is_fainted() { "[昏迷]" } else { "✓" };
-                println!("{}: {} (Lv.{}) {}", valid_count, pokemon.name, pokemon.level, status);
+                println!("{}: {} (Lv.{}) {}", valid_count, pokemon.display_label(), pokemon.level, status);
             }
         }
 
@@ -167,11 +160,11 @@ impl BattleMenu {
         println!("战斗状态: {:?}", battle.status);
 
         if let Some(player) = battle.get_player_pokemon() {
-            println!("你的宝可梦: {} (HP: {}/{})", player.name, player.hp, player.max_hp);
+            println!("你的宝可梦: {} (HP: {}/{})", player.display_label(), player.hp, player.max_hp);
         }
 
         if let Some(opponent) = battle.get_opponent_pokemon() {
-            println!("对手宝可梦: {} (HP: {}/{})", opponent.name, opponent.hp, opponent.max_hp);
+            println!("对手宝可梦: {} (HP: {}/{})", opponent.display_label(), opponent.hp, opponent.max_hp);
         }
     }
 
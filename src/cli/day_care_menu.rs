@@ -0,0 +1,81 @@
+use crate::game::{DayCare, Egg, Item, Pokemon};
+use std::io::{self, Write};
+
+pub struct DayCareMenu;
+
+impl DayCareMenu {
+    /// 显示饲育屋：寄放的宝可梦、相性以及等待领取的蛋
+    pub fn display_day_care_menu(day_care: &DayCare, eggs: &[Egg]) {
+        println!("\n╔════════════════════════════════════╗");
+        println!("║ 🏡 饲育屋                          ║");
+        println!("╠════════════════════════════════════╣");
+        if day_care.slots.is_empty() {
+            println!("║ (没有寄放的宝可梦)                 ║");
+        }
+        for slot in &day_care.slots {
+            println!("║ {:<34} ║", format!("{} Lv.{}", slot.pokemon.display_label(), slot.pokemon.level));
+            if let Some(item) = slot.pokemon.held_item.and_then(Item::get) {
+                println!("║ {:<34} ║", format!("   携带: {}", item.name));
+            }
+        }
+        if day_care.slots.len() == 2 {
+            println!("║ {:<34} ║", Self::compatibility_message(day_care.egg_chance()));
+        }
+        if day_care.egg.is_some() {
+            println!("║ 🥚 有一个蛋在等你领取！            ║");
+        }
+        for egg in eggs {
+            println!("║ {:<34} ║", format!("🥚 携带的蛋: {}", egg.description()));
+        }
+        println!("╠════════════════════════════════════╣");
+        println!("║ 1. 寄放宝可梦                      ║");
+        println!("║ 2. 领回宝可梦                      ║");
+        println!("║ 3. 领取蛋                          ║");
+        println!("║ 0. 返回                            ║");
+        println!("╚════════════════════════════════════╝");
+        print!("请选择: ");
+        io::stdout().flush().unwrap();
+    }
+
+    /// 两只宝可梦的相性描述
+    pub fn compatibility_message(egg_chance: u32) -> &'static str {
+        match egg_chance {
+            0 => "两只宝可梦似乎更喜欢和别的宝可梦玩",
+            1..=49 => "两只宝可梦相处得还不错",
+            _ => "两只宝可梦相处得很融洽",
+        }
+    }
+
+    /// 选择要领回的宝可梦
+    pub fn display_withdraw_menu(day_care: &DayCare) {
+        println!("\n要领回哪只宝可梦？");
+        for (i, slot) in day_care.slots.iter().enumerate() {
+            println!("{}. {} Lv.{}", i + 1, slot.pokemon.display_label(), slot.pokemon.level);
+        }
+        println!("0. 返回");
+        print!("请选择: ");
+        io::stdout().flush().unwrap();
+    }
+
+    /// 选择寄放时携带的道具
+    pub fn display_held_item_menu(pokemon: &Pokemon, items: &[(Item, u32)]) {
+        println!("\n要让 {} 携带道具吗？", pokemon.display_name());
+        for (i, (item, count)) in items.iter().enumerate() {
+            println!("{}. {} x{}", i + 1, item.name, count);
+        }
+        println!("0. 不给新的道具");
+        print!("请选择: ");
+        io::stdout().flush().unwrap();
+    }
+
+    /// 移动时饲育屋里出现了蛋
+    pub fn display_egg_found() {
+        println!("\n📣 饲育屋传来消息：在寄放的宝可梦身边发现了一个蛋！");
+    }
+
+    /// 蛋孵化了
+    pub fn display_egg_hatched(pokemon: &Pokemon) {
+        println!("\n咦？蛋动了一下……");
+        println!("🐣 {} 从蛋里孵出来了！", pokemon.display_label());
+    }
+}
@@ -0,0 +1,32 @@
+use std::io::{self, Write};
+
+pub struct EvolutionMenu;
+
+impl EvolutionMenu {
+    /// 显示进化提示，返回玩家是否同意进化 (输入 n 取消)
+    pub fn confirm_evolution(pokemon_name: &str, target_name: &str) -> bool {
+        println!("\n╔════════════════════════════════════════╗");
+        println!("║ {:<38} ║", format!("咦？{} 的样子……", pokemon_name));
+        println!("╠════════════════════════════════════════╣");
+        println!("║ {:<38} ║", format!("{} 要进化成 {} 了！", pokemon_name, target_name));
+        println!("║ 1. 进化                                ║");
+        println!("║ n. 取消进化                            ║");
+        println!("╚════════════════════════════════════════╝");
+        print!("选择: ");
+        io::stdout().flush().unwrap();
+
+        let mut input = String::new();
+        io::stdin().read_line(&mut input).unwrap();
+        !matches!(input.trim(), "n" | "N" | "0")
+    }
+
+    /// 显示进化结果
+    pub fn display_evolution_result(message: &str) {
+        println!("\n✨ {}", message);
+    }
+
+    /// 显示取消进化
+    pub fn display_evolution_cancelled(pokemon_name: &str) {
+        println!("\n{} 停止了进化。", pokemon_name);
+    }
+}
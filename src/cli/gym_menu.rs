@@ -0,0 +1,56 @@
+use crate::game::BadgeInfo;
+use crate::npc::TrainerData;
+use std::io::{self, Write};
+
+pub struct GymMenu;
+
+impl GymMenu {
+    /// 显示道馆中的馆主以及是否已经击败
+    pub fn display_gym_menu(location_name: &str, leaders: &[TrainerData], defeated: &[bool]) {
+        println!("\n╔════════════════════════════════════╗");
+        println!("║ {:<34} ║", format!("🏟 {} 道馆", location_name));
+        println!("╠════════════════════════════════════╣");
+        for (i, (leader, done)) in leaders.iter().zip(defeated).enumerate() {
+            let mark = if *done { "✓" } else { " " };
+            println!("║ {:<34} ║", format!("{}. {} {}", i + 1, mark, leader.name));
+            if let Some(badge_id) = leader.badge_id {
+                println!("║ {:<34} ║", format!("   徽章: {}", BadgeInfo::name_of(badge_id)));
+            }
+        }
+        println!("║ 0. 离开                            ║");
+        println!("╚════════════════════════════════════╝");
+        print!("请选择挑战的馆主: ");
+        io::stdout().flush().unwrap();
+    }
+
+    /// 显示没有馆主的道馆
+    pub fn display_no_leader(location_name: &str) {
+        println!("\n{} 的道馆里没有馆主。", location_name);
+    }
+
+    /// 显示馆主的挑战宣言
+    pub fn display_challenge(leader: &TrainerData) {
+        println!("\n{} 接受了你的挑战！", leader.name);
+    }
+
+    /// 显示击败馆主后的奖励
+    pub fn display_victory(leader: &TrainerData, money: u32) {
+        println!("\n🎉 你击败了 {}！", leader.name);
+        if money > 0 {
+            println!("获得了 ¥{}！", money);
+        }
+    }
+
+    /// 显示获得的徽章及其效果
+    pub fn display_badge_awarded(badge: &BadgeInfo) {
+        println!("\n🏅 获得了 {}！", badge.name);
+        for effect in badge.effect_descriptions() {
+            println!("  • {}", effect);
+        }
+    }
+
+    /// 显示挑战失败
+    pub fn display_defeat(leader: &TrainerData) {
+        println!("\n你输给了 {}……", leader.name);
+    }
+}
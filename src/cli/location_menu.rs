@@ -1,5 +1,5 @@
 use std::io::{self, Write};
-use crate::game::{Location, Player, WildPokemonPreview};
+use crate::game::{quest, BadgeInfo, EncounterMethod, Location, Player, WildPokemonPreview};
 
 pub struct LocationMenu;
 
@@ -14,6 +14,7 @@ impl LocationMenu {
         println!("╠─────────────────────────────────────╣");
         println!("║ 描述: {:<28} ║", location.description);
         println!("║ 环境: {:<28} ║", location.environment_name());
+        println!("║ 时间: {:<28} ║", player.clock.display());
         println!("║ 遭遇率: {:<25} ║", format!("{}%", (location.encounter_rate * 100.0) as u32));
 
         // 显示连接的地点
@@ -74,10 +75,10 @@ impl LocationMenu {
             println!("║ {} 等级: {}/{:<25} ║", status, max_level, requirement.required_level);
         }
 
-        if !requirement.required_badges.is_empty() {
+        for &badge_id in &requirement.required_badges {
             has_requirement = true;
-            let status = if player.badges.len() >= requirement.required_badges.len() { "✓" } else { "✗" };
-            println!("║ {} 徽章: {}/{:<24} ║", status, player.badges.len(), requirement.required_badges.len());
+            let status = if player.has_badge(badge_id) { "✓" } else { "✗" };
+            println!("║ {} 徽章: {:<27} ║", status, BadgeInfo::name_of(badge_id));
         }
 
         if let Some(required_count) = requirement.required_pokemon_count {
@@ -86,6 +87,12 @@ impl LocationMenu {
             println!("║ {} 宝可梦: {}/{:<22} ║", status, player.pokemons.len(), required_count);
         }
 
+        for flag in &requirement.required_flags {
+            has_requirement = true;
+            let status = if player.quests.has_flag(flag) { "✓" } else { "✗" };
+            println!("║ {} 任务: {:<27} ║", status, quest::flag_source(flag));
+        }
+
         if !has_requirement {
             println!("║ 无条件 (已可访问)                  ║");
         }
@@ -120,6 +127,11 @@ impl LocationMenu {
         println!("\n❌ 无法移动: {}", reason);
     }
 
+    /// 显示在地点获得的道具
+    pub fn show_item_received(location_name: &str, item_name: &str) {
+        println!("🎁 在 {} 获得了 {}！", location_name, item_name);
+    }
+
     /// 显示成功移动信息
     pub fn show_movement_success(location_name: &str) {
         println!("\n✓ 成功前往 {}!", location_name);
@@ -140,6 +152,25 @@ impl LocationMenu {
         io::stdout().flush().unwrap();
     }
 
+    /// 显示当前地点可以使用的遭遇方式
+    pub fn display_encounter_methods(methods: &[EncounterMethod]) {
+        println!("\n╔─────────────────────────────────────╗");
+        println!("║     选择寻找宝可梦的方式           ║");
+        println!("╠─────────────────────────────────────╣");
+        for (idx, method) in methods.iter().enumerate() {
+            println!("║ {}. {:<32} ║", idx + 1, method.name());
+        }
+        println!("║ 0. 返回                            ║");
+        println!("╚─────────────────────────────────────╝");
+        print!("请选择: ");
+        io::stdout().flush().unwrap();
+    }
+
+    /// 显示驱虫喷雾或引虫香水的效果结束
+    pub fn show_effect_expired(item_name: &str) {
+        println!("\n{} 的效果消失了。", item_name);
+    }
+
     /// 显示没有可达地点信息
     pub fn show_no_reachable_locations() {
         println!("\n⚠ 当前地点没有相邻的地点可以前往。");
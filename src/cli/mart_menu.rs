@@ -0,0 +1,69 @@
+use crate::game::mart::{self, MAX_QUANTITY};
+use crate::game::Item;
+use std::io::{self, Write};
+
+pub struct MartMenu;
+
+impl MartMenu {
+    /// 显示友好商店主菜单
+    pub fn display_mart_menu(location_name: &str, money: u32) {
+        println!("\n╔════════════════════════════════════╗");
+        println!("║ {:<34} ║", format!("🏪 {} 友好商店", location_name));
+        println!("║ {:<34} ║", format!("持有金币: ¥{}", money));
+        println!("╠════════════════════════════════════╣");
+        println!("║ 1. 购买                            ║");
+        println!("║ 2. 卖出                            ║");
+        println!("║ 0. 离开                            ║");
+        println!("╚════════════════════════════════════╝");
+        print!("请选择: ");
+        io::stdout().flush().unwrap();
+    }
+
+    /// 显示货架上的道具与价格
+    pub fn display_buy_menu(items: &[Item], locked_count: usize, money: u32) {
+        println!("\n╔════════════════════════════════════╗");
+        println!("║ {:<34} ║", format!("购买  (持有: ¥{})", money));
+        println!("╠════════════════════════════════════╣");
+        for (i, item) in items.iter().enumerate() {
+            println!("║ {:<34} ║", format!("{}. {} ¥{}", i + 1, item.name, item.price.unwrap_or(0)));
+            println!("║ {:<34} ║", format!("   {}", item.description));
+        }
+        if locked_count > 0 {
+            println!("║ {:<34} ║", format!("🔒 还有 {} 种商品需要更多徽章", locked_count));
+        }
+        println!("║ 0. 返回                            ║");
+        println!("╚════════════════════════════════════╝");
+        print!("请选择: ");
+        io::stdout().flush().unwrap();
+    }
+
+    /// 显示背包中可以卖出的道具与卖出价格
+    pub fn display_sell_menu(items: &[(Item, u32)], money: u32) {
+        println!("\n╔════════════════════════════════════╗");
+        println!("║ {:<34} ║", format!("卖出  (持有: ¥{})", money));
+        println!("╠════════════════════════════════════╣");
+        if items.is_empty() {
+            println!("║ (没有可以卖出的道具)               ║");
+        }
+        for (i, (item, count)) in items.iter().enumerate() {
+            let price = mart::sell_price(item).unwrap_or(0);
+            println!("║ {:<34} ║", format!("{}. {} x{}  ¥{}", i + 1, item.name, count, price));
+        }
+        println!("║ 0. 返回                            ║");
+        println!("╚════════════════════════════════════╝");
+        print!("请选择: ");
+        io::stdout().flush().unwrap();
+    }
+
+    /// 询问数量
+    pub fn prompt_quantity(item_name: &str, unit_price: u32, max: u32) {
+        let max = max.min(MAX_QUANTITY);
+        print!("{} 单价 ¥{}，要多少个？(1-{}，0 取消): ", item_name, unit_price, max);
+        io::stdout().flush().unwrap();
+    }
+
+    /// 显示没有营业的商店
+    pub fn display_empty_mart(location_name: &str) {
+        println!("\n{} 的友好商店暂时没有商品。", location_name);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::game::Pokemon;
 use std::io::{self, Write};
 
 pub struct Menu;
@@ -14,9 +15,19 @@ impl Menu {
         io::stdout().flush().unwrap();
     }
 
-    pub fn print_game_menu_with_location(location_name: &str, visited_count: usize, total_locations: usize) {
+    pub fn print_game_menu_with_location(
+        location_name: &str,
+        time: &str,
+        effects: &[String],
+        visited_count: usize,
+        total_locations: usize,
+    ) {
         println!("\n╔════════════════════════════════════╗");
         println!("║  📍 当前位置: {:<22} ║", location_name);
+        println!("║  🕐 {:<30} ║", time);
+        for effect in effects {
+            println!("║  🧴 {:<30} ║", effect);
+        }
         println!("║  🗺 已访问: {}/{:<20} ║", visited_count, total_locations);
         println!("╠════════════════════════════════════╣");
         println!("║ 1. 探索 (寻找宝可梦)               ║");
@@ -24,7 +35,11 @@ impl Menu {
         println!("║ 3. 查看队伍                        ║");
         println!("║ 4. 查看背包                        ║");
         println!("║ 5. 地图 (地区和对战)               ║");
-        println!("║ 6. 回到大厅                        ║");
+        println!("║ 6. 设施服务                        ║");
+        println!("║ 7. 秘传招式                        ║");
+        println!("║ 8. 宝可梦图鉴                      ║");
+        println!("║ 9. 训练家卡片                      ║");
+        println!("║ 10. 回到大厅                       ║");
         println!("╚════════════════════════════════════╝");
         print!("请选择: ");
         io::stdout().flush().unwrap();
@@ -97,4 +112,32 @@ impl Menu {
         io::stdout().flush().unwrap();
         Menu::get_input()
     }
+
+    /// 选择游戏模式，硬核模式下全队昏迷后不会自动回到宝可梦中心
+    pub fn get_hardcore_mode() -> bool {
+        println!("\n选择游戏模式:");
+        println!("1. 普通模式 (全队昏迷后回到宝可梦中心，损失部分金钱)");
+        println!("2. 硬核模式 (全队昏迷后只能使用道具或付费复活)");
+        print!("请选择 (默认 1): ");
+        io::stdout().flush().unwrap();
+        Menu::get_input() == "2"
+    }
+
+    /// 询问是否为新得到的宝可梦取昵称，直接回车则不取昵称
+    pub fn get_nickname(species_name: &str) -> String {
+        print!("要给 {} 取个昵称吗？(直接回车跳过): ", species_name);
+        io::stdout().flush().unwrap();
+        Menu::get_input()
+    }
+
+    /// 为新得到的宝可梦取昵称，昵称无效时重新输入
+    pub fn name_new_pokemon(pokemon: &mut Pokemon) {
+        loop {
+            let nickname = Menu::get_nickname(&pokemon.name);
+            match pokemon.set_nickname(&nickname) {
+                Ok(()) => return,
+                Err(e) => println!("✗ {}", e),
+            }
+        }
+    }
 }
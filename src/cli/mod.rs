@@ -7,6 +7,17 @@ pub mod team_detail_menu;
 pub mod team_list_menu;
 pub mod pokemon_detail_menu;
 pub mod battle_menu;
+pub mod evolution_menu;
+pub mod move_learn_menu;
+pub mod service_menu;
+pub mod day_care_menu;
+pub mod mart_menu;
+pub mod bag_menu;
+pub mod gym_menu;
+pub mod pokedex_menu;
+pub mod trainer_card_menu;
+pub mod achievement_menu;
+pub mod quest_menu;
 
 pub use menu::Menu;
 // pub use display::print_separator;  // Unused - removed
@@ -17,3 +28,14 @@ pub use location_menu::LocationMenu;
 pub use team_list_menu::TeamListMenu;
 pub use pokemon_detail_menu::PokemonDetailMenu;
 pub use battle_menu::BattleMenu;
+pub use evolution_menu::EvolutionMenu;
+pub use move_learn_menu::MoveLearnMenu;
+pub use service_menu::ServiceMenu;
+pub use day_care_menu::DayCareMenu;
+pub use mart_menu::MartMenu;
+pub use bag_menu::BagMenu;
+pub use gym_menu::GymMenu;
+pub use pokedex_menu::PokedexMenu;
+pub use trainer_card_menu::TrainerCardMenu;
+pub use achievement_menu::AchievementMenu;
+pub use quest_menu::QuestMenu;
@@ -0,0 +1,76 @@
+use crate::game::{Move, Pokemon};
+use std::io::{self, Write};
+
+pub struct MoveLearnMenu;
+
+impl MoveLearnMenu {
+    /// 招式已满时询问要忘记哪个招式，返回招式位置 (放弃学习时返回 None)
+    pub fn choose_move_to_forget(pokemon: &Pokemon, new_move: &Move) -> Option<usize> {
+        println!("\n╔════════════════════════════════════════╗");
+        println!("║ {:<38} ║", format!("{} 想要学习 {}！", pokemon.name, new_move.name));
+        println!("║ {:<38} ║", format!("但是 {} 已经学会了 4 个招式。", pokemon.name));
+        println!("╠════════════════════════════════════════╣");
+        println!("║ 要忘记哪个招式？                       ║");
+        for (i, mv) in pokemon.moves.iter().enumerate() {
+            println!("║ {:<38} ║", format!("{}. {} (Pow:{}, PP:{}/{})", i + 1, mv.name, pokemon.move_power(mv), mv.pp, mv.max_pp));
+        }
+        println!("║ {:<38} ║", format!("新招式: {} (Pow:{}, PP:{})", new_move.name, pokemon.move_power(new_move), new_move.max_pp));
+        println!("║ 0. 放弃学习                            ║");
+        println!("╚════════════════════════════════════════╝");
+
+        loop {
+            print!("选择 (0-{}): ", pokemon.moves.len());
+            io::stdout().flush().unwrap();
+
+            let mut input = String::new();
+            io::stdin().read_line(&mut input).unwrap();
+            match input.trim().parse::<usize>() {
+                Ok(0) => return None,
+                Ok(n) if n <= pokemon.moves.len() => return Some(n - 1),
+                _ => println!("无效的选择，请重试"),
+            }
+        }
+    }
+
+    /// 教宝可梦一个新招式，招式已满时询问要忘记哪个招式，返回是否学会
+    pub fn learn_move(pokemon: &mut Pokemon, new_move: Move) -> bool {
+        let move_name = new_move.name.clone();
+        if pokemon.add_move(new_move.clone()) {
+            Self::display_move_learned(&pokemon.name, &move_name);
+            return true;
+        }
+
+        match Self::choose_move_to_forget(pokemon, &new_move) {
+            Some(index) => match pokemon.replace_move(index, new_move) {
+                Ok(forgotten) => {
+                    Self::display_move_replaced(&pokemon.name, &forgotten.name, &move_name);
+                    true
+                }
+                Err(e) => {
+                    println!("学习招式失败: {}", e);
+                    false
+                }
+            },
+            None => {
+                Self::display_move_skipped(&pokemon.name, &move_name);
+                false
+            }
+        }
+    }
+
+    /// 显示学会新招式
+    pub fn display_move_learned(pokemon_name: &str, move_name: &str) {
+        println!("\n📖 {} 学会了 {}！", pokemon_name, move_name);
+    }
+
+    /// 显示替换招式的结果
+    pub fn display_move_replaced(pokemon_name: &str, forgotten: &str, learned: &str) {
+        println!("\n1、2、3……噗！{} 忘记了 {}！", pokemon_name, forgotten);
+        println!("📖 {} 学会了 {}！", pokemon_name, learned);
+    }
+
+    /// 显示放弃学习
+    pub fn display_move_skipped(pokemon_name: &str, move_name: &str) {
+        println!("\n{} 没有学会 {}。", pokemon_name, move_name);
+    }
+}
@@ -0,0 +1,71 @@
+use crate::game::{Pokedex, PokedexEntry, PokemonType};
+use std::io::{self, Write};
+
+pub struct PokedexMenu;
+
+impl PokedexMenu {
+    /// 显示图鉴列表与完成度：● 捕捉过，○ 见过，没见过的物种显示为 ???
+    pub fn display_pokedex(entries: &[PokedexEntry], pokedex: &Pokedex) {
+        println!("\n╔════════════════════════════════════╗");
+        println!("║ 📖 宝可梦图鉴                      ║");
+        println!(
+            "║ {:<34} ║",
+            format!(
+                "见过: {}  捕捉: {}/{} ({:.1}%)",
+                pokedex.seen.len(),
+                pokedex.caught.len(),
+                entries.len(),
+                pokedex.completion_percent(entries.len())
+            )
+        );
+        println!("╠════════════════════════════════════╣");
+        for (i, entry) in entries.iter().enumerate() {
+            let line = if pokedex.is_caught(entry.species_id) {
+                format!("{}. No.{:03} ● {} {}", i + 1, entry.species_id, entry.name, Self::type_text(entry.types))
+            } else if pokedex.is_seen(entry.species_id) {
+                format!("{}. No.{:03} ○ {} {}", i + 1, entry.species_id, entry.name, Self::type_text(entry.types))
+            } else {
+                format!("{}. No.{:03}   ???", i + 1, entry.species_id)
+            };
+            println!("║ {:<34} ║", line);
+        }
+        println!("║ 0. 返回                            ║");
+        println!("╚════════════════════════════════════╝");
+        print!("选择查看的条目: ");
+        io::stdout().flush().unwrap();
+    }
+
+    /// 显示一个图鉴条目：见过后显示属性与出没地点，捕捉后才显示说明、身高和体重
+    pub fn display_entry(entry: &PokedexEntry, pokedex: &Pokedex) {
+        if !pokedex.is_seen(entry.species_id) {
+            println!("\nNo.{:03} ??? —— 还没有遇到过这只宝可梦。", entry.species_id);
+            return;
+        }
+
+        println!("\n╔════════════════════════════════════════╗");
+        println!("║ {:<38} ║", format!("No.{:03} {}", entry.species_id, entry.name));
+        println!("╠════════════════════════════════════════╣");
+        println!("║ 属性: {:<32} ║", Self::type_text(entry.types));
+        if pokedex.is_caught(entry.species_id) {
+            println!("║ 身高: {:<32} ║", format!("{:.1} m", entry.height));
+            println!("║ 体重: {:<32} ║", format!("{:.1} kg", entry.weight));
+            println!("║ {:<38} ║", entry.description);
+        } else {
+            println!("║ (捕捉后可以查看详细资料)               ║");
+        }
+        println!("╠════════════════════════════════════════╣");
+        if entry.locations.is_empty() {
+            println!("║ 出没地点: 不明                         ║");
+        } else {
+            println!("║ 出没地点: {:<28} ║", entry.locations.join("、"));
+        }
+        println!("╚════════════════════════════════════════╝");
+    }
+
+    fn type_text(types: (PokemonType, Option<PokemonType>)) -> String {
+        match types.1 {
+            Some(second_type) => format!("{:?}/{:?}", types.0, second_type),
+            None => format!("{:?}", types.0),
+        }
+    }
+}
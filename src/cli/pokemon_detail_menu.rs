@@ -6,6 +6,7 @@
 //! # Features
 //! - Basic Pokemon information (ID, type, level, experience)
 //! - All base stats with visual progress bars
+//! - Effort values (EVs) per stat with the total cap
 //! - Move information with PP (Power Points) display
 //! - Capture location, method, and timestamp
 //! - Team navigation footer with keyboard controls
@@ -24,12 +25,14 @@
 use std::io::{self, Write};
 use crate::game::Pokemon;
 use crate::data::locations_data;
+use crate::pokemon_generator::EffortValues;
 
 /// Menu handler for displaying comprehensive Pokemon details
 ///
 /// Provides structured display of all Pokemon information including:
 /// - Basic info (ID, type, level, experience)
 /// - All 6 stats with visual progress bars
+/// - Effort values with per-stat bars
 /// - Moves with type, power, accuracy, and PP
 /// - Capture information (ball type, location, date)
 /// - Navigation controls for team browsing
@@ -47,12 +50,14 @@ impl PokemonDetailMenu {
     /// Displays in order:
     /// 1. Basic info box (name, ID, type, level, experience)
     /// 2. Stats box (all 6 stats with bars)
-    /// 3. Moves box (up to 4 moves with details)
-    /// 4. Capture info box (ball type, location, date)
-    /// 5. Navigation footer
+    /// 3. Effort values box (all 6 EVs with bars and total)
+    /// 4. Moves box (up to 4 moves with details)
+    /// 5. Capture info box (ball type, location, date)
+    /// 6. Navigation footer
     pub fn display_pokemon_detail(pokemon: &Pokemon, team_index: usize, team_size: usize) {
         Self::display_basic_info(pokemon);
         Self::display_stats(pokemon);
+        Self::display_effort_values(pokemon);
         Self::display_moves(pokemon);
         Self::display_capture_info(pokemon);
         Self::display_navigation(team_index, team_size);
@@ -61,15 +66,26 @@ impl PokemonDetailMenu {
     /// Display basic Pokemon information (ID, type, level, experience)
     ///
     /// Shows:
-    /// - Pokemon name and level
+    /// - Pokemon name (nickname, shiny mark, gender) and level
+    /// - Species name when nicknamed, and gender
     /// - National Pokedex ID
     /// - Type (primary and secondary if present)
+    /// - Nature, talent, ability, IV total and friendship readout
     /// - Experience progress toward next level
     fn display_basic_info(pokemon: &Pokemon) {
         println!("\n╔════════════════════════════════════════╗");
-        println!("║ {:<40} ║", format!("{} (Lv. {})", pokemon.name, pokemon.level));
+        println!("║ {:<40} ║", format!("{} (Lv. {})", pokemon.display_label(), pokemon.level));
         println!("╠════════════════════════════════════════╣");
 
+        // Species name is shown separately when the Pokemon has a nickname
+        if pokemon.nickname.is_some() {
+            println!("║ 种类: {:<34} ║", pokemon.name);
+        }
+        println!("║ 性别: {:<34} ║", pokemon.gender.name());
+        if pokemon.is_shiny {
+            println!("║ {:<40} ║", "★ 异色");
+        }
+
         // ID and type info
         println!("║ ID: {:<34} ║", pokemon.id);
         let type_display = match pokemon.pokemon_type.1 {
@@ -77,20 +93,30 @@ impl PokemonDetailMenu {
             None => format!("{:?} / —", pokemon.pokemon_type.0),
         };
         println!("║ 类型: {:<36} ║", type_display);
+        println!("║ 性格: {:<8} 天赋: {:<22} ║", pokemon.nature.name(), pokemon.talent.name());
+        println!("║ 个体值总和: {:<28} ║", format!("{}/186", pokemon.individual_values.total()));
+        println!("║ 亲密度: {:<32} ║", pokemon.friendship_description());
+        if let Some(ability) = crate::game::Ability::of(pokemon) {
+            println!("║ 特性: {:<34} ║", ability.name);
+        }
+        if let Some(item_id) = pokemon.held_item {
+            println!("║ 携带道具: {:<30} ║", crate::game::Item::name_of(item_id));
+        }
 
         // Experience bar
-        let next_level_exp = pokemon.level as u32 * 100;
-        let exp_percent = if next_level_exp > 0 {
-            (pokemon.experience as f32 / next_level_exp as f32 * 100.0) as u32
+        let (exp_into_level, exp_span) = pokemon.experience_progress();
+        let exp_percent = if exp_span > 0 {
+            (exp_into_level as f32 / exp_span as f32 * 100.0) as u32
         } else {
-            0
+            100
         };
         let exp_bar_width = 20;
         let exp_filled = (exp_percent as usize * exp_bar_width / 100).min(exp_bar_width);
         let exp_empty = exp_bar_width - exp_filled;
 
-        println!("║ 经验: {}/{} ({}%)             ║",
-                 pokemon.experience, next_level_exp, exp_percent);
+        println!("║ 经验: {}/{} ({}%) 类型: {:<8} ║",
+                 exp_into_level, exp_span, exp_percent, pokemon.growth_rate.name());
+        println!("║ 距下一级: {:<28} ║", pokemon.experience_to_next_level());
         println!("║ [{}{}]                             ║",
                  "█".repeat(exp_filled),
                  "░".repeat(exp_empty));
@@ -157,6 +183,39 @@ impl PokemonDetailMenu {
                  value);
     }
 
+    /// Display Pokemon effort values with visual progress bars
+    ///
+    /// Each EV is drawn as a 16-character bar scaled to the per-stat cap (252),
+    /// followed by the running total against the overall cap (510).
+    fn display_effort_values(pokemon: &Pokemon) {
+        println!("╔════════════════════════════════════════╗");
+        println!("║              努力值                    ║");
+        println!("╠════════════════════════════════════════╣");
+
+        let evs = &pokemon.effort_values;
+        for stat in EffortValues::STAT_KEYS {
+            let value = evs.get(stat);
+            println!("║ {:<6} {} {:<14} ║",
+                     EffortValues::stat_name(stat),
+                     Self::get_ev_bar(value),
+                     value);
+        }
+        println!("║ 总计: {:<32} ║", format!("{}/{}", evs.total(), EffortValues::MAX_TOTAL));
+
+        println!("╚════════════════════════════════════════╝");
+    }
+
+    /// Generate an EV progress bar scaled to the per-stat cap
+    ///
+    /// # Returns
+    /// Formatted string like: `[████░░░░░░░░░░░░]`
+    fn get_ev_bar(value: u32) -> String {
+        let bar_width = 16;
+        let filled = (value.min(EffortValues::MAX_PER_STAT) as usize * bar_width)
+            / EffortValues::MAX_PER_STAT as usize;
+        format!("[{}{}]", "█".repeat(filled), "░".repeat(bar_width - filled))
+    }
+
     /// Display Pokemon moves with detailed information
     ///
     /// Shows up to 4 moves that the Pokemon currently knows.
@@ -177,7 +236,7 @@ impl PokemonDetailMenu {
             for (i, m) in pokemon.moves.iter().enumerate() {
                 println!("║ {}. {:<32} ║", i + 1, m.name);
                 println!("║    类型:{:?} 威力:{} 命中:{} ║",
-                         m.pokemon_type, m.power, m.accuracy);
+                         m.pokemon_type, pokemon.move_power(m), m.accuracy);
 
                 // PP bar
                 let pp_bar_width = 20;
@@ -198,6 +257,7 @@ impl PokemonDetailMenu {
     /// Display Pokemon capture and origin information
     ///
     /// Shows details about when and where the Pokemon was caught:
+    /// - Original trainer (the player, a gym trainer or an import)
     /// - Capture method (the type of Pokéball used)
     /// - Capture location (resolved from location_id or shown as "Unknown")
     /// - Capture date (displayed as timestamp, see limitations)
@@ -210,6 +270,7 @@ impl PokemonDetailMenu {
         println!("║              捕捉信息                  ║");
         println!("╠════════════════════════════════════════╣");
 
+        println!("║ 原训练家: {:<31} ║", pokemon.original_trainer.as_deref().unwrap_or("未记录"));
         println!("║ 捕捉方式: {:<31} ║", pokemon.caught_with);
 
         // Get location name
@@ -267,15 +328,19 @@ impl PokemonDetailMenu {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::game::{Pokemon, PokemonType, Stat, Move, MoveType};
+    use crate::game::{Pokemon, PokemonType, Stat, Move, MoveType, GrowthRate};
 
     /// Create a test Pokemon for testing display functions
     fn create_test_pokemon() -> Pokemon {
         Pokemon {
             id: 25,
             name: "皮卡丘".to_string(),
+            nickname: None,
+            gender: crate::pokemon_generator::Gender::Male,
+            is_shiny: false,
             level: 25,
-            experience: 2500,
+            experience: 16_000,
+            growth_rate: GrowthRate::MediumFast,
             hp: 95,
             max_hp: 120,
             pokemon_type: (PokemonType::Electric, None),
@@ -287,6 +352,12 @@ mod tests {
                 sp_defense: 58,
                 speed: 105,
             },
+            individual_values: crate::pokemon_generator::IndividualValues::perfect(),
+            effort_values: Default::default(),
+            nature: Default::default(),
+            talent: Default::default(),
+            unique_id: "test".to_string(),
+            friendship: 70,
             moves: vec![
                 Move {
                     id: 1,
@@ -302,6 +373,8 @@ mod tests {
             caught_with: "精灵球".to_string(),
             caught_location_id: 1,
             caught_date: 1699012445,
+            original_trainer: None,
+            held_item: None,
             catch_rate: 35,
         }
     }
@@ -422,8 +495,19 @@ mod tests {
     fn test_experience_bar_calculation() {
         // Test experience progress calculation
         let pokemon = create_test_pokemon();
-        let next_level_exp = pokemon.level as u32 * 100;
-        let exp_percent = (pokemon.experience as f32 / next_level_exp as f32 * 100.0) as u32;
+        let (exp_into_level, exp_span) = pokemon.experience_progress();
+        let exp_percent = (exp_into_level as f32 / exp_span as f32 * 100.0) as u32;
         assert!(exp_percent > 0 && exp_percent <= 100);
+        assert_eq!(pokemon.experience_to_next_level(), 17_576 - 16_000);
+    }
+
+    #[test]
+    fn test_ev_bar_scaling() {
+        assert_eq!(PokemonDetailMenu::get_ev_bar(0), format!("[{}]", "░".repeat(16)));
+        assert_eq!(PokemonDetailMenu::get_ev_bar(252), format!("[{}]", "█".repeat(16)));
+        assert_eq!(
+            PokemonDetailMenu::get_ev_bar(126),
+            format!("[{}{}]", "█".repeat(8), "░".repeat(8))
+        );
     }
 }
@@ -0,0 +1,113 @@
+use crate::game::{Quest, QuestStatus};
+use crate::npc::QuestNpc;
+use std::io::{self, Write};
+
+pub struct QuestMenu;
+
+impl QuestMenu {
+    /// 显示地点中可以交谈的居民
+    pub fn display_residents(location_name: &str, npcs: &[QuestNpc]) {
+        println!("\n╔════════════════════════════════════╗");
+        println!("║ {:<34} ║", format!("🏘 {} 的居民", location_name));
+        println!("╠════════════════════════════════════╣");
+        for (i, npc) in npcs.iter().enumerate() {
+            println!("║ {:<34} ║", format!("{}. {}", i + 1, npc.name));
+        }
+        println!("║ 0. 离开                            ║");
+        println!("╚════════════════════════════════════╝");
+        print!("请选择交谈的对象: ");
+        io::stdout().flush().unwrap();
+    }
+
+    /// 显示没有居民的地点
+    pub fn display_no_residents(location_name: &str) {
+        println!("\n{} 没有可以交谈的居民。", location_name);
+    }
+
+    /// 显示 NPC 发布的任务及其状态 (未解锁的任务不显示)
+    pub fn display_npc_quests(npc: &QuestNpc, quests: &[(Quest, QuestStatus, bool)]) {
+        if let Some(greeting) = &npc.greeting {
+            println!("\n{}: 「{}」", npc.name, greeting);
+        }
+        println!("\n╔════════════════════════════════════╗");
+        println!("║ {:<34} ║", format!("📜 {} 的委托", npc.name));
+        println!("╠════════════════════════════════════╣");
+        if quests.is_empty() {
+            println!("║ 暂时没有委托                       ║");
+        }
+        for (i, (quest, status, met)) in quests.iter().enumerate() {
+            println!("║ {:<34} ║", format!("{}. {} [{}]", i + 1, quest.name, Self::status_text(*status, *met)));
+        }
+        println!("║ 0. 离开                            ║");
+        println!("╚════════════════════════════════════╝");
+        print!("请选择任务: ");
+        io::stdout().flush().unwrap();
+    }
+
+    /// 显示任务详情并询问是否接受
+    pub fn display_offer(quest: &Quest) {
+        println!("\n╔════════════════════════════════════╗");
+        println!("║ {:<34} ║", format!("📜 {}", quest.name));
+        println!("╠════════════════════════════════════╣");
+        println!("║ {:<34} ║", quest.description);
+        println!("║ {:<34} ║", format!("目标: {}", quest.objective.description()));
+        for reward in quest.reward.descriptions() {
+            println!("║ {:<34} ║", format!("奖励: {}", reward));
+        }
+        println!("╠════════════════════════════════════╣");
+        println!("║ 1. 接受任务                        ║");
+        println!("║ 0. 返回                            ║");
+        println!("╚════════════════════════════════════╝");
+        print!("请选择: ");
+        io::stdout().flush().unwrap();
+    }
+
+    /// 显示尚未达成目标的任务进度
+    pub fn display_in_progress(quest: &Quest, progress: (u32, u32)) {
+        println!(
+            "\n「{}」还没有完成: {} ({}/{})",
+            quest.name,
+            quest.objective.description(),
+            progress.0,
+            progress.1
+        );
+    }
+
+    /// 显示完成任务获得的奖励
+    pub fn display_completed(message: &str, quest: &Quest) {
+        println!("\n🎉 {}", message);
+        for reward in quest.reward.descriptions() {
+            println!("  获得了 {}", reward);
+        }
+    }
+
+    /// 显示任务日志：进行中的任务与进度，以及已完成的任务
+    pub fn display_journal(active: &[(Quest, (u32, u32))], completed: &[Quest]) {
+        println!("\n╔════════════════════════════════════════╗");
+        println!("║ 📔 任务日志                            ║");
+        println!("╠════════════════════════════════════════╣");
+        println!("║ {:<38} ║", format!("进行中 ({})", active.len()));
+        for (quest, (current, target)) in active {
+            let giver = QuestNpc::get(quest.npc_id).map(|npc| npc.name).unwrap_or_default();
+            println!("║ {:<38} ║", format!("• {} ({})", quest.name, giver));
+            println!("║ {:<38} ║", format!("   {} {}/{}", quest.objective.description(), current, target));
+        }
+        println!("╠════════════════════════════════════════╣");
+        println!("║ {:<38} ║", format!("已完成 ({})", completed.len()));
+        for quest in completed {
+            println!("║ {:<38} ║", format!("✓ {}", quest.name));
+        }
+        println!("╚════════════════════════════════════════╝");
+    }
+
+    /// 任务状态的显示文本
+    fn status_text(status: QuestStatus, met: bool) -> &'static str {
+        match status {
+            QuestStatus::Available => "新委托",
+            QuestStatus::Active if met => "可交付",
+            QuestStatus::Active => "进行中",
+            QuestStatus::Completed => "已完成",
+            QuestStatus::Locked => "未解锁",
+        }
+    }
+}
@@ -18,25 +18,22 @@ impl RevivalMenu {
         std::io::stdout().flush().unwrap();
     }
 
+    /// 全队昏迷后眼前一黑，回到宝可梦中心
+    pub fn print_blackout(money_lost: u32, center_name: &str) {
+        println!("\n你的队伍全部昏迷了!");
+        println!("你慌忙中丢掉了 ¥{}...", money_lost);
+        println!("眼前一片漆黑!");
+        println!("\n你回到了 {} 的宝可梦中心，队伍已经完全恢复。", center_name);
+    }
+
     pub fn print_recovery_item_menu(player: &Player) {
         println!("\n╔═════════════════════════════════════╗");
         println!("║      选择恢复道具                  ║");
         println!("╠═════════════════════════════════════╣");
 
-        let recovery_items = vec![
-            ("恢复药", "恢复单只宝可梦 50% HP"),
-            ("超级恢复药", "恢复单只宝可梦 100% HP"),
-            ("全复活", "复活昏迷宝可梦，恢复 50% HP"),
-            ("完全恢复", "复活昏迷宝可梦，恢复 100% HP"),
-        ];
-
-        let mut index = 1;
-        for (item_name, effect) in recovery_items {
-            if let Some(count) = player.items.get(item_name) {
-                println!("║ {}. {} x{}                   ║", index, item_name, count);
-                println!("║    {}", effect);
-                index += 1;
-            }
+        for (i, (item, count)) in player.get_recovery_items().iter().enumerate() {
+            println!("║ {}. {} x{}                   ║", i + 1, item.name, count);
+            println!("║    {}", item.description);
         }
 
         println!("║ 0. 返回                            ║");
@@ -0,0 +1,78 @@
+use crate::game::{FieldAbility, LocationService, Move};
+use std::io::{self, Write};
+
+pub struct ServiceMenu;
+
+impl ServiceMenu {
+    /// 显示当前地点的设施服务
+    pub fn display_service_menu(location_name: &str, services: &[LocationService]) {
+        println!("\n╔════════════════════════════════════╗");
+        println!("║ {:<34} ║", format!("🏢 {} 的设施", location_name));
+        println!("╠════════════════════════════════════╣");
+        for (i, service) in services.iter().enumerate() {
+            println!("║ {:<34} ║", format!("{}. {}", i + 1, service.name()));
+        }
+        println!("║ 0. 返回                            ║");
+        println!("╚════════════════════════════════════╝");
+        print!("请选择: ");
+        io::stdout().flush().unwrap();
+    }
+
+    /// 显示没有设施
+    pub fn display_no_services(location_name: &str) {
+        println!("\n{} 没有可以使用的设施。", location_name);
+    }
+
+    /// 显示可以付费学习的招式
+    pub fn display_move_options(service: LocationService, pokemon_name: &str, moves: &[Move], price: u32, money: u32) {
+        println!("\n╔════════════════════════════════════╗");
+        println!("║ {:<34} ║", format!("{} - {}", service.name(), pokemon_name));
+        println!("║ {:<34} ║", format!("费用: ¥{}  (持有: ¥{})", price, money));
+        println!("╠════════════════════════════════════╣");
+        for (i, mv) in moves.iter().enumerate() {
+            println!("║ {:<34} ║", format!("{}. {} (Pow:{}, PP:{})", i + 1, mv.name, mv.power, mv.max_pp));
+        }
+        println!("║ 0. 返回                            ║");
+        println!("╚════════════════════════════════════╝");
+        print!("请选择: ");
+        io::stdout().flush().unwrap();
+    }
+
+    /// 显示没有可学习的招式
+    pub fn display_no_moves(service: LocationService, pokemon_name: &str) {
+        match service {
+            LocationService::MoveTutor => println!("\n没有可以教给 {} 的招式。", pokemon_name),
+            LocationService::MoveRelearner => println!("\n{} 没有可以回忆起的招式。", pokemon_name),
+            LocationService::DayCare
+            | LocationService::PokeMart
+            | LocationService::PokemonCenter
+            | LocationService::Gym
+            | LocationService::Residents => {}
+        }
+    }
+
+    /// 显示宝可梦中心恢复完成
+    pub fn display_pokemon_center_healed() {
+        println!("\n✓ 你的宝可梦已经完全恢复了！欢迎再来！");
+    }
+
+    /// 显示队伍可以使用的秘传招式
+    pub fn display_field_ability_menu(abilities: &[(FieldAbility, String)]) {
+        println!("\n╔════════════════════════════════════╗");
+        println!("║ 秘传招式                           ║");
+        println!("╠════════════════════════════════════╣");
+        for (i, (ability, pokemon_name)) in abilities.iter().enumerate() {
+            println!("║ {:<34} ║", format!("{}. {} ({})", i + 1, ability.name(), pokemon_name));
+            println!("║ {:<34} ║", format!("   {}", ability.description()));
+        }
+        println!("║ 0. 返回                            ║");
+        println!("╚════════════════════════════════════╝");
+        print!("请选择: ");
+        io::stdout().flush().unwrap();
+    }
+
+    /// 显示没有可用的秘传招式
+    pub fn display_no_field_abilities() {
+        println!("\n队伍中没有会秘传招式的宝可梦。");
+    }
+}
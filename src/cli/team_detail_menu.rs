@@ -27,10 +27,15 @@ impl TeamDetailMenu {
         println!("║ {:<39} ║", hp_bar);
 
         // 经验值进度
-        let next_level_exp = pokemon.level as u32 * 100;
-        let exp_percent = (pokemon.experience as f32 / next_level_exp as f32 * 100.0) as u32;
+        let (exp_into_level, exp_span) = pokemon.experience_progress();
+        let exp_percent = if exp_span > 0 {
+            (exp_into_level as f32 / exp_span as f32 * 100.0) as u32
+        } else {
+            100
+        };
         println!("║ 经验值: {}/{} ({}%) {:<18} ║",
-                 pokemon.experience, next_level_exp, exp_percent, "");
+                 exp_into_level, exp_span, exp_percent, "");
+        println!("║ 距下一级: {:<30} ║", pokemon.experience_to_next_level());
 
         println!("║                                         ║");
 
@@ -60,7 +65,7 @@ impl TeamDetailMenu {
                 );
                 println!("║  {:<35} ║", move_info);
                 let pp_info = format!("     Power: {}, Accuracy: {}%, PP: {}/{}",
-                                     m.power, m.accuracy, m.pp, m.max_pp);
+                                     pokemon.move_power(m), m.accuracy, m.pp, m.max_pp);
                 println!("║  {:<35} ║", pp_info);
             }
         }
@@ -53,7 +53,7 @@ impl TeamListMenu {
         for i in 0..6 {
             if i < player.pokemons.len() {
                 let pokemon = &player.pokemons[i];
-                let level_display = format!("{}. {} Lv.{}", i + 1, pokemon.name, pokemon.level);
+                let level_display = format!("{}. {} Lv.{}", i + 1, pokemon.display_label(), pokemon.level);
 
                 // HP bar
                 let hp_bar = Self::get_hp_bar(pokemon.hp, pokemon.max_hp);
@@ -68,11 +68,34 @@ impl TeamListMenu {
 
         println!("║                                        ║");
         println!("║ 请选择查看详情 (1-6) 或 [0] 返回      ║");
+        println!("║ [E] 导出队伍  [B] 导出箱子  [I] 导入  ║");
         println!("╚════════════════════════════════════════╝");
         print!("选择: ");
         io::stdout().flush().unwrap();
     }
 
+    /// Print exported Showdown text between separator lines
+    pub fn display_export(title: &str, text: &str) {
+        println!("\n──────── {} (Showdown) ────────", title);
+        println!("{}", text);
+        println!("────────────────────────────────────────");
+    }
+
+    /// Read a Showdown paste until a line containing `END` or end of input
+    pub fn read_paste() -> String {
+        println!("\n请粘贴 Showdown 格式的队伍，单独一行输入 END 结束:");
+        let mut lines = Vec::new();
+        loop {
+            let mut line = String::new();
+            match io::stdin().read_line(&mut line) {
+                Ok(0) | Err(_) => break,
+                Ok(_) if line.trim() == "END" => break,
+                Ok(_) => lines.push(line.trim_end().to_string()),
+            }
+        }
+        lines.join("\n")
+    }
+
     /// Generate HP progress bar visualization
     ///
     /// # Arguments
@@ -109,6 +132,7 @@ impl TeamListMenu {
     ///
     /// # Valid Inputs
     /// - `1` to `6`: Select a team member
+    /// - `E` / `B` / `I`: Export team, export a box, import a paste
     /// - `0`: Return to main menu
     pub fn get_input() -> String {
         let mut input = String::new();
@@ -120,15 +144,19 @@ impl TeamListMenu {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::game::{PokemonType, Stat};
+    use crate::game::{PokemonType, Stat, GrowthRate};
 
     /// Create a test Pokemon for testing team display
     fn create_test_pokemon(id: u32, name: &str, level: u32) -> Pokemon {
         Pokemon {
             id,
             name: name.to_string(),
+            nickname: None,
+            gender: crate::pokemon_generator::Gender::Male,
+            is_shiny: false,
             level,
             experience: 0,
+            growth_rate: GrowthRate::MediumFast,
             hp: 100,
             max_hp: 100,
             pokemon_type: (PokemonType::Normal, None),
@@ -140,10 +168,18 @@ mod tests {
                 sp_defense: 100,
                 speed: 100,
             },
+            individual_values: crate::pokemon_generator::IndividualValues::perfect(),
+            effort_values: Default::default(),
+            nature: Default::default(),
+            talent: Default::default(),
+            unique_id: "test".to_string(),
+            friendship: 70,
             moves: vec![],
             caught_with: "精灵球".to_string(),
             caught_location_id: 1,
             caught_date: 0,
+            original_trainer: None,
+            held_item: None,
             catch_rate: 45,
         }
     }
@@ -0,0 +1,47 @@
+use crate::game::stats::{format_date, format_playtime};
+use crate::game::TrainerCard;
+use std::io::{self, Write};
+
+pub struct TrainerCardMenu;
+
+impl TrainerCardMenu {
+    /// 显示训练家卡片与终身统计
+    pub fn display_trainer_card(card: &TrainerCard) {
+        let stats = &card.stats;
+        let start_date = if stats.start_date > 0 { format_date(stats.start_date) } else { "未记录".to_string() };
+
+        println!("\n╔════════════════════════════════════════╗");
+        println!("║ {:<38} ║", format!("🪪 训练家卡片  {}", card.name));
+        println!("╠════════════════════════════════════════╣");
+        println!("║ {:<38} ║", format!("持有金币: ¥{}", card.money));
+        println!("║ {:<38} ║", format!("徽章: {} 枚", card.badges.len()));
+        println!("║ {:<38} ║", format!("图鉴: 见过 {}  捕捉 {}", card.pokedex_seen, card.pokedex_caught));
+        println!("║ {:<38} ║", format!("成就: {} 个", card.achievements_unlocked));
+        println!("║ {:<38} ║", format!("冒险开始: {}", start_date));
+        println!("║ {:<38} ║", format!("游戏时间: {}", format_playtime(stats.playtime_seconds)));
+        println!("╠════════════════════════════════════════╣");
+        println!("║ {:<38} ║", format!("野生战斗: {} 胜 {} 负", stats.wild_battles_won, stats.wild_battles_lost));
+        println!("║ {:<38} ║", format!("训练家对战: {} 胜 {} 负", stats.trainer_battles_won, stats.trainer_battles_lost));
+        println!("║ {:<38} ║", format!("胜率: {:.1}%", stats.win_rate()));
+        println!("║ {:<38} ║", format!("击倒野生宝可梦: {}", stats.wild_pokemon_defeated));
+        println!("║ {:<38} ║", format!("捕捉宝可梦: {}", stats.pokemon_caught));
+        println!("║ {:<38} ║", format!("步数: {}", stats.steps));
+        println!("║ {:<38} ║", format!("累计收入: ¥{}", stats.money_earned));
+        println!("║ {:<38} ║", format!("累计支出: ¥{}", stats.money_spent));
+        println!("╠════════════════════════════════════════╣");
+        println!("║ A. 查看成就                            ║");
+        println!("║ J. 任务日志                            ║");
+        println!("║ E. 导出为 JSON                         ║");
+        println!("║ 0. 返回                                ║");
+        println!("╚════════════════════════════════════════╝");
+        print!("请选择: ");
+        io::stdout().flush().unwrap();
+    }
+
+    /// 显示导出的 JSON
+    pub fn display_export(json: &str) {
+        println!("\n──────── 训练家卡片 (JSON) ────────");
+        println!("{}", json);
+        println!("────────────────────────────────────────");
+    }
+}
@@ -3,6 +3,7 @@
 //! These structures map to the JSON file formats and can be converted to game structures
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Pokémon species data from JSON
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -15,9 +16,15 @@ pub struct PokemonSpeciesJSON {
     pub base_stats: BaseStatsJSON,
     pub catch_rate: u32,
     pub experience_yield: u32,
+    #[serde(default)]
+    pub growth_rate: Option<String>,
+    #[serde(default)]
+    pub ev_yield: Option<HashMap<String, u32>>,
     pub evolution: Option<EvolutionJSON>,
     #[serde(default)]
     pub initial_moves: Option<Vec<u32>>,
+    #[serde(default)]
+    pub learnset: Option<Vec<LearnsetEntryJSON>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -35,6 +42,15 @@ pub struct EvolutionJSON {
     pub method: String,
     pub trigger: u32,
     pub to: u32,
+    /// 只能在该时段进化 ("morning" / "day" / "night")
+    #[serde(default)]
+    pub time: Option<crate::game::TimeOfDay>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LearnsetEntryJSON {
+    pub level: u32,
+    pub move_id: u32,
 }
 
 /// Move data from JSON
@@ -124,7 +140,11 @@ mod tests {
             base_stats: stats,
             catch_rate: 45,
             experience_yield: 64,
+            growth_rate: Some("MediumSlow".to_string()),
+            ev_yield: Some(HashMap::from([("sp_attack".to_string(), 1)])),
             evolution: None,
+            initial_moves: Some(vec![1, 74]),
+            learnset: Some(vec![LearnsetEntryJSON { level: 7, move_id: 22 }]),
         };
 
         assert_eq!(pokemon.id, 1);
@@ -6,6 +6,7 @@
 //! - Validating loaded data
 //! - Providing convenient access to game data
 
+use super::validator;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::env;
@@ -25,12 +26,17 @@ pub struct GameDataCache {
     pub moves: Vec<serde_json::Value>,
     pub locations: Vec<serde_json::Value>,
     pub trainers: Vec<serde_json::Value>,
+    pub badges: Vec<serde_json::Value>,
+    pub achievements: Vec<serde_json::Value>,
+    pub quests: Vec<serde_json::Value>,
+    pub quest_npcs: Vec<serde_json::Value>,
     pub items: Vec<serde_json::Value>,
     pub type_effectiveness: Vec<serde_json::Value>,
     pub game_constants: serde_json::Value,
     pub player_defaults: serde_json::Value,
     pub environment_bonuses: Vec<serde_json::Value>,
     pub natures: Vec<serde_json::Value>,
+    pub abilities: Vec<serde_json::Value>,
 }
 
 /// Find the assets directory by searching multiple locations
@@ -106,23 +112,48 @@ pub fn load_all_data() -> Result<(), String> {
     let move_data = load_move_data()?;
     let location_data = load_location_data()?;
     let trainer_data = load_trainer_data()?;
+    let badge_data = load_badge_data()?;
+    let achievement_data = load_achievement_data()?;
+    let quest_data = load_quest_data()?;
+    let quest_npc_data = load_quest_npc_data()?;
     let items_data = load_items_data()?;
     let type_effectiveness_data = load_type_effectiveness_data()?;
     let game_constants_data = load_game_constants()?;
     let player_defaults_data = load_player_defaults()?;
     let environment_bonuses_data = load_environment_bonuses()?;
     let natures_data = load_natures()?;
+    let abilities_data = load_abilities()?;
 
     println!("✓ Loaded {} Pokémon species", pokemon_data.len());
     println!("✓ Loaded {} moves", move_data.len());
     println!("✓ Loaded {} locations", location_data.len());
     println!("✓ Loaded {} trainers", trainer_data.len());
+    println!("✓ Loaded {} badges", badge_data.len());
+    println!("✓ Loaded {} achievements", achievement_data.len());
+    println!("✓ Loaded {} quests from {} NPCs", quest_data.len(), quest_npc_data.len());
     println!("✓ Loaded {} items", items_data.len());
     println!("✓ Loaded {} type matchups", type_effectiveness_data.len());
     println!("✓ Loaded game constants");
     println!("✓ Loaded player defaults");
     println!("✓ Loaded {} environment types", environment_bonuses_data.len());
     println!("✓ Loaded {} natures", natures_data.len());
+    println!("✓ Loaded {} abilities", abilities_data.len());
+
+    let checks = [
+        validator::validate_all_data(&pokemon_data, &location_data, &trainer_data),
+        validator::validate_achievement_data(&achievement_data),
+        validator::validate_quest_data(&quest_data, &quest_npc_data),
+        validator::validate_items_data(&items_data),
+        validator::validate_type_effectiveness(&type_effectiveness_data),
+        validator::validate_environment_bonuses(&environment_bonuses_data),
+        validator::validate_natures(&natures_data),
+        validator::validate_abilities(&abilities_data, &pokemon_data),
+    ];
+    let errors: Vec<String> = checks.into_iter().filter_map(Result::err).flatten().collect();
+    if !errors.is_empty() {
+        return Err(format!("Invalid game data:\n  - {}", errors.join("\n  - ")));
+    }
+    println!("✓ Validated game data");
 
     // Initialize global cache
     unsafe {
@@ -131,12 +162,17 @@ pub fn load_all_data() -> Result<(), String> {
             moves: move_data,
             locations: location_data,
             trainers: trainer_data,
+            badges: badge_data,
+            achievements: achievement_data,
+            quests: quest_data,
+            quest_npcs: quest_npc_data,
             items: items_data,
             type_effectiveness: type_effectiveness_data,
             game_constants: game_constants_data,
             player_defaults: player_defaults_data,
             environment_bonuses: environment_bonuses_data,
             natures: natures_data,
+            abilities: abilities_data,
         });
     }
 
@@ -173,6 +209,34 @@ fn load_trainer_data() -> Result<Vec<serde_json::Value>, String> {
     load_json_array(&path, "trainers")
 }
 
+/// Load gym badge data from JSON
+fn load_badge_data() -> Result<Vec<serde_json::Value>, String> {
+    let assets_dir = get_assets_dir();
+    let path = assets_dir.join("npcs/badges.json");
+    load_json_array(&path, "badges")
+}
+
+/// Load achievement definitions from JSON
+fn load_achievement_data() -> Result<Vec<serde_json::Value>, String> {
+    let assets_dir = get_assets_dir();
+    let path = assets_dir.join("config/achievements.json");
+    load_json_array(&path, "achievements")
+}
+
+/// Load quest definitions from JSON
+fn load_quest_data() -> Result<Vec<serde_json::Value>, String> {
+    let assets_dir = get_assets_dir();
+    let path = assets_dir.join("npcs/quests.json");
+    load_json_array(&path, "quests")
+}
+
+/// Load the NPCs that give quests from JSON
+fn load_quest_npc_data() -> Result<Vec<serde_json::Value>, String> {
+    let assets_dir = get_assets_dir();
+    let path = assets_dir.join("npcs/quests.json");
+    load_json_array(&path, "npcs")
+}
+
 /// Load items data from JSON
 fn load_items_data() -> Result<Vec<serde_json::Value>, String> {
     let assets_dir = get_assets_dir();
@@ -239,6 +303,13 @@ fn load_natures() -> Result<Vec<serde_json::Value>, String> {
     load_json_array(&path, "natures")
 }
 
+/// Load abilities from JSON
+fn load_abilities() -> Result<Vec<serde_json::Value>, String> {
+    let assets_dir = get_assets_dir();
+    let path = assets_dir.join("pokemon/abilities.json");
+    load_json_array(&path, "abilities")
+}
+
 /// Helper to load a JSON array from file
 fn load_json_array(path: &Path, key: &str) -> Result<Vec<serde_json::Value>, String> {
     // Check if file exists
@@ -271,6 +342,59 @@ pub fn get_item_by_type(item_type: &str) -> Option<serde_json::Value> {
     }).cloned()
 }
 
+/// Get item by its numeric ID (the key used in the player's bag)
+pub fn get_item_by_id(id: u32) -> Option<serde_json::Value> {
+    get_game_data()?.items.iter().find(|item| {
+        item.get("id").and_then(|v| v.as_u64()) == Some(id as u64)
+    }).cloned()
+}
+
+/// Get item by its English name (old saves keyed Poké Balls this way)
+pub fn get_item_by_english_name(name: &str) -> Option<serde_json::Value> {
+    get_game_data()?.items.iter().find(|item| {
+        item.get("english_name")
+            .and_then(|v| v.as_str())
+            .map(|n| n == name)
+            .unwrap_or(false)
+    }).cloned()
+}
+
+/// Get item by its Chinese display name
+pub fn get_item_by_name(name: &str) -> Option<serde_json::Value> {
+    get_game_data()?.items.iter().find(|item| {
+        item.get("name")
+            .and_then(|v| v.as_str())
+            .map(|n| n == name)
+            .unwrap_or(false)
+    }).cloned()
+}
+
+/// Get an ability from abilities.json by ID
+pub fn get_ability_by_id(id: u32) -> Option<serde_json::Value> {
+    get_game_data()?.abilities.iter().find(|ability| {
+        ability.get("id").and_then(|v| v.as_u64()) == Some(id as u64)
+    }).cloned()
+}
+
+/// Get a trainer from trainers.json by ID
+pub fn get_trainer_by_id(id: u32) -> Option<serde_json::Value> {
+    get_game_data()?.trainers.iter().find(|trainer| {
+        trainer.get("id").and_then(|v| v.as_u64()) == Some(id as u64)
+    }).cloned()
+}
+
+/// Get a gym badge from badges.json by ID
+pub fn get_badge_by_id(id: u32) -> Option<serde_json::Value> {
+    get_game_data()?.badges.iter().find(|badge| {
+        badge.get("id").and_then(|v| v.as_u64()) == Some(id as u64)
+    }).cloned()
+}
+
+/// Get a numeric value from game_constants.json by section and key
+pub fn get_game_constant(section: &str, key: &str) -> Option<u64> {
+    get_game_data()?.game_constants.get(section)?.get(key)?.as_u64()
+}
+
 /// Get type effectiveness multiplier for attacking and defending types
 pub fn get_type_effectiveness(attacking: &str, defending: &str) -> f64 {
     get_game_data()
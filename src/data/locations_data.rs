@@ -1,4 +1,4 @@
-use crate::game::{Location, EnvironmentType, WildPokemonSpawn};
+use crate::game::{EncounterMethod, Location, EnvironmentType, LocationRequirement, LocationService, TimeOfDay, WildPokemonSpawn};
 use crate::data::loader;
 
 // /// 创建所有游戏地点
@@ -399,6 +399,8 @@ pub fn get_all_locations() -> Vec<Location> {
                                 spawn_rate: spawn_rate as f32,
                                 level_min: min_lvl as u32,
                                 level_max: max_lvl as u32,
+                                times: parse_spawn_times(spawn),
+                                method: parse_spawn_method(spawn),
                             });
                         }
                     }
@@ -413,6 +415,12 @@ pub fn get_all_locations() -> Vec<Location> {
                     }
                 }
 
+                location.services = parse_services(loc_json);
+                location.mart = parse_entries(loc_json, "mart");
+                location.connection_requirements = parse_entries(loc_json, "connection_requirements");
+                location.gift_items = parse_entries(loc_json, "gift_items");
+                location.unlock_requirement = parse_unlock_requirement(loc_json);
+
                 Some(location)
             }).collect()
         }
@@ -469,6 +477,8 @@ pub fn get_location_by_id(location_id: u32) -> Option<Location> {
                     spawn_rate: spawn_rate as f32,
                     level_min: min_lvl as u32,
                     level_max: max_lvl as u32,
+                    times: parse_spawn_times(spawn),
+                    method: parse_spawn_method(spawn),
                 });
             }
         }
@@ -483,5 +493,63 @@ pub fn get_location_by_id(location_id: u32) -> Option<Location> {
         }
     }
 
+    location.services = parse_services(loc_json);
+    location.mart = parse_entries(loc_json, "mart");
+    location.connection_requirements = parse_entries(loc_json, "connection_requirements");
+    location.gift_items = parse_entries(loc_json, "gift_items");
+    location.unlock_requirement = parse_unlock_requirement(loc_json);
+
     Some(location)
 }
+
+/// 解析地点的设施服务 (可选字段，未知服务会被忽略)
+fn parse_services(loc_json: &serde_json::Value) -> Vec<LocationService> {
+    loc_json
+        .get("services")
+        .and_then(|v| v.as_array())
+        .map(|services| {
+            services
+                .iter()
+                .filter_map(|s| s.as_str().and_then(LocationService::from_name))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// 解析地点中的可选列表 (如 mart、connection_requirements，加载数据时已经校验过，每一项都能解析)
+fn parse_entries<T: serde::de::DeserializeOwned>(loc_json: &serde_json::Value, key: &str) -> Vec<T> {
+    loc_json
+        .get(key)
+        .and_then(|v| v.as_array())
+        .map(|entries| {
+            entries
+                .iter()
+                .map(|entry| serde_json::from_value(entry.clone()).expect("地点数据在加载时已校验"))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// 解析宝可梦出现的时段 (没有 times 时全天出现)
+fn parse_spawn_times(spawn_json: &serde_json::Value) -> Vec<TimeOfDay> {
+    spawn_json
+        .get("times")
+        .and_then(|times| serde_json::from_value(times.clone()).ok())
+        .unwrap_or_default()
+}
+
+/// 解析遭遇宝可梦的方式 (没有 method 时为在草丛中行走)
+fn parse_spawn_method(spawn_json: &serde_json::Value) -> EncounterMethod {
+    spawn_json
+        .get("method")
+        .and_then(|method| serde_json::from_value(method.clone()).ok())
+        .unwrap_or_default()
+}
+
+/// 解析地点的解锁条件 (可选字段，没有时无条件；加载数据时已经校验过格式)
+fn parse_unlock_requirement(loc_json: &serde_json::Value) -> LocationRequirement {
+    loc_json
+        .get("unlock_requirement")
+        .map(|requirement| serde_json::from_value(requirement.clone()).expect("地点数据在加载时已校验"))
+        .unwrap_or_default()
+}
@@ -1,5 +1,7 @@
-use crate::game::{Pokemon, PokemonType, Stat, Move, MoveType};
+use crate::game::{Pokemon, PokemonType, Stat, Move, MoveType, GrowthRate, Evolution, Learnset, FieldAbility};
+use crate::data::json_schemas::{EvolutionJSON, LearnsetEntryJSON};
 use crate::data::loader;
+use crate::pokemon_generator::{EffortValues, PokemonInstance, Talent};
 
 pub fn get_pokemon_by_id(id: u32) -> Option<Pokemon> {
     // Get game data from the JSON cache
@@ -15,6 +17,7 @@ pub fn get_pokemon_by_id(id: u32) -> Option<Pokemon> {
     let primary_type_str = pokemon_data.get("primary_type")?.as_str()?;
     let secondary_type_str = pokemon_data.get("secondary_type").and_then(|v| v.as_str());
     let catch_rate = pokemon_data.get("catch_rate")?.as_u64()? as u32;
+    let growth_rate = parse_growth_rate(pokemon_data);
 
     // Parse base stats
     let base_stats = pokemon_data.get("base_stats")?;
@@ -82,15 +85,21 @@ pub fn get_pokemon_by_id(id: u32) -> Option<Pokemon> {
         (primary_type, secondary_type),
         stats,
         catch_rate,
-    );
+    )
+    .with_growth_rate(growth_rate);
+
+    // Load the moves known at the current level from the learnset
+    pokemon.moves = get_moves_for_level(id, pokemon.level);
 
-    // === Phase 4: Load initial moves from JSON ===
-    if let Some(initial_moves) = pokemon_data.get("initial_moves")
-        .and_then(|v| v.as_array()) {
-        for move_id_value in initial_moves {
-            if let Some(move_id) = move_id_value.as_u64() {
-                if let Some(move_data) = get_move_by_id(move_id as u32) {
-                    pokemon.add_move(move_data);
+    // === Phase 4: Load initial moves from JSON (species without a learnset) ===
+    if pokemon.moves.is_empty() {
+        if let Some(initial_moves) = pokemon_data.get("initial_moves")
+            .and_then(|v| v.as_array()) {
+            for move_id_value in initial_moves {
+                if let Some(move_id) = move_id_value.as_u64() {
+                    if let Some(move_data) = get_move_by_id(move_id as u32) {
+                        pokemon.add_move(move_data);
+                    }
                 }
             }
         }
@@ -782,6 +791,323 @@ pub fn get_wild_pokemon() -> Pokemon {
     get_pokemon_by_id(id).unwrap()
 }
 
+/// Build a battle-ready Pokemon from a generated instance, keeping its IVs, EVs,
+/// nature, talent and unique ID,
+/// and knowing the last four moves it could have learned by its level
+pub fn get_pokemon_from_instance(instance: &PokemonInstance) -> Option<Pokemon> {
+    let mut pokemon = get_pokemon_by_id(instance.species_id)?.with_instance(instance);
+    let moves = get_moves_for_level(pokemon.id, pokemon.level);
+    if !moves.is_empty() {
+        pokemon.moves = moves;
+    }
+    Some(pokemon)
+}
+
+/// Get the level-up learnset of a species (empty if it has none)
+pub fn get_learnset(species_id: u32) -> Learnset {
+    find_species_json(species_id)
+        .and_then(|p| p.get("learnset"))
+        .and_then(|v| serde_json::from_value::<Vec<LearnsetEntryJSON>>(v.clone()).ok())
+        .map(|entries| Learnset::from_json(&entries))
+        .unwrap_or_default()
+}
+
+/// Get the moves a species learns exactly at the given level
+pub fn get_moves_learned_at(species_id: u32, level: u32) -> Vec<Move> {
+    get_learnset(species_id)
+        .moves_learned_at(level)
+        .into_iter()
+        .filter_map(get_move_by_id)
+        .collect()
+}
+
+/// Get the last four moves a species could have learned by the given level
+pub fn get_moves_for_level(species_id: u32, level: u32) -> Vec<Move> {
+    get_learnset(species_id)
+        .moves_for_level(level)
+        .into_iter()
+        .filter_map(get_move_by_id)
+        .collect()
+}
+
+/// Get the evolution rule of a species, if it evolves
+pub fn get_evolution(species_id: u32) -> Option<Evolution> {
+    let json = find_species_json(species_id)?.get("evolution")?;
+    let evolution: EvolutionJSON = serde_json::from_value(json.clone()).ok()?;
+    Evolution::from_json(&evolution)
+}
+
+/// Check whether a species can learn a move from a TM or HM
+pub fn can_learn_machine_move(species_id: u32, move_id: u32) -> bool {
+    get_move_id_list(species_id, "machine_moves").contains(&move_id)
+}
+
+/// Get the moves a move tutor can teach a species
+pub fn get_tutor_moves(species_id: u32) -> Vec<Move> {
+    get_move_id_list(species_id, "tutor_moves")
+        .into_iter()
+        .filter_map(get_move_by_id)
+        .collect()
+}
+
+/// Get the learnset moves a Pokemon could already know at its level but has forgotten
+pub fn get_relearnable_moves(pokemon: &Pokemon) -> Vec<Move> {
+    let mut move_ids: Vec<u32> = Vec::new();
+    for level in 1..=pokemon.level {
+        for move_id in get_learnset(pokemon.id).moves_learned_at(level) {
+            if !move_ids.contains(&move_id) && !pokemon.knows_move(move_id) {
+                move_ids.push(move_id);
+            }
+        }
+    }
+    move_ids.into_iter().filter_map(get_move_by_id).collect()
+}
+
+/// Check whether a species can learn a move at all: by level-up, TM/HM or move
+/// tutor, either itself or as one of its pre-evolutions
+pub fn can_learn_move(species_id: u32, move_id: u32) -> bool {
+    get_evolution_line(species_id).into_iter().any(|id| {
+        get_learnset(id).contains(move_id)
+            || can_learn_machine_move(id, move_id)
+            || get_move_id_list(id, "tutor_moves").contains(&move_id)
+    })
+}
+
+/// Find a species ID by its English or Chinese name (case-insensitive for English)
+pub fn find_species_id_by_name(name: &str) -> Option<u32> {
+    loader::get_game_data()?
+        .pokemon
+        .iter()
+        .find(|p| json_name_matches(p, name))
+        .and_then(|p| p.get("id")?.as_u64())
+        .map(|id| id as u32)
+}
+
+/// Get every species ID in species.json, sorted by Pokédex number
+pub fn get_all_species_ids() -> Vec<u32> {
+    let mut ids: Vec<u32> = loader::get_game_data()
+        .map(|data| {
+            data.pokemon
+                .iter()
+                .filter_map(|p| p.get("id")?.as_u64())
+                .map(|id| id as u32)
+                .collect()
+        })
+        .unwrap_or_default();
+    ids.sort_unstable();
+    ids
+}
+
+/// Get the Pokédex text, height (m) and weight (kg) of a species.
+/// Species without these fields get an empty text and zero measurements
+pub fn get_pokedex_data(species_id: u32) -> Option<(String, f32, f32)> {
+    let species = find_species_json(species_id)?;
+    let entry = species
+        .get("pokedex_entry")
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_string();
+    let height = species.get("height").and_then(|v| v.as_f64()).unwrap_or(0.0) as f32;
+    let weight = species.get("weight").and_then(|v| v.as_f64()).unwrap_or(0.0) as f32;
+    Some((entry, height, weight))
+}
+
+/// Get the English name of a species, if species.json has one
+pub fn get_species_english_name(species_id: u32) -> Option<String> {
+    find_species_json(species_id)
+        .and_then(|p| p.get("english_name")?.as_str())
+        .map(|name| name.to_string())
+}
+
+/// Find a move by its English or Chinese name (case-insensitive for English)
+pub fn get_move_by_name(name: &str) -> Option<Move> {
+    loader::get_game_data()?
+        .moves
+        .iter()
+        .find(|m| json_name_matches(m, name))
+        .and_then(parse_move_from_json)
+}
+
+/// Get the English name of a move, if moves.json has one
+pub fn get_move_english_name(move_id: u32) -> Option<String> {
+    loader::get_game_data()?
+        .moves
+        .iter()
+        .find(|m| m.get("id").and_then(|v| v.as_u64()) == Some(move_id as u64))
+        .and_then(|m| m.get("english_name")?.as_str())
+        .map(|name| name.to_string())
+}
+
+/// Whether a species or move entry is called `name` in Chinese or English
+fn json_name_matches(entry: &serde_json::Value, name: &str) -> bool {
+    entry.get("name").and_then(|v| v.as_str()) == Some(name)
+        || entry
+            .get("english_name")
+            .and_then(|v| v.as_str())
+            .map(|english| english.eq_ignore_ascii_case(name))
+            .unwrap_or(false)
+}
+
+/// Get the field ability granted by a move, if an HM teaches it
+pub fn get_field_ability(move_id: u32) -> Option<FieldAbility> {
+    loader::get_game_data()?
+        .items
+        .iter()
+        .filter(|item| item.get("item_type").and_then(|v| v.as_str()) == Some("HM"))
+        .find(|item| item.get("move_id").and_then(|v| v.as_u64()) == Some(move_id as u64))
+        .and_then(|item| item.get("field_ability")?.as_str())
+        .and_then(FieldAbility::from_name)
+}
+
+/// Read a list of move IDs (such as "machine_moves") from a species entry
+fn get_move_id_list(species_id: u32, key: &str) -> Vec<u32> {
+    find_species_json(species_id)
+        .and_then(|p| p.get(key))
+        .and_then(|v| v.as_array())
+        .map(|ids| ids.iter().filter_map(|id| id.as_u64()).map(|id| id as u32).collect())
+        .unwrap_or_default()
+}
+
+/// Get the female share of a species (0.0-1.0), or None for genderless species.
+/// Species without a "female_ratio" default to an even split
+pub fn get_female_ratio(species_id: u32) -> Option<f32> {
+    let species = find_species_json(species_id);
+    if species.and_then(|p| p.get("genderless")).and_then(|v| v.as_bool()) == Some(true) {
+        return None;
+    }
+    Some(
+        species
+            .and_then(|p| p.get("female_ratio"))
+            .and_then(|v| v.as_f64())
+            .unwrap_or(0.5) as f32,
+    )
+}
+
+/// Get the egg groups of a species from species.json, falling back to the
+/// generator's species table. Species with no egg group cannot breed
+pub fn get_egg_groups(species_id: u32) -> Vec<String> {
+    if let Some(groups) = find_species_json(species_id)
+        .and_then(|p| p.get("egg_groups"))
+        .and_then(|v| v.as_array())
+    {
+        return groups
+            .iter()
+            .filter_map(|g| g.as_str().map(|g| g.to_string()))
+            .collect();
+    }
+    crate::pokemon_generator::get_species(species_id)
+        .map(|species| vec![species.egg_group])
+        .unwrap_or_default()
+}
+
+/// Get the ability ID a species has with the given talent (the "abilities" field of species.json)
+pub fn get_ability_id(species_id: u32, talent: Talent) -> Option<u32> {
+    let slot = match talent {
+        Talent::Normal => "normal",
+        Talent::Hidden => "hidden",
+    };
+    find_species_json(species_id)?
+        .get("abilities")?
+        .get(slot)?
+        .as_u64()
+        .map(|id| id as u32)
+}
+
+/// Get the first stage of a species' evolution line (what hatches from its eggs)
+pub fn get_base_species(species_id: u32) -> u32 {
+    get_evolution_line(species_id).last().copied().unwrap_or(species_id)
+}
+
+/// Get a species followed by each of its pre-evolutions, back to the first stage
+pub fn get_evolution_line(species_id: u32) -> Vec<u32> {
+    let mut line = vec![species_id];
+    let game_data = match loader::get_game_data() {
+        Some(data) => data,
+        None => return line,
+    };
+
+    let mut current = species_id;
+    // Evolution lines are short; the bound guards against cycles in the data
+    for _ in 0..game_data.pokemon.len() {
+        let pre_evolution = game_data.pokemon.iter().find(|p| {
+            p.get("evolution")
+                .and_then(|e| e.get("to"))
+                .and_then(|v| v.as_u64())
+                .map(|v| v as u32)
+                == Some(current)
+        });
+        match pre_evolution.and_then(|p| p.get("id")).and_then(|v| v.as_u64()) {
+            Some(id) => {
+                current = id as u32;
+                line.push(current);
+            }
+            None => break,
+        }
+    }
+    line
+}
+
+/// Get how many steps an egg of a species needs before hatching, from
+/// species.json or else game_constants.json, defaulting to 10
+pub fn get_hatch_steps(species_id: u32) -> u32 {
+    find_species_json(species_id)
+        .and_then(|p| p.get("hatch_steps"))
+        .and_then(|v| v.as_u64())
+        .or_else(|| loader::get_game_constant("breeding", "hatch_steps"))
+        .unwrap_or(10) as u32
+}
+
+/// Get the shiny odds (1 in N) from game_constants.json, defaulting to 1 in 4096
+pub fn get_shiny_odds() -> u32 {
+    loader::get_game_constant("pokemon", "shiny_odds")
+        .map(|odds| odds as u32)
+        .unwrap_or(4096)
+}
+
+/// Get the growth rate (experience curve) of a species, defaulting to Medium Fast
+pub fn get_growth_rate(species_id: u32) -> GrowthRate {
+    find_species_json(species_id)
+        .map(parse_growth_rate)
+        .unwrap_or_default()
+}
+
+/// Get the base experience yield of a species (used when it is defeated)
+pub fn get_experience_yield(species_id: u32) -> u32 {
+    find_species_json(species_id)
+        .and_then(|p| p.get("experience_yield"))
+        .and_then(|v| v.as_u64())
+        .unwrap_or(50) as u32
+}
+
+/// Get the effort values a species yields when defeated
+pub fn get_ev_yield(species_id: u32) -> EffortValues {
+    let mut ev_yield = EffortValues::default();
+    if let Some(yields) = find_species_json(species_id)
+        .and_then(|p| p.get("ev_yield"))
+        .and_then(|v| v.as_object())
+    {
+        for (stat, amount) in yields {
+            ev_yield.add(stat, amount.as_u64().unwrap_or(0) as u32);
+        }
+    }
+    ev_yield
+}
+
+/// Find the raw species JSON entry by ID
+fn find_species_json(species_id: u32) -> Option<&'static serde_json::Value> {
+    loader::get_game_data()?.pokemon.iter().find(|p| {
+        p.get("id").and_then(|v| v.as_u64()).map(|v| v as u32) == Some(species_id)
+    })
+}
+
+/// Parse the "growth_rate" field of a species entry
+fn parse_growth_rate(data: &serde_json::Value) -> GrowthRate {
+    data.get("growth_rate")
+        .and_then(|v| v.as_str())
+        .and_then(GrowthRate::from_name)
+        .unwrap_or_default()
+}
+
 // ============================================================================
 // Phase 3: Move Loading Functions
 // ============================================================================
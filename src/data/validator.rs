@@ -40,6 +40,91 @@ pub fn validate_all_data(
     }
 }
 
+/// Validate achievement definitions: unique IDs, a name and a definition that parses with a known condition
+pub fn validate_achievement_data(achievements: &[Value]) -> Result<(), Vec<String>> {
+    let mut errors = Vec::new();
+    let mut ids = HashSet::new();
+
+    for (idx, achievement) in achievements.iter().enumerate() {
+        match achievement.get("id").and_then(|v| v.as_u64()) {
+            Some(id) if !ids.insert(id) => errors.push(format!("Achievement {}: duplicate id", id)),
+            Some(_) => {}
+            None => errors.push(format!("Achievement at index {}: missing or invalid 'id'", idx)),
+        }
+
+        if achievement.get("name").and_then(|v| v.as_str()).is_none() {
+            errors.push(format!("Achievement at index {}: missing or invalid 'name'", idx));
+        }
+
+        if let Err(e) = serde_json::from_value::<crate::game::Achievement>(achievement.clone()) {
+            errors.push(format!("Achievement at index {}: invalid definition ({})", idx, e));
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Validate quests and quest NPCs: unique IDs, definitions that parse with known objectives,
+/// and valid NPC and prerequisite references
+pub fn validate_quest_data(quests: &[Value], npcs: &[Value]) -> Result<(), Vec<String>> {
+    let mut errors = Vec::new();
+    let quest_ids: HashSet<u64> = quests.iter().filter_map(|q| q.get("id").and_then(|v| v.as_u64())).collect();
+    let npc_ids: HashSet<u64> = npcs.iter().filter_map(|n| n.get("id").and_then(|v| v.as_u64())).collect();
+    if quest_ids.len() != quests.len() {
+        errors.push("Quests: missing or duplicate ids".to_string());
+    }
+    if npc_ids.len() != npcs.len() {
+        errors.push("Quest NPCs: missing or duplicate ids".to_string());
+    }
+
+    for (idx, quest) in quests.iter().enumerate() {
+        if quest.get("name").and_then(|v| v.as_str()).is_none() {
+            errors.push(format!("Quest at index {}: missing or invalid 'name'", idx));
+        }
+
+        match quest.get("npc_id").and_then(|v| v.as_u64()) {
+            Some(npc_id) if !npc_ids.contains(&npc_id) => {
+                errors.push(format!("Quest at index {}: unknown npc_id {}", idx, npc_id))
+            }
+            Some(_) => {}
+            None => errors.push(format!("Quest at index {}: missing or invalid 'npc_id'", idx)),
+        }
+
+        let prerequisites = quest.get("prerequisites").and_then(|v| v.as_array());
+        for prerequisite in prerequisites.into_iter().flatten().filter_map(|v| v.as_u64()) {
+            if !quest_ids.contains(&prerequisite) {
+                errors.push(format!("Quest at index {}: unknown prerequisite {}", idx, prerequisite));
+            }
+        }
+
+        if let Err(e) = serde_json::from_value::<crate::game::Quest>(quest.clone()) {
+            errors.push(format!("Quest at index {}: invalid definition ({})", idx, e));
+        }
+    }
+
+    for (idx, npc) in npcs.iter().enumerate() {
+        if let Err(e) = serde_json::from_value::<crate::npc::QuestNpc>(npc.clone()) {
+            errors.push(format!("Quest NPC at index {}: invalid definition ({})", idx, e));
+        }
+        let offered = npc.get("quest_ids").and_then(|v| v.as_array());
+        for quest_id in offered.into_iter().flatten().filter_map(|v| v.as_u64()) {
+            if !quest_ids.contains(&quest_id) {
+                errors.push(format!("Quest NPC at index {}: unknown quest {}", idx, quest_id));
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
 /// Validate items data
 pub fn validate_items_data(items: &[Value]) -> Result<(), Vec<String>> {
     let mut errors = Vec::new();
@@ -64,6 +149,66 @@ pub fn validate_items_data(items: &[Value]) -> Result<(), Vec<String>> {
                 errors.push(format!("Item at index {}: price cannot be 0", idx));
             }
         }
+
+        // The item type must be one the game knows; recovery items need a percentage
+        let item_type = item.get("item_type").and_then(|v| v.as_str()).unwrap_or("");
+        if !item_type.is_empty() && crate::game::ItemType::from_name(item_type).is_none() {
+            errors.push(format!("Item at index {}: unknown 'item_type' '{}'", idx, item_type));
+        }
+        let restores_hp = ["is_healing", "is_revive"]
+            .iter()
+            .any(|key| item.get(*key).and_then(|v| v.as_bool()).unwrap_or(false));
+        if restores_hp {
+            match item.get("recovery_percent").and_then(|v| v.as_u64()) {
+                Some(1..=100) => {}
+                _ => errors.push(format!("Item at index {}: missing or invalid 'recovery_percent'", idx)),
+            }
+        }
+
+        // EV-adjusting items must name a stat and a non-zero change
+        if item_type == "Vitamin" || item_type == "EVBerry" {
+            let stat = item.get("ev_stat").and_then(|v| v.as_str()).unwrap_or("");
+            if !crate::pokemon_generator::EffortValues::STAT_KEYS.contains(&stat) {
+                errors.push(format!("Item at index {}: missing or invalid 'ev_stat'", idx));
+            }
+
+            match item.get("ev_change").and_then(|v| v.as_i64()) {
+                Some(change) if item_type == "Vitamin" && change > 0 => {}
+                Some(change) if item_type == "EVBerry" && change < 0 => {}
+                _ => errors.push(format!("Item at index {}: invalid 'ev_change' for {}", idx, item_type)),
+            }
+        }
+
+        // TMs and HMs must name the move they teach; HMs also need a known field ability
+        if (item_type == "TM" || item_type == "HM") && item.get("move_id").and_then(|v| v.as_u64()).is_none() {
+            errors.push(format!("Item at index {}: missing or invalid 'move_id'", idx));
+        }
+        if item_type == "HM" {
+            let ability = item.get("field_ability").and_then(|v| v.as_str()).unwrap_or("");
+            if crate::game::FieldAbility::from_name(ability).is_none() {
+                errors.push(format!("Item at index {}: unknown 'field_ability' '{}'", idx, ability));
+            }
+        }
+
+        // Day-care items must name a known breeding effect
+        if item_type == "BreedingItem" {
+            let effect = item.get("breeding_effect").and_then(|v| v.as_str()).unwrap_or("");
+            if crate::game::breeding::BreedingItem::from_name(effect).is_none() {
+                errors.push(format!("Item at index {}: unknown 'breeding_effect' '{}'", idx, effect));
+            }
+        }
+
+        // Repels and lures last a number of steps; lures and held items may scale the encounter rate
+        if (item_type == "Repel" || item_type == "Lure")
+            && item.get("effect_steps").and_then(|v| v.as_u64()).is_none_or(|steps| steps == 0)
+        {
+            errors.push(format!("Item at index {}: missing or invalid 'effect_steps'", idx));
+        }
+        if let Some(multiplier) = item.get("encounter_rate_multiplier") {
+            if multiplier.as_f64().is_none_or(|m| m <= 0.0) {
+                errors.push(format!("Item at index {}: invalid 'encounter_rate_multiplier'", idx));
+            }
+        }
     }
 
     if errors.is_empty() {
@@ -192,10 +337,56 @@ pub fn validate_natures(natures: &[Value]) -> Result<(), Vec<String>> {
     }
 }
 
+/// Validate abilities: unique IDs, definitions that parse, positive encounter multipliers,
+/// and a known normal and hidden ability for every species
+pub fn validate_abilities(abilities: &[Value], pokemon: &[Value]) -> Result<(), Vec<String>> {
+    let mut errors = Vec::new();
+    let mut ids = HashSet::new();
+
+    for (idx, ability) in abilities.iter().enumerate() {
+        match ability.get("id").and_then(|v| v.as_u64()) {
+            Some(id) if !ids.insert(id) => errors.push(format!("Ability {}: duplicate id", id)),
+            Some(_) => {}
+            None => errors.push(format!("Ability at index {}: missing or invalid 'id'", idx)),
+        }
+
+        if let Err(e) = serde_json::from_value::<crate::game::Ability>(ability.clone()) {
+            errors.push(format!("Ability at index {}: invalid definition ({})", idx, e));
+        }
+        if let Some(multiplier) = ability.get("encounter_rate_multiplier") {
+            if multiplier.as_f64().is_none_or(|m| m <= 0.0) {
+                errors.push(format!("Ability at index {}: invalid 'encounter_rate_multiplier'", idx));
+            }
+        }
+    }
+
+    for (idx, species) in pokemon.iter().enumerate() {
+        for slot in ["normal", "hidden"] {
+            match species.get("abilities").and_then(|a| a.get(slot)).and_then(|v| v.as_u64()) {
+                Some(id) if !ids.contains(&id) => {
+                    errors.push(format!("Pokemon at index {}: unknown {} ability {}", idx, slot, id))
+                }
+                Some(_) => {}
+                None => errors.push(format!("Pokemon at index {}: missing or invalid '{}' ability", idx, slot)),
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
 /// Validate Pokémon species data
 fn validate_pokemon_data(pokemon: &[Value]) -> Result<(), Vec<String>> {
     let mut errors = Vec::new();
     let mut seen_ids = HashSet::new();
+    let all_ids: HashSet<u64> = pokemon
+        .iter()
+        .filter_map(|p| p.get("id").and_then(|v| v.as_u64()))
+        .collect();
 
     for (idx, poke) in pokemon.iter().enumerate() {
         // Check required fields
@@ -215,6 +406,95 @@ fn validate_pokemon_data(pokemon: &[Value]) -> Result<(), Vec<String>> {
             errors.push(format!("Pokémon at index {}: missing 'base_stats'", idx));
         }
 
+        // Growth rate is optional, but must name a known curve when present
+        if let Some(rate) = poke.get("growth_rate").and_then(|v| v.as_str()) {
+            if crate::game::GrowthRate::from_name(rate).is_none() {
+                errors.push(format!("Pokémon at index {}: unknown 'growth_rate' {}", idx, rate));
+            }
+        }
+
+        // Evolution is optional, but must use a known method and target an existing species
+        if let Some(evolution) = poke.get("evolution").filter(|v| !v.is_null()) {
+            let method = evolution.get("method").and_then(|v| v.as_str()).unwrap_or("");
+            match crate::game::EvolutionMethod::from_name(method) {
+                Some(crate::game::EvolutionMethod::Level) => {
+                    let level = evolution.get("trigger").and_then(|v| v.as_u64()).unwrap_or(0);
+                    if !(1..=100).contains(&level) {
+                        errors.push(format!("Pokémon at index {}: evolution level {} out of range", idx, level));
+                    }
+                }
+                Some(_) => {}
+                None => errors.push(format!("Pokémon at index {}: unknown evolution method '{}'", idx, method)),
+            }
+
+            match evolution.get("to").and_then(|v| v.as_u64()) {
+                Some(to) if all_ids.contains(&to) => {}
+                Some(to) => errors.push(format!("Pokémon at index {}: evolves to unknown species {}", idx, to)),
+                None => errors.push(format!("Pokémon at index {}: evolution missing 'to'", idx)),
+            }
+        }
+
+        // Learnset is optional, but every entry needs a move ID and a level in 1-100
+        if let Some(learnset) = poke.get("learnset") {
+            match learnset.as_array() {
+                Some(entries) => {
+                    for entry in entries {
+                        let level = entry.get("level").and_then(|v| v.as_u64()).unwrap_or(0);
+                        if !(1..=100).contains(&level) {
+                            errors.push(format!("Pokémon at index {}: learnset level {} out of range", idx, level));
+                        }
+                        if entry.get("move_id").and_then(|v| v.as_u64()).is_none() {
+                            errors.push(format!("Pokémon at index {}: learnset entry missing 'move_id'", idx));
+                        }
+                    }
+                }
+                None => errors.push(format!("Pokémon at index {}: invalid 'learnset'", idx)),
+            }
+        }
+
+        // TM/HM and tutor compatibility lists are optional, but must hold move IDs
+        for key in ["machine_moves", "tutor_moves"] {
+            if let Some(list) = poke.get(key) {
+                let valid = list
+                    .as_array()
+                    .map(|ids| ids.iter().all(|id| id.as_u64().is_some()))
+                    .unwrap_or(false);
+                if !valid {
+                    errors.push(format!("Pokémon at index {}: invalid '{}'", idx, key));
+                }
+            }
+        }
+
+        // Egg groups are optional, but must be a non-empty list of names
+        if let Some(groups) = poke.get("egg_groups") {
+            let valid = groups
+                .as_array()
+                .map(|names| !names.is_empty() && names.iter().all(|g| g.as_str().is_some()))
+                .unwrap_or(false);
+            if !valid {
+                errors.push(format!("Pokémon at index {}: invalid 'egg_groups'", idx));
+            }
+        }
+
+        // EV yield is optional, but must use known stat keys and stay within 1-3 points
+        if let Some(ev_yield) = poke.get("ev_yield") {
+            match ev_yield.as_object() {
+                Some(yields) => {
+                    let mut total = 0;
+                    for (stat, amount) in yields {
+                        if !crate::pokemon_generator::EffortValues::STAT_KEYS.contains(&stat.as_str()) {
+                            errors.push(format!("Pokémon at index {}: unknown 'ev_yield' stat {}", idx, stat));
+                        }
+                        total += amount.as_u64().unwrap_or(0);
+                    }
+                    if !(1..=3).contains(&total) {
+                        errors.push(format!("Pokémon at index {}: 'ev_yield' total must be 1-3, got {}", idx, total));
+                    }
+                }
+                None => errors.push(format!("Pokémon at index {}: invalid 'ev_yield'", idx)),
+            }
+        }
+
         // Check for duplicate IDs
         if let Some(id) = poke.get("id").and_then(|v| v.as_u64()) {
             if seen_ids.contains(&id) {
@@ -285,6 +565,163 @@ fn validate_location_data(locations: &[Value], pokemon: &[Value]) -> Result<(),
                         ));
                     }
                 }
+
+                if let Some(times) = spawn.get("times") {
+                    if serde_json::from_value::<Vec<crate::game::TimeOfDay>>(times.clone()).is_err() {
+                        errors.push(format!(
+                            "Location {}: invalid spawn times {}",
+                            location.get("id").and_then(|v| v.as_u64()).unwrap_or(0),
+                            times
+                        ));
+                    }
+                }
+
+                // Encounter methods must be known and usable in the location's environment
+                if let Some(method) = spawn.get("method") {
+                    let location_id = location.get("id").and_then(|v| v.as_u64()).unwrap_or(0);
+                    match serde_json::from_value::<crate::game::EncounterMethod>(method.clone()) {
+                        Ok(method) => {
+                            let environment = location
+                                .get("environment")
+                                .and_then(|v| serde_json::from_value::<crate::game::EnvironmentType>(v.clone()).ok());
+                            if environment.is_some_and(|env| !method.allowed_in(env)) {
+                                errors.push(format!(
+                                    "Location {}: encounter method {:?} is not available in this environment",
+                                    location_id, method
+                                ));
+                            }
+                        }
+                        Err(_) => errors.push(format!(
+                            "Location {}: unknown encounter method {}",
+                            location_id, method
+                        )),
+                    }
+                }
+            }
+        }
+
+        // Check services are known
+        if let Some(services) = location.get("services").and_then(|v| v.as_array()) {
+            for service in services {
+                let name = service.as_str().unwrap_or("");
+                if crate::game::LocationService::from_name(name).is_none() {
+                    errors.push(format!(
+                        "Location {}: unknown service '{}'",
+                        location.get("id").and_then(|v| v.as_u64()).unwrap_or(0),
+                        name
+                    ));
+                }
+            }
+        }
+
+        // Check gated connections point at a connection and name a key item or a known field move
+        if let Some(requirements) = location.get("connection_requirements").and_then(|v| v.as_array()) {
+            let location_id = location.get("id").and_then(|v| v.as_u64()).unwrap_or(0);
+            let connections: Vec<u64> = location
+                .get("connections")
+                .and_then(|v| v.as_array())
+                .map(|ids| ids.iter().filter_map(|id| id.as_u64()).collect())
+                .unwrap_or_default();
+            for requirement in requirements {
+                let before = errors.len();
+                let to = requirement.get("to").and_then(|v| v.as_u64());
+                if !to.is_some_and(|to| connections.contains(&to)) {
+                    errors.push(format!(
+                        "Location {}: connection requirement for {:?} is not one of its connections",
+                        location_id, to
+                    ));
+                }
+                let ability = requirement.get("field_ability").and_then(|v| v.as_str());
+                if ability.is_some_and(|name| crate::game::FieldAbility::from_name(name).is_none()) {
+                    errors.push(format!(
+                        "Location {}: unknown 'field_ability' '{}'",
+                        location_id,
+                        ability.unwrap_or("")
+                    ));
+                }
+                if ability.is_none() && requirement.get("key_item").and_then(|v| v.as_u64()).is_none() {
+                    errors.push(format!(
+                        "Location {}: connection requirement needs a 'key_item' or 'field_ability'",
+                        location_id
+                    ));
+                }
+                // Anything the checks above miss must still parse, the loader does not skip bad entries
+                if errors.len() == before {
+                    if let Err(e) = serde_json::from_value::<crate::game::ConnectionRequirement>(requirement.clone()) {
+                        errors.push(format!(
+                            "Location {}: invalid connection requirement for {:?} ({})",
+                            location_id, to, e
+                        ));
+                    }
+                }
+            }
+        }
+
+        // Check mart stock entries have an item ID and gate on badge IDs
+        if let Some(stock) = location.get("mart").and_then(|v| v.as_array()) {
+            let location_id = location.get("id").and_then(|v| v.as_u64()).unwrap_or(0);
+            for (entry_idx, entry) in stock.iter().enumerate() {
+                let before = errors.len();
+                if entry.get("item_id").and_then(|v| v.as_u64()).is_none() {
+                    errors.push(format!(
+                        "Location {}: mart entry {} missing or invalid 'item_id'",
+                        location_id, entry_idx
+                    ));
+                }
+                if entry.get("badges_required").is_some() {
+                    errors.push(format!(
+                        "Location {}: mart entry {} uses a badge count, list badge IDs in 'required_badges'",
+                        location_id, entry_idx
+                    ));
+                }
+                if let Some(badges) = entry.get("required_badges") {
+                    let valid = badges.as_array().is_some_and(|ids| ids.iter().all(|id| id.as_u64().is_some()));
+                    if !valid {
+                        errors.push(format!(
+                            "Location {}: mart entry {} has invalid 'required_badges'",
+                            location_id, entry_idx
+                        ));
+                    }
+                }
+                if errors.len() == before {
+                    if let Err(e) = serde_json::from_value::<crate::game::MartItem>(entry.clone()) {
+                        errors.push(format!(
+                            "Location {}: invalid mart entry {} ({})",
+                            location_id, entry_idx, e
+                        ));
+                    }
+                }
+            }
+        }
+
+        // Check gift items are a list of item IDs
+        if let Some(gifts) = location.get("gift_items") {
+            if serde_json::from_value::<Vec<u32>>(gifts.clone()).is_err() {
+                errors.push(format!(
+                    "Location {}: 'gift_items' must be a list of item IDs",
+                    location.get("id").and_then(|v| v.as_u64()).unwrap_or(0)
+                ));
+            }
+        }
+
+        // Check the unlock requirement parses and only uses known conditions
+        if let Some(requirement) = location.get("unlock_requirement") {
+            let location_id = location.get("id").and_then(|v| v.as_u64()).unwrap_or(0);
+            const CONDITIONS: [&str; 4] =
+                ["required_level", "required_badges", "required_pokemon_count", "required_flags"];
+            if let Some(key) = requirement
+                .as_object()
+                .and_then(|fields| fields.keys().find(|key| !CONDITIONS.contains(&key.as_str())))
+            {
+                errors.push(format!(
+                    "Location {}: unknown unlock condition '{}'",
+                    location_id, key
+                ));
+            } else if let Err(e) = serde_json::from_value::<crate::game::LocationRequirement>(requirement.clone()) {
+                errors.push(format!(
+                    "Location {}: invalid unlock_requirement ({})",
+                    location_id, e
+                ));
             }
         }
 
@@ -326,6 +763,7 @@ fn validate_trainer_data(
         .iter()
         .filter_map(|l| l.get("id").and_then(|v| v.as_u64()))
         .collect();
+    let mut badge_ids = HashSet::new();
 
     for (idx, trainer) in trainers.iter().enumerate() {
         // Check required fields
@@ -333,6 +771,19 @@ fn validate_trainer_data(
             errors.push(format!("Trainer at index {}: missing or invalid 'id'", idx));
         }
 
+        // Check that each gym badge is awarded by exactly one leader
+        if let Some(badge_id) = trainer.get("badge_id").filter(|v| !v.is_null()) {
+            match badge_id.as_u64() {
+                Some(badge_id) if !badge_ids.insert(badge_id) => errors.push(format!(
+                    "Trainer {}: badge #{} is already awarded by another trainer",
+                    trainer.get("id").and_then(|v| v.as_u64()).unwrap_or(0),
+                    badge_id
+                )),
+                Some(_) => {}
+                None => errors.push(format!("Trainer at index {}: invalid 'badge_id'", idx)),
+            }
+        }
+
         if !trainer.get("name").and_then(|v| v.as_str()).is_some() {
             errors.push(format!("Trainer at index {}: missing or invalid 'name'", idx));
         }
@@ -384,6 +835,78 @@ fn validate_trainer_data(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_validate_achievement_data() {
+        let achievements = vec![
+            json!({"id": 1, "name": "初次捕捉", "description": "捕捉 1 只宝可梦",
+                   "condition": {"type": "pokemon_caught", "target": 1}}),
+            json!({"id": 2, "name": "旅行家", "description": "到访所有地点", "condition": {"type": "all_locations_visited"}}),
+        ];
+        assert!(validate_achievement_data(&achievements).is_ok());
+
+        let achievements = vec![
+            json!({"id": 1, "name": "初次捕捉", "description": "捕捉 1 只宝可梦",
+                   "condition": {"type": "pokemon_caught", "target": 1}}),
+            json!({"id": 1, "name": "未知", "description": "钓鱼", "condition": {"type": "fishing"}}),
+        ];
+        let errors = validate_achievement_data(&achievements).unwrap_err();
+        assert_eq!(errors.len(), 2);
+    }
+
+    #[test]
+    fn test_validate_abilities() {
+        let abilities = vec![
+            json!({"id": 9, "name": "静电", "english_name": "Static", "description": ""}),
+            json!({"id": 22, "name": "威吓", "english_name": "Intimidate", "description": "",
+                   "encounter_rate_multiplier": 0.5}),
+        ];
+        let pokemon = vec![json!({"id": 25, "abilities": {"normal": 9, "hidden": 22}})];
+        assert!(validate_abilities(&abilities, &pokemon).is_ok());
+
+        let abilities = vec![
+            json!({"id": 9, "name": "静电", "english_name": "Static", "description": ""}),
+            json!({"id": 9, "name": "威吓", "english_name": "Intimidate", "description": "",
+                   "encounter_rate_multiplier": 0}),
+        ];
+        let pokemon = vec![json!({"id": 25, "abilities": {"normal": 9, "hidden": 22}}), json!({"id": 26})];
+        let errors = validate_abilities(&abilities, &pokemon).unwrap_err();
+        assert_eq!(errors.len(), 5);
+    }
+
+    #[test]
+    fn test_validate_quest_data() {
+        let npcs = vec![json!({"id": 1, "name": "大木博士", "location_id": 101, "quest_ids": [1, 2]})];
+        let quests = vec![
+            json!({"id": 1, "name": "调查", "description": "", "npc_id": 1, "objective": {"type": "catch_species", "species_id": 10}}),
+            json!({"id": 2, "name": "送信", "description": "", "npc_id": 1, "prerequisites": [1],
+                   "objective": {"type": "reach_location", "location_id": 105}}),
+        ];
+        assert!(validate_quest_data(&quests, &npcs).is_ok());
+
+        let quests = vec![
+            json!({"id": 1, "name": "调查", "description": "", "npc_id": 2, "objective": {"type": "catch_species", "species_id": 10}}),
+            json!({"id": 2, "name": "送信", "description": "", "npc_id": 1, "prerequisites": [3],
+                   "objective": {"type": "fishing"}}),
+        ];
+        let errors = validate_quest_data(&quests, &npcs).unwrap_err();
+        assert_eq!(errors.len(), 3);
+    }
+
+    #[test]
+    fn test_validate_trainer_badges() {
+        let pokemon = vec![json!({"id": 1})];
+        let locations = vec![json!({"id": 101})];
+        let leader = |id: u32, badge_id: u32| {
+            json!({"id": id, "name": "馆主", "location_id": 101, "team": [], "badge_id": badge_id})
+        };
+        let trainers = vec![leader(1, 1), leader(2, 2)];
+        assert!(validate_trainer_data(&trainers, &pokemon, &locations).is_ok());
+
+        let trainers = vec![leader(1, 1), leader(2, 1)];
+        let errors = validate_trainer_data(&trainers, &pokemon, &locations).unwrap_err();
+        assert!(errors[0].contains("badge #1"));
+    }
+
     #[test]
     fn test_validate_pokemon_data_valid() {
         let pokemon = vec![
@@ -408,4 +931,242 @@ mod tests {
 
         assert!(validate_pokemon_data(&pokemon).is_err());
     }
+
+    #[test]
+    fn test_validate_pokemon_data_unknown_growth_rate() {
+        let pokemon = vec![json!({
+            "id": 1,
+            "name": "Test",
+            "primary_type": "Normal",
+            "base_stats": {},
+            "growth_rate": "Sideways"
+        })];
+
+        assert!(validate_pokemon_data(&pokemon).is_err());
+    }
+
+    #[test]
+    fn test_validate_items_data_ev_items() {
+        let items = vec![
+            json!({"id": 7, "name": "HP增强剂", "item_type": "Vitamin", "price": 10000,
+                   "ev_stat": "hp", "ev_change": 10}),
+            json!({"id": 13, "name": "榴石果", "item_type": "EVBerry", "price": 200,
+                   "ev_stat": "hp", "ev_change": -10}),
+        ];
+        assert!(validate_items_data(&items).is_ok());
+
+        let bad_items = vec![
+            json!({"id": 7, "name": "HP增强剂", "item_type": "Vitamin", "price": 10000,
+                   "ev_stat": "luck", "ev_change": -10}),
+        ];
+        assert_eq!(validate_items_data(&bad_items).unwrap_err().len(), 2);
+    }
+
+    #[test]
+    fn test_validate_items_data_machines() {
+        let items = vec![
+            json!({"id": 21, "name": "招式学习器24", "item_type": "TM", "price": 3000, "move_id": 85}),
+            json!({"id": 27, "name": "秘传学习器03", "item_type": "HM", "move_id": 57, "field_ability": "surf"}),
+        ];
+        assert!(validate_items_data(&items).is_ok());
+
+        let bad_items = vec![
+            json!({"id": 21, "name": "招式学习器24", "item_type": "TM", "price": 3000}),
+            json!({"id": 27, "name": "秘传学习器03", "item_type": "HM", "move_id": 57, "field_ability": "dig"}),
+        ];
+        assert_eq!(validate_items_data(&bad_items).unwrap_err().len(), 2);
+    }
+
+    #[test]
+    fn test_validate_items_data_encounter_items() {
+        let items = vec![
+            json!({"id": 37, "name": "驱虫喷雾", "item_type": "Repel", "price": 350, "effect_steps": 100}),
+            json!({"id": 40, "name": "引虫香水", "item_type": "Lure", "price": 400, "effect_steps": 100,
+                   "encounter_rate_multiplier": 1.5}),
+        ];
+        assert!(validate_items_data(&items).is_ok());
+
+        let bad_items = vec![
+            json!({"id": 37, "name": "驱虫喷雾", "item_type": "Repel", "price": 350}),
+            json!({"id": 42, "name": "洁净之铃", "item_type": "HeldItem", "price": 5000,
+                   "encounter_rate_multiplier": -1}),
+        ];
+        assert_eq!(validate_items_data(&bad_items).unwrap_err().len(), 2);
+    }
+
+    #[test]
+    fn test_validate_breeding_data() {
+        let items = vec![
+            json!({"id": 30, "name": "命运红线", "item_type": "BreedingItem", "price": 10000,
+                   "breeding_effect": "destiny_knot"}),
+            json!({"id": 31, "name": "不变之石", "item_type": "BreedingItem", "price": 3000,
+                   "breeding_effect": "luck"}),
+        ];
+        assert_eq!(validate_items_data(&items).unwrap_err().len(), 1);
+
+        let pokemon = vec![
+            json!({"id": 1, "name": "A", "primary_type": "Grass", "base_stats": {},
+                   "egg_groups": ["怪兽", "植物"]}),
+            json!({"id": 2, "name": "B", "primary_type": "Grass", "base_stats": {}, "egg_groups": []}),
+        ];
+        assert_eq!(validate_pokemon_data(&pokemon).unwrap_err().len(), 1);
+    }
+
+    #[test]
+    fn test_validate_recovery_items() {
+        let items = vec![
+            json!({"id": 2, "name": "恢复药", "item_type": "Potion", "price": 150,
+                   "recovery_percent": 50, "is_revive": false, "is_healing": true}),
+            json!({"id": 4, "name": "全复活", "item_type": "Revive", "price": 200,
+                   "recovery_percent": null, "is_revive": true, "is_healing": false}),
+            json!({"id": 40, "name": "神秘道具", "item_type": "Mystery", "price": 100}),
+        ];
+        assert_eq!(validate_items_data(&items).unwrap_err().len(), 2);
+    }
+
+    #[test]
+    fn test_validate_location_mart() {
+        let locations = vec![json!({
+            "id": 1, "name": "城市", "connections": [],
+            "services": ["poke_mart"],
+            "mart": [
+                {"item_id": 1}, {"item_id": 4, "required_badges": [1, 2]}, {"required_badges": [1]},
+                {"item_id": 3, "badges_required": 2}, {"item_id": 6, "required_badges": 1}
+            ]
+        })];
+        let errors = validate_location_data(&locations, &[]).unwrap_err();
+        assert_eq!(errors.len(), 3);
+        assert!(errors[0].contains("mart entry 2"));
+        assert!(errors[1].contains("mart entry 3"));
+        assert!(errors[2].contains("mart entry 4"));
+
+        // 无法解析的条目会报错，而不是在加载时被丢弃
+        let locations = vec![json!({
+            "id": 1, "name": "城市", "connections": [],
+            "mart": [{"item_id": 1}, {"item_id": 5000000000u64}, "item_id"],
+            "gift_items": [1, "2"]
+        })];
+        let errors = validate_location_data(&locations, &[]).unwrap_err();
+        assert_eq!(errors.len(), 3);
+        assert!(errors[0].contains("invalid mart entry 1"));
+        assert!(errors[1].contains("mart entry 2"));
+        assert!(errors[2].contains("gift_items"));
+    }
+
+    #[test]
+    fn test_validate_spawn_times() {
+        let pokemon = vec![json!({"id": 10})];
+        let locations = vec![json!({
+            "id": 1, "name": "森林", "connections": [],
+            "wild_pokemon": [
+                {"pokemon_id": 10, "times": ["morning", "night"]},
+                {"pokemon_id": 10, "times": ["evening"]}
+            ]
+        })];
+        let errors = validate_location_data(&locations, &pokemon).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].contains("spawn times"));
+    }
+
+    #[test]
+    fn test_validate_encounter_methods() {
+        let pokemon = vec![json!({"id": 129})];
+        let locations = vec![json!({
+            "id": 1, "name": "海边", "environment": "Water", "connections": [],
+            "wild_pokemon": [
+                {"pokemon_id": 129, "method": "old_rod"},
+                {"pokemon_id": 129, "method": "rock_smash"},
+                {"pokemon_id": 129, "method": "net"}
+            ]
+        })];
+        let errors = validate_location_data(&locations, &pokemon).unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert!(errors[0].contains("not available"));
+        assert!(errors[1].contains("unknown encounter method"));
+    }
+
+    #[test]
+    fn test_validate_connection_requirements() {
+        let locations = vec![
+            json!({"id": 1, "name": "A", "connections": [2],
+                   "connection_requirements": [{"to": 2, "field_ability": "surf"}]}),
+            json!({"id": 2, "name": "B", "connections": [1],
+                   "connection_requirements": [{"to": 3, "key_item": 32}, {"to": 1, "field_ability": "dig"}, {"to": 1}]}),
+        ];
+        let errors = validate_location_data(&locations, &[]).unwrap_err();
+        assert_eq!(errors.len(), 3);
+
+        let locations = vec![
+            json!({"id": 1, "name": "A", "connections": [2],
+                   "connection_requirements": [{"to": 2, "field_ability": "surf", "key_item": "32"}]}),
+            json!({"id": 2, "name": "B", "connections": [1]}),
+        ];
+        let errors = validate_location_data(&locations, &[]).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].contains("invalid connection requirement"));
+    }
+
+    #[test]
+    fn test_validate_unlock_requirement() {
+        let locations = vec![
+            json!({"id": 1, "name": "A", "connections": [],
+                   "unlock_requirement": {"required_level": 15, "required_badges": [1], "required_flags": ["pass"]}}),
+            json!({"id": 2, "name": "B", "connections": [],
+                   "unlock_requirement": {"required_badges": 1}}),
+            json!({"id": 3, "name": "C", "connections": [],
+                   "unlock_requirement": {"required_badge": [1]}}),
+            json!({"id": 4, "name": "D", "connections": [], "unlock_requirement": "badge"}),
+        ];
+        let errors = validate_location_data(&locations, &[]).unwrap_err();
+        assert_eq!(errors.len(), 3);
+        assert!(errors[0].contains("Location 2: invalid unlock_requirement"));
+        assert!(errors[1].contains("Location 3: unknown unlock condition 'required_badge'"));
+        assert!(errors[2].contains("Location 4: invalid unlock_requirement"));
+    }
+
+    #[test]
+    fn test_validate_pokemon_data_evolution() {
+        let pokemon = vec![
+            json!({"id": 1, "name": "A", "primary_type": "Grass", "base_stats": {},
+                   "evolution": {"method": "level", "trigger": 16, "to": 2}}),
+            json!({"id": 2, "name": "B", "primary_type": "Grass", "base_stats": {}}),
+        ];
+        assert!(validate_pokemon_data(&pokemon).is_ok());
+
+        let broken = vec![
+            json!({"id": 1, "name": "A", "primary_type": "Grass", "base_stats": {},
+                   "evolution": {"method": "moon", "trigger": 0, "to": 99}}),
+        ];
+        assert_eq!(validate_pokemon_data(&broken).unwrap_err().len(), 2);
+    }
+
+    #[test]
+    fn test_validate_pokemon_data_learnset() {
+        let pokemon = vec![json!({
+            "id": 1, "name": "A", "primary_type": "Grass", "base_stats": {},
+            "learnset": [{"level": 1, "move_id": 1}, {"level": 7, "move_id": 22}]
+        })];
+        assert!(validate_pokemon_data(&pokemon).is_ok());
+
+        let broken = vec![json!({
+            "id": 1, "name": "A", "primary_type": "Grass", "base_stats": {},
+            "learnset": [{"level": 0, "move_id": 1}, {"level": 7}]
+        })];
+        assert_eq!(validate_pokemon_data(&broken).unwrap_err().len(), 2);
+    }
+
+    #[test]
+    fn test_validate_pokemon_data_invalid_ev_yield() {
+        let pokemon = vec![json!({
+            "id": 1,
+            "name": "Test",
+            "primary_type": "Normal",
+            "base_stats": {},
+            "ev_yield": {"luck": 1, "speed": 3}
+        })];
+
+        let errors = validate_pokemon_data(&pokemon).unwrap_err();
+        assert!(errors.iter().any(|e| e.contains("unknown 'ev_yield' stat luck")));
+        assert!(errors.iter().any(|e| e.contains("total must be 1-3")));
+    }
 }
@@ -0,0 +1,32 @@
+use super::Pokemon;
+use crate::data::{loader, pokemon_data};
+use serde::{Deserialize, Serialize};
+
+/// abilities.json 中定义的一个特性
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Ability {
+    pub id: u32,
+    pub name: String,
+    pub english_name: String,
+    pub description: String,
+    /// 领头时对遭遇率的倍率 (如威吓为 0.5)
+    #[serde(default)]
+    pub encounter_rate_multiplier: Option<f32>,
+}
+
+impl Ability {
+    /// 按 ID 从 abilities.json 查找特性
+    pub fn get(id: u32) -> Option<Ability> {
+        loader::get_ability_by_id(id).and_then(|ability| serde_json::from_value(ability).ok())
+    }
+
+    /// 宝可梦的特性：由物种和天赋 (普通 / 隐藏) 决定
+    pub fn of(pokemon: &Pokemon) -> Option<Ability> {
+        pokemon_data::get_ability_id(pokemon.id, pokemon.talent).and_then(Self::get)
+    }
+
+    /// 名称 (中文名或英文名) 是否指这个特性
+    pub fn matches_name(&self, name: &str) -> bool {
+        self.name == name || self.english_name.eq_ignore_ascii_case(name)
+    }
+}
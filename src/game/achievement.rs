@@ -0,0 +1,127 @@
+use super::stats::now_timestamp;
+use super::Player;
+use crate::data::loader;
+use serde::{Deserialize, Serialize};
+
+/// 成就的达成条件 (对应 achievements.json 中的 condition)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AchievementCondition {
+    PokemonCaught { target: u32 },   // 累计捕捉的宝可梦数量
+    SpeciesCaught { target: u32 },   // 图鉴中捕捉过的物种数量
+    Badges { target: u32 },          // 徽章数量
+    FlawlessGymWins { target: u32 }, // 没有宝可梦昏迷地击败馆主的次数
+    MaxLevel { target: u32 },        // 队伍中最高的等级
+    AllLocationsVisited,             // 到访所有地点
+    BattlesWon { target: u32 },      // 赢得的战斗数量
+    Steps { target: u32 },           // 移动次数
+}
+
+impl AchievementCondition {
+    /// 当前进度与目标值 (进度不超过目标)
+    pub fn progress(&self, player: &Player, total_locations: usize) -> (u32, u32) {
+        let stats = &player.stats;
+        let (current, target) = match *self {
+            AchievementCondition::PokemonCaught { target } => (stats.pokemon_caught, target),
+            AchievementCondition::SpeciesCaught { target } => (player.pokedex.caught.len() as u32, target),
+            AchievementCondition::Badges { target } => (player.badges.len() as u32, target),
+            AchievementCondition::FlawlessGymWins { target } => (stats.flawless_gym_wins, target),
+            AchievementCondition::MaxLevel { target } => {
+                (player.pokemons.iter().map(|p| p.level).max().unwrap_or(0), target)
+            }
+            AchievementCondition::AllLocationsVisited => {
+                (player.location_state.visited_count() as u32, total_locations as u32)
+            }
+            AchievementCondition::BattlesWon { target } => {
+                (stats.wild_battles_won + stats.trainer_battles_won, target)
+            }
+            AchievementCondition::Steps { target } => (stats.steps, target),
+        };
+        (current.min(target), target)
+    }
+}
+
+/// achievements.json 中定义的一个成就
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Achievement {
+    pub id: u32,
+    pub name: String,
+    pub description: String,
+    pub condition: AchievementCondition,
+}
+
+impl Achievement {
+    /// achievements.json 中的所有成就 (加载数据时已经校验过，每一条都能解析)
+    pub fn all() -> Vec<Achievement> {
+        loader::get_game_data()
+            .map(|data| {
+                data.achievements
+                    .iter()
+                    .map(|achievement| {
+                        serde_json::from_value(achievement.clone()).expect("成就数据在加载时已校验")
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// 是否已经满足达成条件
+    pub fn is_complete(&self, player: &Player, total_locations: usize) -> bool {
+        let (current, target) = self.condition.progress(player, total_locations);
+        current >= target
+    }
+}
+
+/// 解锁所有新达成的成就，记录解锁时间并返回它们
+pub fn unlock_new(player: &mut Player, achievements: &[Achievement], total_locations: usize) -> Vec<Achievement> {
+    let unlocked: Vec<Achievement> = achievements
+        .iter()
+        .filter(|achievement| !player.achievements.contains_key(&achievement.id))
+        .filter(|achievement| achievement.is_complete(player, total_locations))
+        .cloned()
+        .collect();
+    let timestamp = now_timestamp();
+    for achievement in &unlocked {
+        player.achievements.insert(achievement.id, timestamp);
+    }
+    unlocked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn achievement(id: u32, condition: AchievementCondition) -> Achievement {
+        Achievement { id, name: format!("成就{}", id), description: String::new(), condition }
+    }
+
+    #[test]
+    fn test_condition_from_json() {
+        let condition: AchievementCondition =
+            serde_json::from_str(r#"{"type": "species_caught", "target": 10}"#).unwrap();
+        assert_eq!(condition, AchievementCondition::SpeciesCaught { target: 10 });
+        let condition: AchievementCondition = serde_json::from_str(r#"{"type": "all_locations_visited"}"#).unwrap();
+        assert_eq!(condition, AchievementCondition::AllLocationsVisited);
+    }
+
+    #[test]
+    fn test_unlock_new_achievements() {
+        let achievements = vec![
+            achievement(1, AchievementCondition::PokemonCaught { target: 1 }),
+            achievement(2, AchievementCondition::Steps { target: 10 }),
+            achievement(3, AchievementCondition::AllLocationsVisited),
+        ];
+        let mut player = Player::new("测试".to_string());
+        assert!(unlock_new(&mut player, &achievements, 2).is_empty());
+
+        player.stats.pokemon_caught = 1;
+        player.stats.steps = 4;
+        let unlocked = unlock_new(&mut player, &achievements, 1);
+        assert_eq!(unlocked.iter().map(|a| a.id).collect::<Vec<_>>(), vec![1, 3]);
+        assert_eq!(achievements[1].condition.progress(&player, 1), (4, 10));
+
+        // 已经解锁的成就不会再次通知
+        assert!(unlock_new(&mut player, &achievements, 1).is_empty());
+        assert_eq!(player.achievements.len(), 2);
+    }
+}
@@ -0,0 +1,139 @@
+use super::{EnvironmentBonus, FieldAbility};
+use crate::data::loader;
+use serde::{Deserialize, Serialize};
+
+/// 没有徽章时听从指挥的最高等级
+pub const DEFAULT_OBEDIENCE_LEVEL: u32 = 10;
+/// 徽章提升属性的倍率
+const BADGE_STAT_MULTIPLIER: f32 = 1.1;
+
+/// badges.json 中的一枚道馆徽章及其效果
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BadgeInfo {
+    pub id: u32,
+    pub name: String,
+    /// 拥有后，这个等级及以下的宝可梦都会听从指挥
+    #[serde(default)]
+    pub obedience_level: Option<u32>,
+    /// 拥有后才能在野外使用的秘传招式
+    #[serde(default)]
+    pub field_ability: Option<FieldAbility>,
+    /// 战斗中提升我方宝可梦的属性 (如 "attack")
+    #[serde(default)]
+    pub stat_boost: Option<String>,
+}
+
+impl BadgeInfo {
+    /// 按 ID 从 badges.json 查找徽章
+    pub fn get(id: u32) -> Option<BadgeInfo> {
+        loader::get_badge_by_id(id).and_then(|badge| serde_json::from_value(badge).ok())
+    }
+
+    /// badges.json 中的所有徽章
+    pub fn all() -> Vec<BadgeInfo> {
+        loader::get_game_data()
+            .map(|data| {
+                data.badges
+                    .iter()
+                    .filter_map(|badge| serde_json::from_value(badge.clone()).ok())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// 徽章名称，找不到数据时显示编号
+    pub fn name_of(id: u32) -> String {
+        Self::get(id).map(|badge| badge.name).unwrap_or_else(|| format!("徽章 #{}", id))
+    }
+
+    /// 徽章效果的说明文字
+    pub fn effect_descriptions(&self) -> Vec<String> {
+        let mut effects = Vec::new();
+        if let Some(level) = self.obedience_level {
+            effects.push(format!("Lv.{} 及以下的宝可梦会听从指挥", level));
+        }
+        if let Some(ability) = self.field_ability {
+            effects.push(format!("可以在野外使用「{}」", ability.name()));
+        }
+        if let Some(stat) = self.stat_boost.as_deref() {
+            let stat_name = match stat {
+                "attack" => "攻击",
+                "defense" => "防御",
+                "sp_attack" => "特攻",
+                "sp_defense" => "特防",
+                "speed" => "速度",
+                other => other,
+            };
+            effects.push(format!("战斗中我方宝可梦的{}提升", stat_name));
+        }
+        effects
+    }
+}
+
+/// 一组徽章听从指挥的最高等级
+pub fn obedience_level(badges: &[BadgeInfo]) -> u32 {
+    badges
+        .iter()
+        .filter_map(|badge| badge.obedience_level)
+        .fold(DEFAULT_OBEDIENCE_LEVEL, u32::max)
+}
+
+/// 一组徽章带来的战斗属性加成 (同一属性不会重复叠加)
+pub fn stat_bonus(badges: &[BadgeInfo]) -> EnvironmentBonus {
+    let mut bonus = EnvironmentBonus::default();
+    for stat in badges.iter().filter_map(|badge| badge.stat_boost.as_deref()) {
+        match stat {
+            "attack" => bonus.attack_bonus = BADGE_STAT_MULTIPLIER,
+            "defense" => bonus.defense_bonus = BADGE_STAT_MULTIPLIER,
+            "sp_attack" => bonus.sp_attack_bonus = BADGE_STAT_MULTIPLIER,
+            "sp_defense" => bonus.sp_defense_bonus = BADGE_STAT_MULTIPLIER,
+            "speed" => bonus.speed_bonus = BADGE_STAT_MULTIPLIER,
+            _ => {}
+        }
+    }
+    bonus
+}
+
+/// 秘传招式是否需要徽章许可，需要时返回许可它的徽章
+pub fn required_badge_for(ability: FieldAbility, all_badges: &[BadgeInfo]) -> Option<&BadgeInfo> {
+    all_badges.iter().find(|badge| badge.field_ability == Some(ability))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn badge(id: u32, obedience_level: Option<u32>, stat_boost: Option<&str>) -> BadgeInfo {
+        BadgeInfo {
+            id,
+            name: format!("徽章{}", id),
+            obedience_level,
+            field_ability: None,
+            stat_boost: stat_boost.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_obedience_level() {
+        assert_eq!(obedience_level(&[]), DEFAULT_OBEDIENCE_LEVEL);
+        let badges = [badge(2, Some(30), None), badge(4, Some(50), None), badge(1, None, Some("attack"))];
+        assert_eq!(obedience_level(&badges), 50);
+    }
+
+    #[test]
+    fn test_stat_bonus() {
+        let bonus = stat_bonus(&[badge(1, None, Some("attack")), badge(5, None, Some("speed"))]);
+        assert_eq!(bonus.apply_to_stat("attack", 100), 110);
+        assert_eq!(bonus.apply_to_stat("speed", 100), 110);
+        assert_eq!(bonus.apply_to_stat("defense", 100), 100);
+    }
+
+    #[test]
+    fn test_field_ability_permission() {
+        let mut surf = badge(2, None, None);
+        surf.field_ability = Some(FieldAbility::Surf);
+        let badges = [badge(1, None, None), surf];
+        assert_eq!(required_badge_for(FieldAbility::Surf, &badges).map(|b| b.id), Some(2));
+        assert!(required_badge_for(FieldAbility::Cut, &badges).is_none());
+    }
+}
@@ -1,5 +1,8 @@
-use super::{Pokemon, PokemonType, MoveType};
-use crate::data::loader;
+use super::{EnvironmentBonus, FriendshipEvent, Item, Pokemon, PokemonType, MoveType};
+use crate::data::{loader, pokemon_data};
+use super::pokemon::MAX_LEVEL;
+use crate::pokemon_generator::EffortValues;
+use rand::seq::SliceRandom;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 
@@ -12,6 +15,32 @@ pub enum BattleStatus {
     Escaped,     // 成功逃脱
 }
 
+/// 宝可梦不听指挥时的表现
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Disobedience {
+    Ignore,    // 无视指令
+    Loaf,      // 偷懒
+    OtherMove, // 擅自使用其他招式
+}
+
+impl Disobedience {
+    /// 等级超过上限时在 0..(等级+上限) 中掷骰，小于上限才听从指挥
+    pub fn roll(level: u32, level_cap: u32, rng: &mut impl Rng) -> Option<Disobedience> {
+        if level <= level_cap || rng.gen_range(0..level + level_cap) < level_cap {
+            return None;
+        }
+        Some(match rng.gen_range(0..3) {
+            0 => Disobedience::Ignore,
+            1 => Disobedience::Loaf,
+            _ => Disobedience::OtherMove,
+        })
+    }
+}
+
+fn default_obedience_level_cap() -> u32 {
+    MAX_LEVEL
+}
+
 /// 完整的战斗系统
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Battle {
@@ -28,6 +57,12 @@ pub struct Battle {
     pub status: BattleStatus,
     pub battle_log: Vec<String>,
     pub is_wild_battle: bool, // 野生战斗可以逃脱
+    #[serde(default)]
+    pub player_bonus: EnvironmentBonus, // 我方宝可梦的徽章属性加成
+    #[serde(default)]
+    pub trainer_name: String, // 玩家名字，用来判断交换得来的宝可梦
+    #[serde(default = "default_obedience_level_cap")]
+    pub obedience_level_cap: u32, // 交换得来的宝可梦听从指挥的最高等级
 }
 
 impl Battle {
@@ -42,6 +77,9 @@ impl Battle {
             status: BattleStatus::Active,
             battle_log: Vec::new(),
             is_wild_battle: true,
+            player_bonus: EnvironmentBonus::default(),
+            trainer_name: String::new(),
+            obedience_level_cap: MAX_LEVEL,
         }
     }
 
@@ -60,6 +98,9 @@ impl Battle {
             status: BattleStatus::Active,
             battle_log: Vec::new(),
             is_wild_battle: is_wild,
+            player_bonus: EnvironmentBonus::default(),
+            trainer_name: String::new(),
+            obedience_level_cap: MAX_LEVEL,
         }
     }
 
@@ -105,7 +146,7 @@ impl Battle {
             return Err("该宝可梦已在战斗中".to_string());
         }
         self.player_current_index = index;
-        let pokemon_name = self.player_team[index].name.clone();
+        let pokemon_name = self.player_team[index].display_name().to_string();
         self.add_log(format!("派遣了 {}!", pokemon_name));
         Ok(())
     }
@@ -122,7 +163,7 @@ impl Battle {
             return Err("该宝可梦已在战斗中".to_string());
         }
         self.opponent_current_index = index;
-        let pokemon_name = self.opponent_team[index].name.clone();
+        let pokemon_name = self.opponent_team[index].display_name().to_string();
         self.add_log(format!("对手派遣了 {}!", pokemon_name));
         Ok(())
     }
@@ -208,7 +249,9 @@ impl Battle {
         if let (Some(player_poke), Some(opponent_poke)) =
             (self.get_player_pokemon(), self.get_opponent_pokemon())
         {
-            let player_speed = player_poke.get_effective_stat("speed", player_poke.level);
+            let player_speed = self
+                .player_bonus
+                .apply_to_stat("speed", player_poke.get_effective_stat("speed", player_poke.level));
             let opponent_speed = opponent_poke.get_effective_stat("speed", opponent_poke.level);
             player_speed >= opponent_speed
         } else {
@@ -220,6 +263,18 @@ impl Battle {
         attacker: &Pokemon,
         defender: &Pokemon,
         move_data: &super::Move,
+    ) -> u32 {
+        let no_bonus = EnvironmentBonus::default();
+        Self::calculate_damage_with_bonus(attacker, defender, move_data, &no_bonus, &no_bonus)
+    }
+
+    /// 计算伤害，攻击方与防守方的属性分别乘以各自的加成 (如徽章加成)
+    pub fn calculate_damage_with_bonus(
+        attacker: &Pokemon,
+        defender: &Pokemon,
+        move_data: &super::Move,
+        attacker_bonus: &EnvironmentBonus,
+        defender_bonus: &EnvironmentBonus,
     ) -> u32 {
         let mut rng = rand::thread_rng();
 
@@ -228,21 +283,15 @@ impl Battle {
         }
 
         // 获取攻击方的攻击或特攻
-        let attack = if move_data.move_type == MoveType::Physical {
-            attacker.get_effective_stat("attack", attacker.level)
-        } else {
-            attacker.get_effective_stat("sp_attack", attacker.level)
-        };
+        let attack_stat = if move_data.move_type == MoveType::Physical { "attack" } else { "sp_attack" };
+        let attack = attacker_bonus.apply_to_stat(attack_stat, attacker.get_effective_stat(attack_stat, attacker.level));
 
         // 获取防守方的防御或特防
-        let defense = if move_data.move_type == MoveType::Physical {
-            defender.get_effective_stat("defense", defender.level)
-        } else {
-            defender.get_effective_stat("sp_defense", defender.level)
-        };
+        let defense_stat = if move_data.move_type == MoveType::Physical { "defense" } else { "sp_defense" };
+        let defense = defender_bonus.apply_to_stat(defense_stat, defender.get_effective_stat(defense_stat, defender.level));
 
         let base_damage = (((2.0 * attacker.level as f64 / 5.0 + 2.0)
-            * move_data.power as f64
+            * attacker.move_power(move_data) as f64
             * attack as f64
             / defense as f64)
             / 50.0
@@ -353,6 +402,16 @@ impl Battle {
 
     /// 执行招式攻击
     pub fn use_move(&mut self, move_idx: usize, is_player: bool) -> Result<(), String> {
+        // 交换得来的宝可梦可能不听指挥
+        let move_idx = if is_player {
+            match self.obey_command(move_idx) {
+                Some(idx) => idx,
+                None => return Ok(()),
+            }
+        } else {
+            move_idx
+        };
+
         // 首先获取攻击方和防守方的数据
         let (attacker_name, move_data, defender_clone) = if is_player {
             let attacker = self.get_player_pokemon().ok_or("没有活跃的玩家宝可梦")?;
@@ -363,7 +422,7 @@ impl Battle {
             }
 
             let move_data = attacker.moves[move_idx].clone();
-            (attacker.name.clone(), move_data, defender.clone())
+            (attacker.display_name().to_string(), move_data, defender.clone())
         } else {
             let attacker = self.get_opponent_pokemon().ok_or("没有活跃的对手宝可梦")?;
             let defender = self.get_player_pokemon().ok_or("没有活跃的玩家宝可梦")?;
@@ -373,7 +432,7 @@ impl Battle {
             }
 
             let move_data = attacker.moves[move_idx].clone();
-            (attacker.name.clone(), move_data, defender.clone())
+            (attacker.display_name().to_string(), move_data, defender.clone())
         };
 
         // 检查 PP
@@ -389,12 +448,13 @@ impl Battle {
         }
 
         // 获取攻击方再次计算伤害
+        let no_bonus = EnvironmentBonus::default();
         let damage = if is_player {
             let attacker = self.get_player_pokemon().ok_or("没有活跃的玩家宝可梦")?;
-            Self::calculate_damage(attacker, &defender_clone, &move_data)
+            Self::calculate_damage_with_bonus(attacker, &defender_clone, &move_data, &self.player_bonus, &no_bonus)
         } else {
             let attacker = self.get_opponent_pokemon().ok_or("没有活跃的对手宝可梦")?;
-            Self::calculate_damage(attacker, &defender_clone, &move_data)
+            Self::calculate_damage_with_bonus(attacker, &defender_clone, &move_data, &no_bonus, &self.player_bonus)
         };
 
         // 应用伤害
@@ -409,6 +469,9 @@ impl Battle {
         } else {
             if let Some(player) = self.get_player_pokemon_mut() {
                 player.take_damage(damage);
+                if player.is_fainted() {
+                    player.apply_friendship_event(FriendshipEvent::Faint);
+                }
                 self.add_log(format!(
                     "对手的 {} 使用了 {}，造成 {} 伤害！",
                     attacker_name, move_data.name, damage
@@ -419,64 +482,122 @@ impl Battle {
         Ok(())
     }
 
-    /// 使用道具（如恢复药）
-    pub fn use_item(&mut self, item_type: &str, target_is_player: bool, _amount: u32) -> Result<(), String> {
+    /// 检查玩家宝可梦是否听从指挥，返回实际使用的招式；浪费了这一回合时返回 None
+    fn obey_command(&mut self, move_idx: usize) -> Option<usize> {
+        let Some(pokemon) = self.get_player_pokemon() else {
+            return Some(move_idx);
+        };
+        if !pokemon.is_traded(&self.trainer_name) {
+            return Some(move_idx);
+        }
+
+        let mut rng = rand::thread_rng();
+        let name = pokemon.display_name().to_string();
+        let other_moves: Vec<usize> = (0..pokemon.moves.len())
+            .filter(|&idx| idx != move_idx && pokemon.moves[idx].pp > 0)
+            .collect();
+        match Disobedience::roll(pokemon.level, self.obedience_level_cap, &mut rng) {
+            None => Some(move_idx),
+            Some(Disobedience::Loaf) => {
+                self.add_log(format!("{} 在偷懒!", name));
+                None
+            }
+            Some(Disobedience::OtherMove) if !other_moves.is_empty() => {
+                self.add_log(format!("{} 不听指挥，擅自使用了别的招式!", name));
+                other_moves.choose(&mut rng).copied()
+            }
+            Some(_) => {
+                self.add_log(format!("{} 无视了你的指令!", name));
+                None
+            }
+        }
+    }
+
+    /// 使用恢复道具，效果来自 items.json 中的 recovery_percent 与 is_revive
+    pub fn use_item(&mut self, item: &Item, target_is_player: bool) -> Result<(), String> {
+        let recovery = item
+            .get_hp_recovery()
+            .filter(|_| item.is_recovery_item())
+            .ok_or_else(|| format!("{} 不能在战斗中使用", item.name))?;
         let target = if target_is_player {
             self.get_player_pokemon_mut().ok_or("没有活跃的玩家宝可梦")?
         } else {
             self.get_opponent_pokemon_mut().ok_or("没有活跃的对手宝可梦")?
         };
 
-        match item_type {
-            "恢复药" => {
-                target.heal(20);
-                let actor = if target_is_player { "玩家" } else { "对手" };
-                self.add_log(format!("{} 使用了恢复药，恢复了 20 HP！", actor));
-                Ok(())
-            }
-            "超级恢复药" => {
-                target.heal(50);
-                let actor = if target_is_player { "玩家" } else { "对手" };
-                self.add_log(format!("{} 使用了超级恢复药，恢复了 50 HP！", actor));
-                Ok(())
-            }
-            "全复活" => {
-                target.revive(1.0); // 完全复活
-                let actor = if target_is_player { "玩家的" } else { "对手的" };
-                self.add_log(format!("{}宝可梦被全复活了！", actor));
-                Ok(())
+        let actor = if target_is_player { "玩家" } else { "对手" };
+        if item.is_revive_item() {
+            target.revive(recovery);
+            self.add_log(format!("{} 使用了{}，宝可梦复活了！", actor, item.name));
+        } else {
+            if target.is_fainted() {
+                return Err(format!("{} 无法让昏迷的宝可梦恢复", item.name));
             }
-            _ => Err(format!("未知的道具类型: {}", item_type)),
+            let amount = (target.max_hp as f32 * recovery) as u32;
+            target.heal(amount);
+            self.add_log(format!("{} 使用了{}，恢复了 {} HP！", actor, item.name, amount));
         }
+        Ok(())
     }
 
-    /// 分配经验值
+    /// 分配经验值 (以当前对手的等级计算)
     pub fn award_experience(&mut self, base_exp: u32) {
-        if let Some(opponent) = self.get_opponent_pokemon() {
-            let experience_gained = (base_exp * opponent.level) / 7;
-            let mut level_up_messages = Vec::new();
-
-            // 给所有活跃宝可梦分配经验
-            for pokemon in self.player_team.iter_mut() {
-                if !pokemon.is_fainted() {
-                    pokemon.experience += experience_gained;
-
-                    // 检查升级
-                    let next_level_exp = pokemon.level as u32 * 100;
-                    while pokemon.experience >= next_level_exp {
-                        pokemon.level_up();
-                        level_up_messages.push(format!("{} 升到 Lv.{}！", pokemon.name, pokemon.level));
-                    }
+        if let Some(opponent_level) = self.get_opponent_pokemon().map(|p| p.level) {
+            self.distribute_experience(base_exp, opponent_level);
+        }
+    }
+
+    /// 为所有被击倒的对手分配经验值和努力值，返回每只宝可梦获得的经验总和
+    pub fn award_experience_for_defeated(&mut self) -> u32 {
+        let defeated: Vec<(u32, u32, EffortValues)> = self
+            .opponent_team
+            .iter()
+            .filter(|p| p.is_fainted())
+            .map(|p| {
+                (
+                    pokemon_data::get_experience_yield(p.id),
+                    p.level,
+                    pokemon_data::get_ev_yield(p.id),
+                )
+            })
+            .collect();
+
+        defeated
+            .into_iter()
+            .map(|(base_exp, level, ev_yield)| {
+                for pokemon in self.player_team.iter_mut().filter(|p| !p.is_fainted()) {
+                    pokemon.gain_effort_values(&ev_yield);
                 }
-            }
+                self.distribute_experience(base_exp, level)
+            })
+            .sum()
+    }
+
+    /// 给所有未昏迷的宝可梦分配经验，并按经验曲线升级
+    fn distribute_experience(&mut self, base_exp: u32, opponent_level: u32) -> u32 {
+        let mut experience_gained = (base_exp * opponent_level) / 7;
+        // 训练师对战经验值 1.5 倍
+        if !self.is_wild_battle {
+            experience_gained = experience_gained * 3 / 2;
+        }
 
-            // 现在添加所有消息
-            for msg in level_up_messages {
-                self.add_log(msg);
+        let mut level_up_messages = Vec::new();
+        for pokemon in self.player_team.iter_mut().filter(|p| !p.is_fainted()) {
+            let levels_gained = pokemon.gain_experience(experience_gained);
+            for _ in 0..levels_gained {
+                pokemon.apply_friendship_event(FriendshipEvent::LevelUp);
+            }
+            if levels_gained > 0 {
+                level_up_messages.push(format!("{} 升到 Lv.{}！", pokemon.display_name(), pokemon.level));
             }
+        }
 
-            self.add_log(format!("获得了 {} 经验值！", experience_gained));
+        self.add_log(format!("获得了 {} 经验值！", experience_gained));
+        for msg in level_up_messages {
+            self.add_log(msg);
         }
+
+        experience_gained
     }
 
     /// 计算金钱奖励
@@ -492,16 +613,20 @@ impl Battle {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::game::{Stat, Move};
+    use crate::game::{Stat, Move, GrowthRate};
 
     // 辅助函数：创建测试宝可梦
     fn create_test_pokemon(name: &str, level: u32, hp: u32, attack: u32, sp_attack: u32) -> Pokemon {
         let pokemon = Pokemon {
             id: 1,
             name: name.to_string(),
+            nickname: None,
+            gender: crate::pokemon_generator::Gender::Male,
+            is_shiny: false,
             pokemon_type: (PokemonType::Normal, None),
             level,
             experience: 0,
+            growth_rate: GrowthRate::MediumFast,
             hp,
             max_hp: hp,
             stats: Stat {
@@ -512,6 +637,12 @@ mod tests {
                 sp_defense: 100,
                 speed: 100,
             },
+            individual_values: crate::pokemon_generator::IndividualValues::perfect(),
+            effort_values: Default::default(),
+            nature: Default::default(),
+            talent: Default::default(),
+            unique_id: "test".to_string(),
+            friendship: 70,
             moves: vec![
                 Move {
                     id: 1,
@@ -538,6 +669,8 @@ mod tests {
             caught_with: "Poké Ball".to_string(),
             caught_location_id: 101,
             caught_date: 0,
+            original_trainer: None,
+            held_item: None,
         };
         pokemon
     }
@@ -761,6 +894,47 @@ mod tests {
         assert!(battle.determine_turn_order());
     }
 
+    #[test]
+    fn test_badge_speed_bonus_turn_order() {
+        let player = create_test_pokemon("Pikachu", 5, 35, 55, 50);
+        let mut opponent = create_test_pokemon("Bulbasaur", 5, 45, 49, 65);
+        opponent.stats.speed = 105;
+
+        let mut battle = Battle::new(player, opponent);
+        assert!(!battle.determine_turn_order());
+
+        battle.player_bonus.speed_bonus = 1.1;
+        assert!(battle.determine_turn_order());
+    }
+
+    #[test]
+    fn test_disobedience_roll() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..50 {
+            assert_eq!(Disobedience::roll(30, 30, &mut rng), None);
+            assert!(Disobedience::roll(50, 0, &mut rng).is_some());
+        }
+    }
+
+    #[test]
+    fn test_traded_pokemon_obedience() {
+        let mut player = create_test_pokemon("Pikachu", 60, 35, 55, 50);
+        player.original_trainer = Some("小智".to_string());
+        let opponent = create_test_pokemon("Bulbasaur", 5, 45, 49, 65);
+
+        let mut battle = Battle::new(player, opponent);
+        battle.trainer_name = "小智".to_string();
+        battle.obedience_level_cap = 10;
+        assert!((0..50).all(|_| battle.obey_command(0) == Some(0)));
+
+        // 交换得来的宝可梦等级超过上限
+        battle.player_team[0].original_trainer = Some("小刚".to_string());
+        assert!((0..50).any(|_| battle.obey_command(0) != Some(0)));
+
+        battle.obedience_level_cap = 60;
+        assert!((0..50).all(|_| battle.obey_command(0) == Some(0)));
+    }
+
     #[test]
     fn test_use_move_basic() {
         let player = create_test_pokemon("Pikachu", 5, 35, 55, 50);
@@ -789,4 +963,21 @@ mod tests {
 
         assert!(exp_after > exp_before, "玩家应该获得经验");
     }
+
+    #[test]
+    fn test_experience_follows_growth_curve() {
+        let mut player = create_test_pokemon("Pikachu", 5, 35, 55, 50);
+        player.experience = GrowthRate::MediumFast.experience_for_level(5);
+        let opponent = create_test_pokemon("Bulbasaur", 10, 45, 49, 65);
+
+        let mut battle = Battle::new(player, opponent);
+        // 50 * 10 / 7 = 71 经验: 125 + 71 = 196 ≥ 216? 否，仍为 Lv.5
+        battle.award_experience(50);
+        assert_eq!(battle.player_team[0].level, 5);
+
+        // 再获得足够经验后升到 Lv.6 (216)
+        battle.award_experience(50);
+        assert_eq!(battle.player_team[0].level, 6);
+        assert!(battle.player_team[0].experience >= GrowthRate::MediumFast.experience_for_level(6));
+    }
 }
@@ -0,0 +1,330 @@
+use super::Pokemon;
+use crate::data::{loader, pokemon_data};
+use crate::pokemon_generator::{EffortValues, Gender, PokemonInstance, Talent};
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+/// 饲育屋最多寄放的宝可梦数量
+pub const DAY_CARE_CAPACITY: usize = 2;
+/// 无法生蛋的蛋群
+pub const UNDISCOVERED_EGG_GROUP: &str = "未发现";
+/// 百变怪可以和任何能生蛋的宝可梦配对
+pub const DITTO_SPECIES_ID: u32 = 132;
+
+/// 遗传的个体值项数 (普通 / 携带命运红线)
+const INHERITED_IV_COUNT: usize = 3;
+const DESTINY_KNOT_IV_COUNT: usize = 5;
+/// 刚孵化的宝可梦的亲密度
+const HATCHED_FRIENDSHIP: u32 = 120;
+/// 母方的隐藏天赋遗传给子代的概率 (%)
+const HIDDEN_TALENT_INHERIT_PERCENT: u32 = 60;
+
+/// 寄放在饲育屋时携带的、影响遗传的道具 (对应 items.json 中的 breeding_effect)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BreedingItem {
+    DestinyKnot,  // 命运红线：遗传 5 项个体值
+    Everstone,    // 不变之石：遗传携带者的性格
+}
+
+impl BreedingItem {
+    /// 从 items.json 中的字符串解析
+    pub fn from_name(name: &str) -> Option<BreedingItem> {
+        match name {
+            "destiny_knot" => Some(BreedingItem::DestinyKnot),
+            "everstone" => Some(BreedingItem::Everstone),
+            _ => None,
+        }
+    }
+
+    /// 按道具 ID 从 items.json 查找遗传效果
+    pub fn from_item_id(item_id: u32) -> Option<BreedingItem> {
+        let item = loader::get_item_by_id(item_id)?;
+        BreedingItem::from_name(item.get("breeding_effect")?.as_str()?)
+    }
+}
+
+/// 饲育屋中的一个位置：寄放的宝可梦 (携带的道具就是宝可梦自己的 held_item)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DayCareSlot {
+    pub pokemon: Pokemon,
+    /// 旧存档中单独记录的携带道具，读档时移到宝可梦身上
+    #[serde(default, rename = "held_item", skip_serializing)]
+    pub legacy_held_item: Option<u32>,
+}
+
+impl DayCareSlot {
+    pub fn new(pokemon: Pokemon) -> Self {
+        DayCareSlot { pokemon, legacy_held_item: None }
+    }
+
+    /// 携带道具的遗传效果
+    pub fn breeding_item(&self) -> Option<BreedingItem> {
+        self.pokemon.held_item.and_then(BreedingItem::from_item_id)
+    }
+}
+
+/// 还没孵化的蛋
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Egg {
+    pub instance: PokemonInstance,
+    pub steps_remaining: u32,
+}
+
+impl Egg {
+    pub fn new(instance: PokemonInstance) -> Self {
+        let steps_remaining = pokemon_data::get_hatch_steps(instance.species_id);
+        Egg { instance, steps_remaining }
+    }
+
+    /// 带着蛋走一步，返回是否可以孵化
+    pub fn step(&mut self) -> bool {
+        self.steps_remaining = self.steps_remaining.saturating_sub(1);
+        self.steps_remaining == 0
+    }
+
+    /// 孵化出宝可梦 (孵化出的宝可梦亲密度较高)
+    pub fn hatch(&self) -> Option<Pokemon> {
+        let mut pokemon = pokemon_data::get_pokemon_from_instance(&self.instance)?;
+        pokemon.friendship = HATCHED_FRIENDSHIP;
+        Some(pokemon)
+    }
+
+    /// 蛋的状态描述
+    pub fn description(&self) -> &'static str {
+        match self.steps_remaining {
+            0..=2 => "好像快要孵化了！",
+            3..=5 => "里面时不时传出声音",
+            _ => "看起来还需要很长时间才能孵化",
+        }
+    }
+}
+
+/// 饲育屋：寄放两只宝可梦，移动时有机会得到蛋
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DayCare {
+    pub slots: Vec<DayCareSlot>,
+    /// 等待领取的蛋
+    #[serde(default)]
+    pub egg: Option<Egg>,
+}
+
+impl DayCare {
+    /// 饲育屋是否已经寄放满了
+    pub fn is_full(&self) -> bool {
+        self.slots.len() >= DAY_CARE_CAPACITY
+    }
+
+    /// 寄放宝可梦
+    pub fn deposit(&mut self, pokemon: Pokemon) -> Result<(), String> {
+        if self.is_full() {
+            return Err(format!("饲育屋最多只能寄放 {} 只宝可梦", DAY_CARE_CAPACITY));
+        }
+        self.slots.push(DayCareSlot::new(pokemon));
+        Ok(())
+    }
+
+    /// 把旧存档中单独记录的携带道具移到寄放的宝可梦身上
+    pub fn migrate_legacy_held_items(&mut self) {
+        for slot in &mut self.slots {
+            if let Some(item_id) = slot.legacy_held_item.take() {
+                slot.pokemon.held_item.get_or_insert(item_id);
+            }
+        }
+    }
+
+    /// 领回宝可梦
+    pub fn withdraw(&mut self, index: usize) -> Result<DayCareSlot, String> {
+        if index >= self.slots.len() {
+            return Err("饲育屋序号无效".to_string());
+        }
+        Ok(self.slots.remove(index))
+    }
+
+    /// 当前寄放的两只宝可梦每一步生蛋的概率 (%)
+    pub fn egg_chance(&self) -> u32 {
+        match self.slots.as_slice() {
+            [first, second] => egg_chance(&first.pokemon, &second.pokemon),
+            _ => 0,
+        }
+    }
+
+    /// 玩家走一步，返回是否产生了新的蛋 (已有蛋未领取时不会再生蛋)
+    pub fn step(&mut self) -> bool {
+        if self.egg.is_some() {
+            return false;
+        }
+        let chance = self.egg_chance();
+        if chance == 0 || rand::thread_rng().gen_range(0..100) >= chance {
+            return false;
+        }
+        self.egg = breed(&self.slots[0], &self.slots[1]).map(Egg::new);
+        self.egg.is_some()
+    }
+
+    /// 领取等待中的蛋
+    pub fn take_egg(&mut self) -> Option<Egg> {
+        self.egg.take()
+    }
+}
+
+/// 两只宝可梦每一步生蛋的概率 (%)：不能配对时为 0，同种为 50，不同种为 20
+pub fn egg_chance(first: &Pokemon, second: &Pokemon) -> u32 {
+    let first_groups = pokemon_data::get_egg_groups(first.id);
+    let second_groups = pokemon_data::get_egg_groups(second.id);
+    let can_breed = |groups: &[String]| {
+        !groups.is_empty() && !groups.iter().any(|g| g == UNDISCOVERED_EGG_GROUP)
+    };
+    if !can_breed(&first_groups) || !can_breed(&second_groups) {
+        return 0;
+    }
+
+    let first_is_ditto = first.id == DITTO_SPECIES_ID;
+    let second_is_ditto = second.id == DITTO_SPECIES_ID;
+    if first_is_ditto && second_is_ditto {
+        return 0;
+    }
+    if !first_is_ditto && !second_is_ditto {
+        let opposite_genders = matches!(
+            (first.gender, second.gender),
+            (Gender::Male, Gender::Female) | (Gender::Female, Gender::Male)
+        );
+        let shares_group = first_groups.iter().any(|g| second_groups.contains(g));
+        if !opposite_genders || !shares_group {
+            return 0;
+        }
+    }
+
+    if first.id == second.id { 50 } else { 20 }
+}
+
+/// 由两只寄放的宝可梦生成蛋中的宝可梦，不能配对时返回 None
+///
+/// - 种类：母方 (与百变怪配对时为另一方) 进化链的第一阶段
+/// - 个体值：随机 3 项来自父母之一，任一方携带命运红线时为 5 项
+/// - 性格：携带不变之石的一方遗传性格，否则随机
+/// - 天赋：母方为隐藏天赋时有 60% 概率遗传
+pub fn breed(first: &DayCareSlot, second: &DayCareSlot) -> Option<PokemonInstance> {
+    if egg_chance(&first.pokemon, &second.pokemon) == 0 {
+        return None;
+    }
+
+    let mut rng = rand::thread_rng();
+    let mother = if second.pokemon.id == DITTO_SPECIES_ID
+        || (first.pokemon.id != DITTO_SPECIES_ID
+            && first.pokemon.gender == Gender::Female)
+    {
+        first
+    } else {
+        second
+    };
+    let parents = [first, second];
+
+    let species_id = pokemon_data::get_base_species(mother.pokemon.id);
+    let mut child = PokemonInstance::new(species_id, 1);
+    child.effort_values = EffortValues::default();
+
+    let has_destiny_knot = parents
+        .iter()
+        .any(|p| p.breeding_item() == Some(BreedingItem::DestinyKnot));
+    let inherited_count = if has_destiny_knot { DESTINY_KNOT_IV_COUNT } else { INHERITED_IV_COUNT };
+    let mut stats = EffortValues::STAT_KEYS.to_vec();
+    stats.shuffle(&mut rng);
+    for stat in stats.into_iter().take(inherited_count) {
+        let parent = parents[rng.gen_range(0..parents.len())];
+        child.individual_values.set(stat, parent.pokemon.individual_values.get(stat));
+    }
+
+    let everstone_holders: Vec<_> = parents
+        .iter()
+        .filter(|p| p.breeding_item() == Some(BreedingItem::Everstone))
+        .collect();
+    if let Some(holder) = everstone_holders.choose(&mut rng) {
+        child.nature = holder.pokemon.nature;
+    }
+
+    child.talent = if mother.pokemon.talent == Talent::Hidden
+        && rng.gen_range(0..100) < HIDDEN_TALENT_INHERIT_PERCENT
+    {
+        Talent::Hidden
+    } else {
+        Talent::Normal
+    };
+
+    Some(child)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::pokemon::tests::create_pikachu;
+    use crate::pokemon_generator::IndividualValues;
+
+    fn create_parent(id: u32, gender: Gender) -> Pokemon {
+        let mut pokemon = create_pikachu();
+        pokemon.id = id;
+        pokemon.gender = gender;
+        pokemon
+    }
+
+    fn slot(pokemon: Pokemon) -> DayCareSlot {
+        DayCareSlot::new(pokemon)
+    }
+
+    #[test]
+    fn test_egg_chance_requires_compatible_pair() {
+        let male = create_parent(25, Gender::Male);
+        let female = create_parent(25, Gender::Female);
+        assert_eq!(egg_chance(&male, &female), 50);
+        assert_eq!(egg_chance(&male, &male), 0);
+
+        // 不同蛋群无法配对 (皮卡丘: 陆地，妙蛙种子: 植物)
+        assert_eq!(egg_chance(&male, &create_parent(1, Gender::Female)), 0);
+    }
+
+    #[test]
+    fn test_breed_inherits_ivs() {
+        let mut male = create_parent(25, Gender::Male);
+        let mut female = create_parent(25, Gender::Female);
+        male.individual_values = IndividualValues::perfect();
+        female.individual_values = IndividualValues::perfect();
+
+        let child = breed(&slot(male.clone()), &slot(female.clone())).unwrap();
+        assert_eq!(child.level, 1);
+        let perfect_stats = EffortValues::STAT_KEYS
+            .iter()
+            .filter(|stat| child.individual_values.get(stat) == 31)
+            .count();
+        assert!(perfect_stats >= INHERITED_IV_COUNT);
+
+        assert!(breed(&slot(male.clone()), &slot(male)).is_none());
+    }
+
+    #[test]
+    fn test_day_care_capacity_and_egg_steps() {
+        let mut day_care = DayCare::default();
+        assert!(day_care.deposit(create_parent(25, Gender::Male)).is_ok());
+        assert_eq!(day_care.egg_chance(), 0);
+        assert!(day_care.deposit(create_parent(25, Gender::Female)).is_ok());
+        assert!(day_care.is_full());
+        assert!(day_care.deposit(create_parent(25, Gender::Female)).is_err());
+        assert_eq!(day_care.egg_chance(), 50);
+
+        let mut egg = Egg { instance: PokemonInstance::new(25, 1), steps_remaining: 2 };
+        assert!(!egg.step());
+        assert!(egg.step());
+        assert!(egg.step());
+    }
+
+    #[test]
+    fn test_legacy_slot_item_moves_onto_pokemon() {
+        let pokemon = serde_json::to_value(create_parent(25, Gender::Female)).unwrap();
+        let json = serde_json::json!({ "slots": [{ "pokemon": pokemon, "held_item": 31 }] });
+        let mut day_care: DayCare = serde_json::from_value(json).unwrap();
+
+        day_care.migrate_legacy_held_items();
+        assert_eq!(day_care.slots[0].pokemon.held_item, Some(31));
+        assert_eq!(day_care.slots[0].legacy_held_item, None);
+        assert!(serde_json::to_value(&day_care.slots[0]).unwrap().get("held_item").is_none());
+    }
+}
@@ -0,0 +1,224 @@
+use super::stats::now_timestamp;
+use crate::data::loader;
+use serde::{Deserialize, Serialize};
+
+/// 一天的分钟数
+pub const MINUTES_PER_DAY: u32 = 24 * 60;
+/// 新游戏开始的时刻 (game_constants.json 中没有配置时)
+const DEFAULT_START_HOUR: u32 = 8;
+/// 每个行动经过的游戏分钟数 (game_constants.json 中没有配置时)
+const DEFAULT_MINUTES_PER_ACTION: u32 = 30;
+
+/// 一天中的时段
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimeOfDay {
+    Morning, // 早晨 4:00 - 9:59
+    Day,     // 白天 10:00 - 19:59
+    Night,   // 夜晚 20:00 - 3:59
+}
+
+impl TimeOfDay {
+    /// 根据小时 (0-23) 判断时段
+    pub fn from_hour(hour: u32) -> TimeOfDay {
+        match hour {
+            4..=9 => TimeOfDay::Morning,
+            10..=19 => TimeOfDay::Day,
+            _ => TimeOfDay::Night,
+        }
+    }
+
+    /// 获取时段的中文名称
+    pub fn name(&self) -> &str {
+        match self {
+            TimeOfDay::Morning => "早晨",
+            TimeOfDay::Day => "白天",
+            TimeOfDay::Night => "夜晚",
+        }
+    }
+
+    /// 时段的图标
+    pub fn icon(&self) -> &str {
+        match self {
+            TimeOfDay::Morning => "🌅",
+            TimeOfDay::Day => "☀",
+            TimeOfDay::Night => "🌙",
+        }
+    }
+}
+
+/// 时钟的计时方式 (对应 game_constants.json 中的 clock.mode)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ClockMode {
+    #[default]
+    Actions,  // 随玩家的行动推进
+    RealTime, // 跟随现实时间 (系统时区的本地时间)
+}
+
+/// game_constants.json 中 clock 部分的设置
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClockSettings {
+    pub mode: ClockMode,
+    pub minutes_per_action: u32,
+}
+
+impl Default for ClockSettings {
+    fn default() -> Self {
+        ClockSettings {
+            mode: ClockMode::Actions,
+            minutes_per_action: DEFAULT_MINUTES_PER_ACTION,
+        }
+    }
+}
+
+impl ClockSettings {
+    /// 读取 game_constants.json 中的时钟设置，没有数据时使用默认值
+    pub fn load() -> Self {
+        let defaults = ClockSettings::default();
+        let Some(clock) = loader::get_game_data().and_then(|data| data.game_constants.get("clock")) else {
+            return defaults;
+        };
+        ClockSettings {
+            mode: match clock.get("mode").and_then(|v| v.as_str()) {
+                Some("real_time") => ClockMode::RealTime,
+                _ => ClockMode::Actions,
+            },
+            minutes_per_action: clock
+                .get("minutes_per_action")
+                .and_then(|v| v.as_u64())
+                .map_or(defaults.minutes_per_action, |v| v as u32),
+        }
+    }
+}
+
+/// 系统时区在指定时刻相对 UTC 的偏移分钟数 (含夏令时)
+#[cfg(unix)]
+fn local_offset_minutes(timestamp: u64) -> i64 {
+    let time = timestamp as libc::time_t;
+    // SAFETY: localtime_r 只写入传入的 tm，失败时返回空指针
+    let mut tm: libc::tm = unsafe { std::mem::zeroed() };
+    if unsafe { libc::localtime_r(&time, &mut tm) }.is_null() {
+        return 0;
+    }
+    tm.tm_gmtoff as i64 / 60
+}
+
+/// 其他平台无法读取系统时区，按 UTC 计算
+#[cfg(not(unix))]
+fn local_offset_minutes(_timestamp: u64) -> i64 {
+    0
+}
+
+/// 游戏内时钟：记录第几天与当天的分钟数
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameClock {
+    pub day: u32,
+    pub minute_of_day: u32,
+    /// 计时方式每次读档时从 game_constants.json 读取，不写入存档
+    #[serde(skip, default = "ClockSettings::load")]
+    pub settings: ClockSettings,
+}
+
+impl Default for GameClock {
+    fn default() -> Self {
+        let start_hour = loader::get_game_constant("clock", "start_hour").map_or(DEFAULT_START_HOUR, |h| h as u32);
+        GameClock::new(start_hour, 0, ClockSettings::load())
+    }
+}
+
+impl GameClock {
+    /// 从第 1 天的指定时刻开始的时钟
+    pub fn new(hour: u32, minute: u32, settings: ClockSettings) -> Self {
+        GameClock {
+            day: 1,
+            minute_of_day: (hour * 60 + minute) % MINUTES_PER_DAY,
+            settings,
+        }
+    }
+
+    /// 固定从指定时刻开始、只随行动推进的时钟 (结果可复现，用于测试)
+    #[cfg(test)]
+    pub fn fixed(hour: u32, minute: u32) -> Self {
+        GameClock::new(hour, minute, ClockSettings::default())
+    }
+
+    /// 玩家行动一次，时钟前进配置的分钟数 (现实时间模式下不变)
+    pub fn advance(&mut self) {
+        if self.settings.mode == ClockMode::Actions {
+            self.advance_minutes(self.settings.minutes_per_action);
+        }
+    }
+
+    /// 时钟前进指定的分钟数，跨过午夜时进入下一天
+    pub fn advance_minutes(&mut self, minutes: u32) {
+        let total = self.minute_of_day + minutes;
+        self.day += total / MINUTES_PER_DAY;
+        self.minute_of_day = total % MINUTES_PER_DAY;
+    }
+
+    /// 当前是一天中的第几分钟
+    pub fn current_minute(&self) -> u32 {
+        match self.settings.mode {
+            ClockMode::Actions => self.minute_of_day,
+            ClockMode::RealTime => {
+                let now = now_timestamp();
+                let local = now as i64 / 60 + local_offset_minutes(now);
+                local.rem_euclid(MINUTES_PER_DAY as i64) as u32
+            }
+        }
+    }
+
+    /// 当前的时段
+    pub fn time_of_day(&self) -> TimeOfDay {
+        TimeOfDay::from_hour(self.current_minute() / 60)
+    }
+
+    /// 形如 `第 3 天 20:30 🌙 夜晚` 的显示文本 (现实时间模式不显示天数)
+    pub fn display(&self) -> String {
+        let minute = self.current_minute();
+        let time = self.time_of_day();
+        let clock = format!("{:02}:{:02} {} {}", minute / 60, minute % 60, time.icon(), time.name());
+        match self.settings.mode {
+            ClockMode::Actions => format!("第 {} 天 {}", self.day, clock),
+            ClockMode::RealTime => clock,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_time_of_day_periods() {
+        assert_eq!(TimeOfDay::from_hour(4), TimeOfDay::Morning);
+        assert_eq!(TimeOfDay::from_hour(9), TimeOfDay::Morning);
+        assert_eq!(TimeOfDay::from_hour(10), TimeOfDay::Day);
+        assert_eq!(TimeOfDay::from_hour(19), TimeOfDay::Day);
+        assert_eq!(TimeOfDay::from_hour(20), TimeOfDay::Night);
+        assert_eq!(TimeOfDay::from_hour(3), TimeOfDay::Night);
+    }
+
+    #[test]
+    fn test_fixed_clock_advances_with_actions() {
+        let mut clock = GameClock::fixed(19, 30);
+        assert_eq!(clock.time_of_day(), TimeOfDay::Day);
+        clock.advance();
+        assert_eq!(clock.time_of_day(), TimeOfDay::Night);
+        assert_eq!(clock.display(), "第 1 天 20:00 🌙 夜晚");
+
+        clock.advance_minutes(8 * 60 + 15);
+        assert_eq!((clock.day, clock.minute_of_day), (2, 4 * 60 + 15));
+        assert_eq!(clock.time_of_day(), TimeOfDay::Morning);
+    }
+
+    #[test]
+    fn test_real_time_clock_ignores_actions() {
+        let settings = ClockSettings { mode: ClockMode::RealTime, ..ClockSettings::default() };
+        let mut clock = GameClock::new(8, 0, settings);
+        clock.advance();
+        assert_eq!(clock.minute_of_day, 8 * 60);
+        assert!(clock.current_minute() < MINUTES_PER_DAY);
+    }
+}
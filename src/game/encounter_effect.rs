@@ -0,0 +1,136 @@
+use super::{Ability, Item, Player};
+use serde::{Deserialize, Serialize};
+
+/// 野外道具效果的种类 (对应 items.json 中的 item_type)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EffectKind {
+    Repel, // 驱虫喷雾：等级低于领头宝可梦的野生宝可梦不会出现
+    Lure,  // 引虫香水：提高遭遇率
+}
+
+/// 一个按步数计算的道具效果
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct StepEffect {
+    pub item_id: u32,
+    pub steps_left: u32,
+    /// 遭遇率倍率 (驱虫喷雾为 1)
+    #[serde(default = "default_multiplier")]
+    pub multiplier: f32,
+}
+
+fn default_multiplier() -> f32 {
+    1.0
+}
+
+/// 正在生效的野外道具效果，存档中保存剩余步数
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EncounterEffects {
+    pub repel: Option<StepEffect>,
+    pub lure: Option<StepEffect>,
+}
+
+impl EncounterEffects {
+    /// 开始一个效果，同种效果会被新的道具替换
+    pub fn activate(&mut self, kind: EffectKind, effect: StepEffect) {
+        match kind {
+            EffectKind::Repel => self.repel = Some(effect),
+            EffectKind::Lure => self.lure = Some(effect),
+        }
+    }
+
+    /// 走了一步：所有效果的剩余步数减一，返回刚刚失效的道具 ID
+    pub fn step(&mut self) -> Vec<u32> {
+        let mut expired = Vec::new();
+        for slot in [&mut self.repel, &mut self.lure] {
+            if let Some(effect) = slot {
+                effect.steps_left = effect.steps_left.saturating_sub(1);
+                if effect.steps_left == 0 {
+                    expired.push(effect.item_id);
+                    *slot = None;
+                }
+            }
+        }
+        expired
+    }
+
+    /// 效果的显示文本 (如 `驱虫喷雾 剩余 42 步`)
+    pub fn descriptions(&self) -> Vec<String> {
+        [&self.repel, &self.lure]
+            .into_iter()
+            .flatten()
+            .map(|effect| format!("{} 剩余 {} 步", Item::name_of(effect.item_id), effect.steps_left))
+            .collect()
+    }
+}
+
+/// 影响遭遇率的一项因素
+#[derive(Debug, Clone, PartialEq)]
+pub struct EncounterRateModifier {
+    pub source: String,
+    pub multiplier: f32,
+}
+
+impl EncounterRateModifier {
+    /// 玩家当前的遭遇率修正：引虫香水，以及领头宝可梦的特性和携带的道具
+    /// (abilities.json 与 items.json 中的 encounter_rate_multiplier)
+    pub fn for_player(player: &Player) -> Vec<EncounterRateModifier> {
+        let mut modifiers = Vec::new();
+        if let Some(lure) = &player.encounter_effects.lure {
+            modifiers.push(EncounterRateModifier {
+                source: Item::name_of(lure.item_id),
+                multiplier: lure.multiplier,
+            });
+        }
+        if let Some(ability) = player.lead_pokemon().and_then(Ability::of) {
+            if let Some(multiplier) = ability.encounter_rate_multiplier {
+                modifiers.push(EncounterRateModifier { source: ability.name, multiplier });
+            }
+        }
+        if let Some(item) = player.lead_pokemon().and_then(|p| p.held_item).and_then(Item::get) {
+            if let Some(multiplier) = item.encounter_rate_multiplier() {
+                modifiers.push(EncounterRateModifier { source: item.name, multiplier });
+            }
+        }
+        modifiers
+    }
+
+    /// 把所有修正乘到基础遭遇率上 (结果在 0 到 1 之间)
+    pub fn apply(rate: f32, modifiers: &[EncounterRateModifier]) -> f32 {
+        modifiers
+            .iter()
+            .fold(rate, |rate, modifier| rate * modifier.multiplier)
+            .clamp(0.0, 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_effects_expire_after_steps() {
+        let mut effects = EncounterEffects::default();
+        effects.activate(EffectKind::Repel, StepEffect { item_id: 37, steps_left: 2, multiplier: 1.0 });
+        effects.activate(EffectKind::Lure, StepEffect { item_id: 40, steps_left: 3, multiplier: 1.5 });
+        assert!(effects.step().is_empty());
+        assert_eq!(effects.step(), vec![37]);
+        assert!(effects.repel.is_none());
+        assert!(effects.lure.is_some());
+        assert_eq!(effects.descriptions().len(), 1);
+        assert_eq!(effects.step(), vec![40]);
+        assert_eq!(effects, EncounterEffects::default());
+    }
+
+    #[test]
+    fn test_lure_raises_encounter_rate() {
+        let mut player = Player::new("测试".to_string());
+        assert!(EncounterRateModifier::for_player(&player).is_empty());
+
+        player.encounter_effects.activate(EffectKind::Lure, StepEffect { item_id: 40, steps_left: 10, multiplier: 1.5 });
+        let modifiers = EncounterRateModifier::for_player(&player);
+        assert_eq!(modifiers.len(), 1);
+        assert!((EncounterRateModifier::apply(0.4, &modifiers) - 0.6).abs() < 1e-6);
+        assert_eq!(EncounterRateModifier::apply(0.8, &modifiers), 1.0);
+    }
+}
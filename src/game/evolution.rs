@@ -0,0 +1,174 @@
+use super::{Pokemon, TimeOfDay};
+use crate::data::json_schemas::EvolutionJSON;
+use crate::data::pokemon_data;
+
+/// 进化方式 (对应 species.json 中 evolution.method)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvolutionMethod {
+    Level,       // 达到指定等级
+    Item,        // 使用指定道具 (trigger 为道具 ID)
+    Friendship,  // 亲密度达到 trigger 后升级
+    Trade,       // 通过交换
+}
+
+impl EvolutionMethod {
+    /// 从 species.json 中的字符串解析进化方式
+    pub fn from_name(name: &str) -> Option<EvolutionMethod> {
+        match name {
+            "level" => Some(EvolutionMethod::Level),
+            "item" => Some(EvolutionMethod::Item),
+            "friendship" => Some(EvolutionMethod::Friendship),
+            "trade" => Some(EvolutionMethod::Trade),
+            _ => None,
+        }
+    }
+}
+
+/// 触发进化检查的时机
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvolutionTrigger {
+    LevelUp,        // 战斗后升级
+    UseItem(u32),   // 在背包中使用道具
+    Trade,          // 交换得到
+}
+
+/// 物种的进化规则
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Evolution {
+    pub method: EvolutionMethod,
+    pub trigger: u32,
+    pub to: u32,
+    pub time: Option<TimeOfDay>, // 只能在该时段进化
+}
+
+impl Evolution {
+    /// 从 JSON 数据构建进化规则，进化方式未知时返回 None
+    pub fn from_json(json: &EvolutionJSON) -> Option<Evolution> {
+        Some(Evolution {
+            method: EvolutionMethod::from_name(&json.method)?,
+            trigger: json.trigger,
+            to: json.to,
+            time: json.time,
+        })
+    }
+
+    /// 判断宝可梦在给定时机下是否满足进化条件
+    pub fn is_satisfied(&self, pokemon: &Pokemon, trigger: EvolutionTrigger) -> bool {
+        match (self.method, trigger) {
+            (EvolutionMethod::Level, EvolutionTrigger::LevelUp) => pokemon.level >= self.trigger,
+            (EvolutionMethod::Friendship, EvolutionTrigger::LevelUp) => {
+                pokemon.friendship >= self.trigger
+            }
+            (EvolutionMethod::Item, EvolutionTrigger::UseItem(item_id)) => item_id == self.trigger,
+            (EvolutionMethod::Trade, EvolutionTrigger::Trade) => true,
+            _ => false,
+        }
+    }
+
+    /// 当前时段是否允许进化 (没有时段限制时总是允许)
+    pub fn allowed_at(&self, time: TimeOfDay) -> bool {
+        self.time.is_none_or(|required| required == time)
+    }
+}
+
+/// 检查宝可梦在当前时段是否可以进化，返回进化后的物种 ID
+pub fn check_evolution(pokemon: &Pokemon, trigger: EvolutionTrigger, time: TimeOfDay) -> Option<u32> {
+    pokemon_data::get_evolution(pokemon.id)
+        .filter(|evolution| evolution.allowed_at(time) && evolution.is_satisfied(pokemon, trigger))
+        .map(|evolution| evolution.to)
+}
+
+/// 将宝可梦进化为目标物种，返回提示信息
+pub fn evolve(pokemon: &mut Pokemon, target_species_id: u32) -> Result<String, String> {
+    let target = pokemon_data::get_pokemon_by_id(target_species_id)
+        .ok_or_else(|| format!("物种 {} 不存在", target_species_id))?;
+
+    let old_name = pokemon.name.clone();
+    pokemon.apply_species(&target);
+    Ok(format!("恭喜！{} 进化成了 {}！", old_name, target.name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::{PokemonType, Stat};
+
+    fn create_pokemon(level: u32) -> Pokemon {
+        let stats = Stat { hp: 45, attack: 49, defense: 49, sp_attack: 65, sp_defense: 65, speed: 45 };
+        let mut pokemon = Pokemon::new(1, "妙蛙种子".to_string(), (PokemonType::Grass, None), stats, 45);
+        pokemon.set_level(level);
+        pokemon
+    }
+
+    #[test]
+    fn test_level_evolution() {
+        let evolution = Evolution { method: EvolutionMethod::Level, trigger: 16, to: 2, time: None };
+        assert!(!evolution.is_satisfied(&create_pokemon(15), EvolutionTrigger::LevelUp));
+        assert!(evolution.is_satisfied(&create_pokemon(16), EvolutionTrigger::LevelUp));
+        assert!(!evolution.is_satisfied(&create_pokemon(16), EvolutionTrigger::Trade));
+    }
+
+    #[test]
+    fn test_item_and_trade_evolution() {
+        let pokemon = create_pokemon(5);
+        let stone = Evolution { method: EvolutionMethod::Item, trigger: 19, to: 26, time: None };
+        assert!(stone.is_satisfied(&pokemon, EvolutionTrigger::UseItem(19)));
+        assert!(!stone.is_satisfied(&pokemon, EvolutionTrigger::UseItem(2)));
+        assert!(!stone.is_satisfied(&pokemon, EvolutionTrigger::LevelUp));
+
+        let trade = Evolution { method: EvolutionMethod::Trade, trigger: 0, to: 65, time: None };
+        assert!(trade.is_satisfied(&pokemon, EvolutionTrigger::Trade));
+        assert!(!trade.is_satisfied(&pokemon, EvolutionTrigger::LevelUp));
+    }
+
+    #[test]
+    fn test_friendship_evolution() {
+        let mut pokemon = create_pokemon(5);
+        let evolution = Evolution { method: EvolutionMethod::Friendship, trigger: 220, to: 25, time: None };
+        assert!(!evolution.is_satisfied(&pokemon, EvolutionTrigger::LevelUp));
+        pokemon.friendship = 220;
+        assert!(evolution.is_satisfied(&pokemon, EvolutionTrigger::LevelUp));
+    }
+
+    #[test]
+    fn test_time_restricted_evolution() {
+        let evolution = Evolution { method: EvolutionMethod::Friendship, trigger: 220, to: 25, time: Some(TimeOfDay::Day) };
+        assert!(evolution.allowed_at(TimeOfDay::Day));
+        assert!(!evolution.allowed_at(TimeOfDay::Night));
+        let anytime = Evolution { time: None, ..evolution };
+        assert!(anytime.allowed_at(TimeOfDay::Night));
+    }
+
+    #[test]
+    fn test_apply_species_keeps_individual_data() {
+        let mut pokemon = create_pokemon(16);
+        pokemon.gain_experience(100);
+        let (ivs, nature, unique_id, experience) =
+            (pokemon.individual_values, pokemon.nature, pokemon.unique_id.clone(), pokemon.experience);
+        let moves = pokemon.moves.len();
+
+        let stats = Stat { hp: 60, attack: 62, defense: 63, sp_attack: 80, sp_defense: 80, speed: 60 };
+        let ivysaur = Pokemon::new(2, "妙蛙草".to_string(), (PokemonType::Grass, Some(PokemonType::Poison)), stats, 45);
+        let max_hp_before = pokemon.max_hp;
+        pokemon.apply_species(&ivysaur);
+
+        assert_eq!(pokemon.id, 2);
+        assert_eq!(pokemon.name, "妙蛙草");
+        assert_eq!(pokemon.pokemon_type.1, Some(PokemonType::Poison));
+        assert_eq!(pokemon.stats.sp_attack, 80);
+        assert!(pokemon.max_hp > max_hp_before);
+        assert_eq!(pokemon.hp, pokemon.max_hp);
+        assert_eq!(pokemon.individual_values.total(), ivs.total());
+        assert_eq!(pokemon.nature, nature);
+        assert_eq!(pokemon.unique_id, unique_id);
+        assert_eq!(pokemon.experience, experience);
+        assert_eq!(pokemon.level, 16);
+        assert_eq!(pokemon.moves.len(), moves);
+    }
+
+    #[test]
+    fn test_method_from_name() {
+        assert_eq!(EvolutionMethod::from_name("trade"), Some(EvolutionMethod::Trade));
+        assert_eq!(EvolutionMethod::from_name("moon"), None);
+    }
+}
@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+
+/// 可以在野外使用的秘传招式能力 (对应 items.json 中秘传学习器的 field_ability)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FieldAbility {
+    Cut,       // 居合斩：砍倒小树
+    Fly,       // 飞翔：飞往去过的地点
+    Surf,      // 冲浪：在水面上移动
+    Strength,  // 怪力：推动巨石
+    Flash,     // 闪光：照亮洞穴
+    #[serde(rename = "rock_smash")]
+    RockSmash, // 碎岩：击碎岩石
+}
+
+impl FieldAbility {
+    /// 从 items.json 中的字符串解析野外能力
+    pub fn from_name(name: &str) -> Option<FieldAbility> {
+        match name {
+            "cut" => Some(FieldAbility::Cut),
+            "fly" => Some(FieldAbility::Fly),
+            "surf" => Some(FieldAbility::Surf),
+            "strength" => Some(FieldAbility::Strength),
+            "flash" => Some(FieldAbility::Flash),
+            "rock_smash" => Some(FieldAbility::RockSmash),
+            _ => None,
+        }
+    }
+
+    /// 获取能力的中文名称
+    pub fn name(&self) -> &str {
+        match self {
+            FieldAbility::Cut => "居合斩",
+            FieldAbility::Fly => "飞翔",
+            FieldAbility::Surf => "冲浪",
+            FieldAbility::Strength => "怪力",
+            FieldAbility::Flash => "闪光",
+            FieldAbility::RockSmash => "碎岩",
+        }
+    }
+
+    /// 在野外使用时的说明
+    pub fn description(&self) -> &str {
+        match self {
+            FieldAbility::Cut => "砍倒挡路的小树",
+            FieldAbility::Fly => "飞往去过的地点",
+            FieldAbility::Surf => "载着你在水面上移动",
+            FieldAbility::Strength => "推开挡路的巨石",
+            FieldAbility::Flash => "照亮黑暗的洞穴",
+            FieldAbility::RockSmash => "击碎岩石，里面可能藏着宝可梦",
+        }
+    }
+
+    /// 需要这个能力才能通过的障碍
+    pub fn obstacle(&self) -> &str {
+        match self {
+            FieldAbility::Cut => "一棵小树挡住了去路",
+            FieldAbility::Fly => "这里只能从空中抵达",
+            FieldAbility::Surf => "前方是一片水域",
+            FieldAbility::Strength => "一块巨石挡住了去路",
+            FieldAbility::Flash => "前方的洞穴一片漆黑",
+            FieldAbility::RockSmash => "一块裂开的岩石挡住了去路",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_field_ability_from_name() {
+        assert_eq!(FieldAbility::from_name("surf"), Some(FieldAbility::Surf));
+        assert_eq!(FieldAbility::from_name("dig"), None);
+        assert_eq!(FieldAbility::Fly.name(), "飞翔");
+        let parsed: FieldAbility = serde_json::from_str("\"strength\"").unwrap();
+        assert_eq!(parsed, FieldAbility::Strength);
+        let parsed: FieldAbility = serde_json::from_str("\"rock_smash\"").unwrap();
+        assert_eq!(parsed, FieldAbility::RockSmash);
+    }
+}
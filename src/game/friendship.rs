@@ -0,0 +1,119 @@
+/// 亲密度上限
+pub const MAX_FRIENDSHIP: u32 = 255;
+/// 报恩：亲密度越高威力越大
+pub const RETURN_MOVE_ID: u32 = 216;
+/// 迁怒：亲密度越低威力越大
+pub const FRUSTRATION_MOVE_ID: u32 = 218;
+
+/// 改变亲密度的事件
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FriendshipEvent {
+    LevelUp,  // 战斗中升级
+    Walk,     // 和训练家一起移动
+    Faint,    // 在战斗中昏迷
+}
+
+impl FriendshipEvent {
+    /// 事件带来的亲密度变化 (亲密度越高，升级带来的增长越少)
+    pub fn change(&self, friendship: u32) -> i32 {
+        match self {
+            FriendshipEvent::LevelUp => match friendship {
+                0..=99 => 5,
+                100..=199 => 3,
+                _ => 2,
+            },
+            FriendshipEvent::Walk => 1,
+            FriendshipEvent::Faint => -1,
+        }
+    }
+}
+
+/// 亲密度的文字描述 (详情界面中显示)
+pub fn friendship_description(friendship: u32) -> &'static str {
+    match friendship {
+        255.. => "非常亲近你，看起来十分幸福",
+        200..=254 => "很喜欢你",
+        150..=199 => "对你很友好",
+        70..=149 => "和你还不太熟悉",
+        1..=69 => "对你有些冷淡",
+        0 => "非常讨厌你",
+    }
+}
+
+/// 依赖亲密度的招式威力 (报恩 / 迁怒)，其他招式返回 None
+pub fn friendship_move_power(move_id: u32, friendship: u32) -> Option<u32> {
+    let friendship = friendship.min(MAX_FRIENDSHIP);
+    match move_id {
+        RETURN_MOVE_ID => Some((friendship * 10 / 25).max(1)),
+        FRUSTRATION_MOVE_ID => Some(((MAX_FRIENDSHIP - friendship) * 10 / 25).max(1)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::pokemon::tests::{create_move, create_pikachu};
+    use crate::game::Player;
+
+    #[test]
+    fn test_level_up_gain_shrinks_with_friendship() {
+        assert_eq!(FriendshipEvent::LevelUp.change(70), 5);
+        assert_eq!(FriendshipEvent::LevelUp.change(150), 3);
+        assert_eq!(FriendshipEvent::LevelUp.change(230), 2);
+        assert_eq!(FriendshipEvent::Faint.change(230), -1);
+    }
+
+    #[test]
+    fn test_friendship_move_power() {
+        assert_eq!(friendship_move_power(RETURN_MOVE_ID, 255), Some(102));
+        assert_eq!(friendship_move_power(RETURN_MOVE_ID, 0), Some(1));
+        assert_eq!(friendship_move_power(FRUSTRATION_MOVE_ID, 0), Some(102));
+        assert_eq!(friendship_move_power(FRUSTRATION_MOVE_ID, 255), Some(1));
+        assert_eq!(friendship_move_power(1, 255), None);
+    }
+
+    #[test]
+    fn test_pokemon_friendship_changes() {
+        let mut pokemon = create_pikachu();
+        assert_eq!(pokemon.friendship, 70);
+        assert_eq!(pokemon.apply_friendship_event(FriendshipEvent::LevelUp), 5);
+        assert_eq!(pokemon.apply_friendship_event(FriendshipEvent::Faint), -1);
+        assert_eq!(pokemon.friendship, 74);
+
+        // 亲密度限制在 0-255
+        assert_eq!(pokemon.change_friendship(500), 181);
+        assert_eq!(pokemon.friendship, 255);
+        assert_eq!(pokemon.change_friendship(-300), -255);
+        assert_eq!(pokemon.friendship, 0);
+    }
+
+    #[test]
+    fn test_return_and_frustration_power() {
+        let mut pokemon = create_pikachu();
+        let return_move = create_move(216, "报恩");
+        let frustration = create_move(218, "迁怒");
+
+        pokemon.friendship = 255;
+        assert_eq!(pokemon.move_power(&return_move), 102);
+        assert_eq!(pokemon.move_power(&frustration), 1);
+
+        pokemon.friendship = 0;
+        assert_eq!(pokemon.move_power(&return_move), 1);
+        assert_eq!(pokemon.move_power(&frustration), 102);
+        assert_eq!(pokemon.move_power(&create_move(85, "十万伏特")), create_move(85, "十万伏特").power);
+    }
+
+    #[test]
+    fn test_walking_raises_team_friendship() {
+        let mut player = Player::new("测试".to_string());
+        player.add_pokemon(create_pikachu());
+        let mut fainted = create_pikachu();
+        fainted.hp = 0;
+        player.add_pokemon(fainted);
+
+        player.walk_with_team();
+        assert_eq!(player.pokemons[0].friendship, 71);
+        assert_eq!(player.pokemons[1].friendship, 70);
+    }
+}
@@ -0,0 +1,150 @@
+use serde::{Deserialize, Serialize};
+
+/// 经验值类型 (决定升级所需的经验曲线)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum GrowthRate {
+    Fast,         // 快     (Lv.100 需 800,000)
+    #[default]
+    MediumFast,   // 较快   (Lv.100 需 1,000,000)
+    MediumSlow,   // 较慢   (Lv.100 需 1,059,860)
+    Slow,         // 慢     (Lv.100 需 1,250,000)
+    Erratic,      // 最快   (Lv.100 需 600,000)
+    Fluctuating,  // 最慢   (Lv.100 需 1,640,000)
+}
+
+impl GrowthRate {
+    /// 获取经验值类型的中文名称
+    pub fn name(&self) -> &str {
+        match self {
+            GrowthRate::Fast => "快",
+            GrowthRate::MediumFast => "较快",
+            GrowthRate::MediumSlow => "较慢",
+            GrowthRate::Slow => "慢",
+            GrowthRate::Erratic => "最快",
+            GrowthRate::Fluctuating => "最慢",
+        }
+    }
+
+    /// 从 species.json 中的字符串解析经验值类型
+    pub fn from_name(name: &str) -> Option<GrowthRate> {
+        match name {
+            "Fast" => Some(GrowthRate::Fast),
+            "MediumFast" => Some(GrowthRate::MediumFast),
+            "MediumSlow" => Some(GrowthRate::MediumSlow),
+            "Slow" => Some(GrowthRate::Slow),
+            "Erratic" => Some(GrowthRate::Erratic),
+            "Fluctuating" => Some(GrowthRate::Fluctuating),
+            _ => None,
+        }
+    }
+
+    /// 到达指定等级所需的累计经验值
+    pub fn experience_for_level(&self, level: u32) -> u32 {
+        if level <= 1 {
+            return 0;
+        }
+
+        let n = level.min(super::pokemon::MAX_LEVEL) as i64;
+        let cube = n * n * n;
+
+        let total = match self {
+            GrowthRate::Fast => 4 * cube / 5,
+            GrowthRate::MediumFast => cube,
+            GrowthRate::MediumSlow => 6 * cube / 5 - 15 * n * n + 100 * n - 140,
+            GrowthRate::Slow => 5 * cube / 4,
+            GrowthRate::Erratic => {
+                if n < 50 {
+                    cube * (100 - n) / 50
+                } else if n < 68 {
+                    cube * (150 - n) / 100
+                } else if n < 98 {
+                    cube * ((1911 - 10 * n) / 3) / 500
+                } else {
+                    cube * (160 - n) / 100
+                }
+            }
+            GrowthRate::Fluctuating => {
+                if n < 15 {
+                    cube * ((n + 1) / 3 + 24) / 50
+                } else if n < 36 {
+                    cube * (n + 14) / 50
+                } else {
+                    cube * (n / 2 + 32) / 50
+                }
+            }
+        };
+
+        total.max(0) as u32
+    }
+
+    /// 根据累计经验值计算对应的等级
+    pub fn level_for_experience(&self, experience: u32) -> u32 {
+        let mut level = 1;
+        while level < super::pokemon::MAX_LEVEL && experience >= self.experience_for_level(level + 1) {
+            level += 1;
+        }
+        level
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL_RATES: [GrowthRate; 6] = [
+        GrowthRate::Fast,
+        GrowthRate::MediumFast,
+        GrowthRate::MediumSlow,
+        GrowthRate::Slow,
+        GrowthRate::Erratic,
+        GrowthRate::Fluctuating,
+    ];
+
+    #[test]
+    fn test_level_100_totals() {
+        assert_eq!(GrowthRate::Fast.experience_for_level(100), 800_000);
+        assert_eq!(GrowthRate::MediumFast.experience_for_level(100), 1_000_000);
+        assert_eq!(GrowthRate::MediumSlow.experience_for_level(100), 1_059_860);
+        assert_eq!(GrowthRate::Slow.experience_for_level(100), 1_250_000);
+        assert_eq!(GrowthRate::Erratic.experience_for_level(100), 600_000);
+        assert_eq!(GrowthRate::Fluctuating.experience_for_level(100), 1_640_000);
+    }
+
+    #[test]
+    fn test_curves_start_at_zero_and_increase() {
+        for rate in ALL_RATES {
+            assert_eq!(rate.experience_for_level(1), 0);
+            for level in 2..=100 {
+                assert!(
+                    rate.experience_for_level(level) > rate.experience_for_level(level - 1),
+                    "{:?} 在 Lv.{} 处经验值没有递增",
+                    rate,
+                    level
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_medium_slow_low_levels() {
+        // 较慢曲线在低等级时公式结果为负，需要截断为 0
+        assert_eq!(GrowthRate::MediumSlow.experience_for_level(2), 9);
+        assert_eq!(GrowthRate::MediumSlow.experience_for_level(5), 135);
+    }
+
+    #[test]
+    fn test_level_for_experience_roundtrip() {
+        for rate in ALL_RATES {
+            for level in [1, 2, 10, 49, 50, 67, 68, 97, 98, 100] {
+                let exp = rate.experience_for_level(level);
+                assert_eq!(rate.level_for_experience(exp), level);
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_name() {
+        assert_eq!(GrowthRate::from_name("MediumSlow"), Some(GrowthRate::MediumSlow));
+        assert_eq!(GrowthRate::from_name("Unknown"), None);
+    }
+}
@@ -1,6 +1,16 @@
+use super::EncounterMethod;
+use crate::data::loader;
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
 
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+/// 常用道具的 ID (对应 items.json)
+pub const POKE_BALL_ID: u32 = 1;
+pub const POTION_ID: u32 = 2;
+pub const SUPER_POTION_ID: u32 = 3;
+pub const REVIVE_ID: u32 = 4;
+
+/// 道具种类 (对应 items.json 中的 item_type)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ItemType {
     PokeBall,         // 精灵球
     Potion,           // 基础恢复药 (50% HP)
@@ -8,93 +18,375 @@ pub enum ItemType {
     Revive,           // 全复活药 (复活 + 50% HP)
     FullRestore,      // 完全恢复 (复活 + 100% HP)
     Antidote,         // 解毒药 (未来用)
+    Vitamin,          // 营养剂：提升努力值
+    EVBerry,          // 树果：降低努力值
+    EvolutionStone,   // 进化石
+    TM,               // 招式学习器
+    HM,               // 秘传学习器
+    BreedingItem,     // 饲育屋携带道具
+    KeyItem,          // 重要物品：不能卖出或丢弃
+    Repel,            // 驱虫喷雾：一定步数内弱小的野生宝可梦不会出现
+    Lure,             // 引虫香水：一定步数内提高遭遇率
+    HeldItem,         // 让宝可梦携带的道具
+}
+
+impl ItemType {
+    /// 从 items.json 中的字符串解析
+    pub fn from_name(name: &str) -> Option<ItemType> {
+        serde_json::from_value(serde_json::Value::String(name.to_string())).ok()
+    }
+
+    /// 道具放在背包的哪个口袋
+    pub fn pocket(self) -> Pocket {
+        match self {
+            ItemType::Potion
+            | ItemType::SuperPotion
+            | ItemType::Revive
+            | ItemType::FullRestore
+            | ItemType::Antidote
+            | ItemType::Vitamin => Pocket::Medicine,
+            ItemType::PokeBall => Pocket::Balls,
+            ItemType::EVBerry => Pocket::Berries,
+            ItemType::TM | ItemType::HM => Pocket::TMs,
+            // 携带道具、进化石与野外使用的喷雾归入道具口袋
+            ItemType::EvolutionStone
+            | ItemType::BreedingItem
+            | ItemType::HeldItem
+            | ItemType::Repel
+            | ItemType::Lure => Pocket::Items,
+            ItemType::KeyItem => Pocket::KeyItems,
+        }
+    }
+}
+
+/// 背包的口袋
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Pocket {
+    Medicine,
+    Balls,
+    BattleItems,
+    Items,
+    Berries,
+    TMs,
+    KeyItems,
+}
+
+impl Pocket {
+    /// 按背包中的顺序排列的所有口袋
+    pub const ALL: [Pocket; 7] = [
+        Pocket::Medicine,
+        Pocket::Balls,
+        Pocket::BattleItems,
+        Pocket::Items,
+        Pocket::Berries,
+        Pocket::TMs,
+        Pocket::KeyItems,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Pocket::Medicine => "回复药",
+            Pocket::Balls => "精灵球",
+            Pocket::BattleItems => "对战道具",
+            Pocket::Items => "道具",
+            Pocket::Berries => "树果",
+            Pocket::TMs => "招式学习器",
+            Pocket::KeyItems => "重要物品",
+        }
+    }
+}
+
+/// 口袋中道具的排序方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BagSort {
+    #[default]
+    Id,
+    Name,
+    Count,
+}
+
+impl BagSort {
+    pub fn name(self) -> &'static str {
+        match self {
+            BagSort::Id => "编号",
+            BagSort::Name => "名称",
+            BagSort::Count => "数量",
+        }
+    }
+
+    /// 切换到下一种排序方式
+    pub fn next(self) -> BagSort {
+        match self {
+            BagSort::Id => BagSort::Name,
+            BagSort::Name => BagSort::Count,
+            BagSort::Count => BagSort::Id,
+        }
+    }
+
+    /// 排序道具列表：名称按字典序，数量从多到少；相同时按 ID
+    pub fn sort(self, items: &mut [(Item, u32)]) {
+        match self {
+            BagSort::Id => items.sort_by_key(|(item, _)| item.id),
+            BagSort::Name => items.sort_by(|(a, _), (b, _)| a.name.cmp(&b.name).then(a.id.cmp(&b.id))),
+            BagSort::Count => items.sort_by(|(a, a_count), (b, b_count)| {
+                b_count.cmp(a_count).then(a.id.cmp(&b.id))
+            }),
+        }
+    }
 }
 
+/// items.json 中的一个道具
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Item {
-    pub item_type: ItemType,
+    pub id: u32,
     pub name: String,
+    #[serde(default)]
+    pub english_name: String,
+    pub item_type: ItemType,
     pub description: String,
-    pub price: u32,
+    pub price: Option<u32>,
+    #[serde(default)]
+    pub recovery_percent: Option<u32>,
+    #[serde(default)]
+    pub is_revive: bool,
+    #[serde(default)]
+    pub is_healing: bool,
 }
 
 impl Item {
-    pub fn new(item_type: ItemType) -> Self {
-        match item_type {
-            ItemType::PokeBall => Item {
-                item_type,
-                name: "Poké Ball".to_string(),
-                description: "用来捕捉宝可梦的道具".to_string(),
-                price: 100,
-            },
-            ItemType::Potion => Item {
-                item_type,
-                name: "恢复药".to_string(),
-                description: "恢复宝可梦 50% 的 HP".to_string(),
-                price: 150,
-            },
-            ItemType::SuperPotion => Item {
-                item_type,
-                name: "超级恢复药".to_string(),
-                description: "完全恢复宝可梦的 HP".to_string(),
-                price: 250,
-            },
-            ItemType::Revive => Item {
-                item_type,
-                name: "全复活".to_string(),
-                description: "让昏迷的宝可梦复活，恢复 50% HP".to_string(),
-                price: 200,
-            },
-            ItemType::FullRestore => Item {
-                item_type,
-                name: "完全恢复".to_string(),
-                description: "让昏迷的宝可梦复活，完全恢复 HP".to_string(),
-                price: 400,
-            },
-            ItemType::Antidote => Item {
-                item_type,
-                name: "解毒药".to_string(),
-                description: "解除宝可梦的中毒状态".to_string(),
-                price: 100,
-            },
-        }
+    /// 按 ID 从 items.json 查找道具
+    pub fn get(id: u32) -> Option<Item> {
+        loader::get_item_by_id(id).and_then(|item| serde_json::from_value(item).ok())
+    }
+
+    /// 道具的名称 (找不到时显示 ID)
+    pub fn name_of(id: u32) -> String {
+        Self::get(id).map(|item| item.name).unwrap_or_else(|| format!("道具 {}", id))
+    }
+
+    /// 按中文名或英文名查找道具 (旧存档中的背包以名称记录道具)
+    pub fn find_by_name(name: &str) -> Option<Item> {
+        loader::get_item_by_name(name)
+            .or_else(|| loader::get_item_by_english_name(name))
+            .and_then(|item| serde_json::from_value(item).ok())
     }
 
+    /// 恢复的 HP 比例
     pub fn get_hp_recovery(&self) -> Option<f32> {
-        match self.item_type {
-            ItemType::Potion => Some(0.5),          // 50% HP
-            ItemType::SuperPotion => Some(1.0),     // 100% HP
-            ItemType::Revive => Some(0.5),          // 复活 + 50% HP
-            ItemType::FullRestore => Some(1.0),     // 复活 + 100% HP
-            _ => None,
-        }
+        self.recovery_percent.map(|percent| percent as f32 / 100.0)
     }
 
+    /// 能否复活昏迷的宝可梦
     pub fn is_revive_item(&self) -> bool {
-        matches!(self.item_type, ItemType::Revive | ItemType::FullRestore)
+        self.is_revive
     }
 
+    /// 能否恢复 HP (包括复活道具)
     pub fn is_recovery_item(&self) -> bool {
-        matches!(
-            self.item_type,
-            ItemType::Potion | ItemType::SuperPotion | ItemType::Revive | ItemType::FullRestore
-        )
+        (self.is_healing || self.is_revive) && self.recovery_percent.is_some()
+    }
+
+    /// 能否让宝可梦携带
+    pub fn can_be_held(&self) -> bool {
+        matches!(self.item_type, ItemType::HeldItem | ItemType::BreedingItem)
+    }
+
+    pub fn pocket(&self) -> Pocket {
+        self.item_type.pocket()
+    }
+
+    /// 能否丢弃 (重要物品与可以反复使用的秘传学习器不能丢弃)
+    pub fn can_toss(&self) -> bool {
+        !matches!(self.item_type, ItemType::KeyItem | ItemType::HM)
+    }
+
+    /// items.json 中的其他字段 (如 ev_stat、move_id、breeding_effect)
+    pub fn field(&self, key: &str) -> Option<serde_json::Value> {
+        loader::get_item_by_id(self.id)?.get(key).cloned()
+    }
+
+    /// 驱虫喷雾与引虫香水的有效步数 (items.json 中的 effect_steps)
+    pub fn effect_steps(&self) -> Option<u32> {
+        Some(self.field("effect_steps")?.as_u64()? as u32)
+    }
+
+    /// 引虫香水或携带道具对遭遇率的倍率 (items.json 中的 encounter_rate_multiplier)
+    pub fn encounter_rate_multiplier(&self) -> Option<f32> {
+        Some(self.field("encounter_rate_multiplier")?.as_f64()? as f32)
+    }
+
+    /// 钓竿可以使用的遭遇方式 (items.json 中的 encounter_method)
+    pub fn encounter_method(&self) -> Option<EncounterMethod> {
+        serde_json::from_value(self.field("encounter_method")?).ok()
+    }
+}
+
+/// 背包：道具 ID → 数量
+///
+/// 存档中保存为 `{"1": 5, "2": 3}`；旧存档以道具名称为键 (如 `{"Poké Ball": 5}`)，
+/// 读取时先暂存在 legacy_names 中，由 `migrate_legacy_names` 换算为 ID。
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(from = "HashMap<String, u32>", into = "BTreeMap<u32, u32>")]
+pub struct Inventory {
+    counts: BTreeMap<u32, u32>,
+    legacy_names: HashMap<String, u32>,
+}
+
+impl From<HashMap<String, u32>> for Inventory {
+    fn from(entries: HashMap<String, u32>) -> Self {
+        let mut inventory = Inventory::default();
+        for (key, count) in entries {
+            match key.parse::<u32>() {
+                Ok(id) => inventory.add(id, count),
+                Err(_) => {
+                    *inventory.legacy_names.entry(key).or_insert(0) += count;
+                }
+            }
+        }
+        inventory
+    }
+}
+
+impl From<Inventory> for BTreeMap<u32, u32> {
+    fn from(inventory: Inventory) -> Self {
+        inventory.counts
     }
 }
 
-// 便捷函数：获取所有道具类型
-pub fn get_all_item_types() -> Vec<ItemType> {
-    vec![
-        ItemType::PokeBall,
-        ItemType::Potion,
-        ItemType::SuperPotion,
-        ItemType::Revive,
-        ItemType::FullRestore,
-        ItemType::Antidote,
-    ]
+impl Inventory {
+    /// 某个道具的数量
+    pub fn count(&self, item_id: u32) -> u32 {
+        self.counts.get(&item_id).copied().unwrap_or(0)
+    }
+
+    pub fn add(&mut self, item_id: u32, count: u32) {
+        *self.counts.entry(item_id).or_insert(0) += count;
+    }
+
+    /// 消耗道具，数量不足时不扣除
+    pub fn remove(&mut self, item_id: u32, count: u32) -> bool {
+        match self.counts.get_mut(&item_id) {
+            Some(current) if *current >= count => {
+                *current -= count;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counts.values().all(|&count| count == 0)
+    }
+
+    /// 背包中数量大于 0 的道具，按 ID 排序 (items.json 中找不到的道具会被跳过)
+    pub fn items(&self) -> Vec<(Item, u32)> {
+        self.items_where(|_| true)
+    }
+
+    /// 背包中满足条件的道具，按 ID 排序
+    pub fn items_where(&self, predicate: impl Fn(&Item) -> bool) -> Vec<(Item, u32)> {
+        self.counts
+            .iter()
+            .filter(|(_, &count)| count > 0)
+            .filter_map(|(&id, &count)| Item::get(id).map(|item| (item, count)))
+            .filter(|(item, _)| predicate(item))
+            .collect()
+    }
+
+    /// 某个口袋中的道具，按指定方式排序
+    pub fn pocket(&self, pocket: Pocket, sort: BagSort) -> Vec<(Item, u32)> {
+        let mut items = self.items_where(|item| item.pocket() == pocket);
+        sort.sort(&mut items);
+        items
+    }
+
+    /// 把旧存档中按名称记录的道具换算为 ID，返回无法识别的名称
+    pub fn migrate_legacy_names(&mut self) -> Vec<String> {
+        let mut unknown = Vec::new();
+        for (name, count) in std::mem::take(&mut self.legacy_names) {
+            match Item::find_by_name(&name) {
+                Some(item) => self.add(item.id, count),
+                None => unknown.push(name),
+            }
+        }
+        unknown.sort();
+        unknown
+    }
 }
 
-// 获取道具名称
-pub fn get_item_name(item_type: ItemType) -> String {
-    Item::new(item_type).name
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inventory_add_and_remove() {
+        let mut inventory = Inventory::default();
+        assert!(inventory.is_empty());
+        inventory.add(POTION_ID, 2);
+        assert_eq!(inventory.count(POTION_ID), 2);
+        assert!(inventory.remove(POTION_ID, 2));
+        assert!(!inventory.remove(POTION_ID, 1));
+        assert!(inventory.is_empty());
+    }
+
+    #[test]
+    fn test_inventory_serializes_by_id() {
+        let mut inventory = Inventory::default();
+        inventory.add(POKE_BALL_ID, 5);
+        let json = serde_json::to_string(&inventory).unwrap();
+        assert_eq!(json, r#"{"1":5}"#);
+
+        let restored: Inventory = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.count(POKE_BALL_ID), 5);
+    }
+
+    #[test]
+    fn test_old_name_keys_wait_for_migration() {
+        let inventory: Inventory = serde_json::from_str(r#"{"Poké Ball": 5, "恢复药": 3}"#).unwrap();
+        assert_eq!(inventory.count(POKE_BALL_ID), 0);
+        assert_eq!(inventory.legacy_names.get("Poké Ball"), Some(&5));
+        assert_eq!(inventory.legacy_names.get("恢复药"), Some(&3));
+    }
+
+    #[test]
+    fn test_bag_sort() {
+        let item = |id: u32, name: &str| Item {
+            id,
+            name: name.to_string(),
+            english_name: String::new(),
+            item_type: ItemType::Potion,
+            description: String::new(),
+            price: Some(100),
+            recovery_percent: Some(50),
+            is_revive: false,
+            is_healing: true,
+        };
+        let mut items = vec![(item(3, "b"), 1), (item(2, "c"), 5), (item(4, "a"), 5)];
+        let ids = |items: &[(Item, u32)]| items.iter().map(|(item, _)| item.id).collect::<Vec<_>>();
+
+        BagSort::Id.sort(&mut items);
+        assert_eq!(ids(&items), vec![2, 3, 4]);
+        BagSort::Name.sort(&mut items);
+        assert_eq!(ids(&items), vec![4, 3, 2]);
+        BagSort::Count.sort(&mut items);
+        assert_eq!(ids(&items), vec![2, 4, 3]);
+        assert_eq!(BagSort::Count.next(), BagSort::Id);
+    }
+
+    #[test]
+    fn test_item_type_pockets() {
+        assert_eq!(ItemType::Vitamin.pocket(), Pocket::Medicine);
+        assert_eq!(ItemType::HM.pocket(), Pocket::TMs);
+        assert_eq!(ItemType::EVBerry.pocket(), Pocket::Berries);
+        assert_eq!(ItemType::KeyItem.pocket(), Pocket::KeyItems);
+        assert_eq!(ItemType::EvolutionStone.pocket(), Pocket::Items);
+        assert_eq!(ItemType::Repel.pocket(), Pocket::Items);
+    }
+
+    #[test]
+    fn test_item_type_from_name() {
+        assert_eq!(ItemType::from_name("EVBerry"), Some(ItemType::EVBerry));
+        assert_eq!(ItemType::from_name("TM"), Some(ItemType::TM));
+        assert_eq!(ItemType::from_name("Unknown"), None);
+    }
 }
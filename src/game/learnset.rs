@@ -0,0 +1,87 @@
+use crate::data::json_schemas::LearnsetEntryJSON;
+
+/// 宝可梦同时最多掌握的招式数
+pub const MAX_MOVES: usize = 4;
+
+/// 物种的升级招式表 (等级, 招式 ID)，按等级排序
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Learnset {
+    entries: Vec<(u32, u32)>,
+}
+
+impl Learnset {
+    /// 从 (等级, 招式 ID) 列表构建招式表
+    pub fn new(mut entries: Vec<(u32, u32)>) -> Learnset {
+        entries.sort_by_key(|&(level, _)| level);
+        Learnset { entries }
+    }
+
+    /// 从 JSON 数据构建招式表
+    pub fn from_json(entries: &[LearnsetEntryJSON]) -> Learnset {
+        Learnset::new(entries.iter().map(|e| (e.level, e.move_id)).collect())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// 是否能在某个等级学会该招式
+    pub fn contains(&self, move_id: u32) -> bool {
+        self.entries.iter().any(|&(_, id)| id == move_id)
+    }
+
+    /// 恰好在该等级学会的招式 ID
+    pub fn moves_learned_at(&self, level: u32) -> Vec<u32> {
+        self.entries
+            .iter()
+            .filter(|&&(learn_level, _)| learn_level == level)
+            .map(|&(_, move_id)| move_id)
+            .collect()
+    }
+
+    /// 到达该等级时最后学会的 (最多 4 个) 招式 ID，用于生成野生和 NPC 宝可梦
+    pub fn moves_for_level(&self, level: u32) -> Vec<u32> {
+        let mut moves: Vec<u32> = Vec::new();
+        for &(learn_level, move_id) in &self.entries {
+            if learn_level > level {
+                break;
+            }
+            moves.retain(|&id| id != move_id);
+            moves.push(move_id);
+        }
+        let skip = moves.len().saturating_sub(MAX_MOVES);
+        moves.split_off(skip)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn charmander() -> Learnset {
+        Learnset::new(vec![(1, 10), (1, 45), (7, 33), (13, 83), (25, 163), (31, 34)])
+    }
+
+    #[test]
+    fn test_moves_learned_at() {
+        let learnset = charmander();
+        assert_eq!(learnset.moves_learned_at(1), vec![10, 45]);
+        assert_eq!(learnset.moves_learned_at(13), vec![83]);
+        assert!(learnset.moves_learned_at(14).is_empty());
+    }
+
+    #[test]
+    fn test_moves_for_level_keeps_last_four() {
+        let learnset = charmander();
+        assert_eq!(learnset.moves_for_level(1), vec![10, 45]);
+        assert_eq!(learnset.moves_for_level(13), vec![10, 45, 33, 83]);
+        assert_eq!(learnset.moves_for_level(30), vec![45, 33, 83, 163]);
+        assert_eq!(learnset.moves_for_level(100), vec![33, 83, 163, 34]);
+    }
+
+    #[test]
+    fn test_moves_for_level_relearned_move_counts_as_latest() {
+        let learnset = Learnset::new(vec![(20, 1), (1, 1), (5, 2), (10, 3)]);
+        assert_eq!(learnset.moves_for_level(20), vec![2, 3, 1]);
+    }
+}
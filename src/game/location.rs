@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
+use super::clock::TimeOfDay;
 
 /// 环境类型枚举
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -26,6 +27,47 @@ impl EnvironmentType {
     }
 }
 
+/// 地点提供的设施服务
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LocationService {
+    MoveTutor,      // 招式教学
+    MoveRelearner,  // 招式回忆
+    DayCare,        // 饲育屋
+    PokeMart,       // 友好商店
+    PokemonCenter,  // 宝可梦中心
+    Gym,            // 道馆
+    Residents,      // 居民 (发布任务的 NPC)
+}
+
+impl LocationService {
+    /// 从 world.json 中的字符串解析服务
+    pub fn from_name(name: &str) -> Option<LocationService> {
+        match name {
+            "move_tutor" => Some(LocationService::MoveTutor),
+            "move_relearner" => Some(LocationService::MoveRelearner),
+            "day_care" => Some(LocationService::DayCare),
+            "poke_mart" => Some(LocationService::PokeMart),
+            "pokemon_center" => Some(LocationService::PokemonCenter),
+            "gym" => Some(LocationService::Gym),
+            "residents" => Some(LocationService::Residents),
+            _ => None,
+        }
+    }
+
+    /// 获取服务的中文名称
+    pub fn name(&self) -> &str {
+        match self {
+            LocationService::MoveTutor => "招式教学",
+            LocationService::MoveRelearner => "招式回忆",
+            LocationService::DayCare => "饲育屋",
+            LocationService::PokeMart => "友好商店",
+            LocationService::PokemonCenter => "宝可梦中心",
+            LocationService::Gym => "道馆",
+            LocationService::Residents => "居民",
+        }
+    }
+}
+
 /// 环境属性加成
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct EnvironmentBonus {
@@ -96,12 +138,14 @@ impl EnvironmentBonus {
     }
 }
 
-/// 地点解锁条件
+/// 地点解锁条件 (对应 world.json 中的 unlock_requirement)
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct LocationRequirement {
     pub required_level: u32,
     pub required_badges: Vec<u32>,
     pub required_pokemon_count: Option<u32>,
+    pub required_flags: Vec<String>, // 需要完成任务获得的任务标记
 }
 
 impl Default for LocationRequirement {
@@ -110,6 +154,7 @@ impl Default for LocationRequirement {
             required_level: 1,
             required_badges: Vec::new(),
             required_pokemon_count: None,
+            required_flags: Vec::new(),
         }
     }
 }
@@ -124,13 +169,19 @@ impl LocationRequirement {
         }
 
         if !self.required_badges.is_empty() {
-            conditions.push(format!("徽章 ≥ {}", self.required_badges.len()));
+            let names: Vec<String> = self.required_badges.iter().map(|&id| super::BadgeInfo::name_of(id)).collect();
+            conditions.push(format!("徽章: {}", names.join("、")));
         }
 
         if let Some(count) = self.required_pokemon_count {
             conditions.push(format!("宝可梦 ≥ {}", count));
         }
 
+        if !self.required_flags.is_empty() {
+            let names: Vec<String> = self.required_flags.iter().map(|flag| super::quest::flag_source(flag)).collect();
+            conditions.push(format!("任务: {}", names.join("、")));
+        }
+
         if conditions.is_empty() {
             "无条件".to_string()
         } else {
@@ -139,6 +190,83 @@ impl LocationRequirement {
     }
 }
 
+/// 通往某个相邻地点需要满足的条件 (对应 world.json 中 connection_requirements 的一项)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConnectionRequirement {
+    /// 目标地点 ID
+    pub to: u32,
+    /// 需要的重要物品 ID
+    #[serde(default)]
+    pub key_item: Option<u32>,
+    /// 需要队伍中有宝可梦会的秘传招式
+    #[serde(default)]
+    pub field_ability: Option<super::FieldAbility>,
+}
+
+/// 遭遇野生宝可梦的方式 (对应 world.json 中 wild_pokemon 的 method)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EncounterMethod {
+    #[default]
+    Walk,      // 在草丛中行走
+    Surf,      // 冲浪
+    OldRod,    // 旧钓竿
+    GoodRod,   // 好钓竿
+    SuperRod,  // 厉害钓竿
+    RockSmash, // 碎岩
+}
+
+impl EncounterMethod {
+    /// 所有遭遇方式 (探索菜单中的显示顺序)
+    pub const ALL: [EncounterMethod; 6] = [
+        EncounterMethod::Walk,
+        EncounterMethod::Surf,
+        EncounterMethod::OldRod,
+        EncounterMethod::GoodRod,
+        EncounterMethod::SuperRod,
+        EncounterMethod::RockSmash,
+    ];
+
+    /// 获取遭遇方式的中文名称
+    pub fn name(&self) -> &str {
+        match self {
+            EncounterMethod::Walk => "在草丛中行走",
+            EncounterMethod::Surf => "冲浪",
+            EncounterMethod::OldRod => "旧钓竿",
+            EncounterMethod::GoodRod => "好钓竿",
+            EncounterMethod::SuperRod => "厉害钓竿",
+            EncounterMethod::RockSmash => "碎岩",
+        }
+    }
+
+    /// 是否是钓鱼
+    pub fn is_fishing(&self) -> bool {
+        matches!(self, EncounterMethod::OldRod | EncounterMethod::GoodRod | EncounterMethod::SuperRod)
+    }
+
+    /// 该环境中能否使用这种方式：冲浪和钓鱼需要水域，碎岩需要洞穴或山地
+    pub fn allowed_in(&self, environment: EnvironmentType) -> bool {
+        match self {
+            EncounterMethod::Walk => true,
+            EncounterMethod::Surf | EncounterMethod::OldRod | EncounterMethod::GoodRod | EncounterMethod::SuperRod => {
+                environment == EnvironmentType::Water
+            }
+            EncounterMethod::RockSmash => matches!(environment, EnvironmentType::Cave | EnvironmentType::Mountain),
+        }
+    }
+
+    /// 遭遇的概率：行走和冲浪使用地点的遭遇率，钓竿越好越容易上钩
+    pub fn encounter_chance(&self, location_rate: f32) -> f32 {
+        match self {
+            EncounterMethod::Walk | EncounterMethod::Surf => location_rate,
+            EncounterMethod::OldRod => 0.5,
+            EncounterMethod::GoodRod => 0.65,
+            EncounterMethod::SuperRod => 0.8,
+            EncounterMethod::RockSmash => 0.4,
+        }
+    }
+}
+
 /// 野生宝可梦生成信息
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WildPokemonSpawn {
@@ -146,6 +274,19 @@ pub struct WildPokemonSpawn {
     pub spawn_rate: f32,     // 相对权重
     pub level_min: u32,
     pub level_max: u32,
+    /// 出现的时段 (为空时全天出现)
+    #[serde(default)]
+    pub times: Vec<TimeOfDay>,
+    /// 遭遇的方式 (默认为在草丛中行走)
+    #[serde(default)]
+    pub method: EncounterMethod,
+}
+
+impl WildPokemonSpawn {
+    /// 是否在指定时段出现
+    pub fn appears_at(&self, time: TimeOfDay) -> bool {
+        self.times.is_empty() || self.times.contains(&time)
+    }
 }
 
 /// 地点信息
@@ -157,6 +298,9 @@ pub struct Location {
 
     // 连接关系
     pub connected_locations: Vec<u32>,
+    /// 部分连接需要重要物品或秘传招式才能通过
+    #[serde(default)]
+    pub connection_requirements: Vec<ConnectionRequirement>,
 
     // 环境相关
     pub environment: EnvironmentType,
@@ -170,6 +314,16 @@ pub struct Location {
     pub unlock_requirement: LocationRequirement,
     pub npc_trainers: Vec<u32>,
 
+    // 设施服务
+    #[serde(default)]
+    pub services: Vec<LocationService>,
+    /// 友好商店的货架
+    #[serde(default)]
+    pub mart: Vec<super::MartItem>,
+    /// 第一次来到这里时获得的道具 ID
+    #[serde(default)]
+    pub gift_items: Vec<u32>,
+
     // 游戏开始相关
     pub is_starting_location: bool,
 }
@@ -189,12 +343,16 @@ impl Location {
             name,
             description,
             connected_locations: Vec::new(),
+            connection_requirements: Vec::new(),
             environment,
             stat_bonus,
             wild_pokemon_pool: Vec::new(),
             encounter_rate: 0.7,
             unlock_requirement: LocationRequirement::default(),
             npc_trainers: Vec::new(),
+            services: Vec::new(),
+            mart: Vec::new(),
+            gift_items: Vec::new(),
             is_starting_location: false,
         }
     }
@@ -206,6 +364,11 @@ impl Location {
         }
     }
 
+    /// 前往相邻地点需要满足的条件
+    pub fn connection_requirement(&self, to: u32) -> Option<&ConnectionRequirement> {
+        self.connection_requirements.iter().find(|requirement| requirement.to == to)
+    }
+
     /// 添加野生宝可梦
     pub fn add_wild_pokemon(&mut self, spawn: WildPokemonSpawn) {
         self.wild_pokemon_pool.push(spawn);
@@ -215,6 +378,20 @@ impl Location {
     pub fn environment_name(&self) -> &str {
         self.environment.name()
     }
+
+    /// 某种遭遇方式的宝可梦表
+    pub fn encounter_table(&self, method: EncounterMethod) -> Vec<WildPokemonSpawn> {
+        self.wild_pokemon_pool.iter().filter(|spawn| spawn.method == method).cloned().collect()
+    }
+
+    /// 地点环境允许、并且有宝可梦出现的遭遇方式
+    pub fn encounter_methods(&self) -> Vec<EncounterMethod> {
+        EncounterMethod::ALL
+            .into_iter()
+            .filter(|method| method.allowed_in(self.environment))
+            .filter(|method| self.wild_pokemon_pool.iter().any(|spawn| spawn.method == *method))
+            .collect()
+    }
 }
 
 /// 玩家位置状态
@@ -223,6 +400,14 @@ pub struct PlayerLocationState {
     pub current_location_id: u32,
     pub unlocked_locations: HashSet<u32>,
     pub visited_locations: HashSet<u32>,
+    /// 最后到过的宝可梦中心所在地点，全队昏迷后回到这里
+    #[serde(default = "default_pokemon_center_id")]
+    pub last_pokemon_center_id: u32,
+}
+
+/// 起始小镇的宝可梦中心
+fn default_pokemon_center_id() -> u32 {
+    101
 }
 
 impl Default for PlayerLocationState {
@@ -237,6 +422,7 @@ impl Default for PlayerLocationState {
             current_location_id: 101,
             unlocked_locations: unlocked,
             visited_locations: visited,
+            last_pokemon_center_id: default_pokemon_center_id(),
         }
     }
 }
@@ -247,6 +433,13 @@ impl PlayerLocationState {
         self.unlocked_locations.contains(&location_id)
     }
 
+    /// 来到地点时记录宝可梦中心
+    pub fn record_pokemon_center(&mut self, location: &Location) {
+        if location.services.contains(&LocationService::PokemonCenter) {
+            self.last_pokemon_center_id = location.id;
+        }
+    }
+
     /// 是否已访问某个地点
     pub fn is_visited(&self, location_id: u32) -> bool {
         self.visited_locations.contains(&location_id)
@@ -292,10 +485,73 @@ mod tests {
             required_level: 5,
             required_badges: vec![1, 2],
             required_pokemon_count: Some(3),
+            required_flags: vec!["league_pass".to_string()],
         };
         let text = req.get_condition_text();
         assert!(text.contains("等级"));
         assert!(text.contains("徽章"));
+        assert!(text.contains("任务"));
+    }
+
+    #[test]
+    fn test_unlock_requires_exact_badges() {
+        let req = LocationRequirement {
+            required_badges: vec![1],
+            ..LocationRequirement::default()
+        };
+        let mut player = crate::game::Player::new("测试".to_string());
+        player.award_badge(2);
+        assert!(!player.can_unlock_location(&req));
+        player.award_badge(1);
+        assert!(player.can_unlock_location(&req));
+    }
+
+    #[test]
+    fn test_unlock_requires_quest_flag() {
+        let req = LocationRequirement {
+            required_flags: vec!["league_pass".to_string()],
+            ..LocationRequirement::default()
+        };
+        let mut player = crate::game::Player::new("测试".to_string());
+        assert!(!player.can_unlock_location(&req));
+        player.quests.flags.insert("league_pass".to_string());
+        assert!(player.can_unlock_location(&req));
+    }
+
+    #[test]
+    fn test_encounter_methods_follow_environment() {
+        let mut location = Location::new(1, "海边".to_string(), String::new(), EnvironmentType::Water);
+        location.wild_pokemon_pool = serde_json::from_str(
+            r#"[{"pokemon_id": 129, "spawn_rate": 1.0, "level_min": 5, "level_max": 10, "method": "old_rod"},
+                {"pokemon_id": 7, "spawn_rate": 1.0, "level_min": 5, "level_max": 10, "method": "surf"},
+                {"pokemon_id": 74, "spawn_rate": 1.0, "level_min": 5, "level_max": 10, "method": "rock_smash"}]"#,
+        )
+        .unwrap();
+        assert_eq!(location.encounter_methods(), vec![EncounterMethod::Surf, EncounterMethod::OldRod]);
+        assert_eq!(location.encounter_table(EncounterMethod::OldRod).len(), 1);
+        assert!(location.encounter_table(EncounterMethod::Walk).is_empty());
+        assert!(EncounterMethod::RockSmash.allowed_in(EnvironmentType::Cave));
+        assert!(!EncounterMethod::SuperRod.allowed_in(EnvironmentType::Grassland));
+    }
+
+    #[test]
+    fn test_connection_requirements() {
+        let mut location = Location::new(1, "城市".to_string(), String::new(), EnvironmentType::City);
+        location.add_connection(2);
+        location.connection_requirements = serde_json::from_str(
+            r#"[{"to": 2, "key_item": 32}, {"to": 3, "field_ability": "surf"}]"#,
+        )
+        .unwrap();
+        assert_eq!(location.connection_requirement(3).unwrap().field_ability, Some(crate::game::FieldAbility::Surf));
+        assert!(location.connection_requirement(4).is_none());
+
+        let mut player = crate::game::Player::new("测试".to_string());
+        let bicycle = location.connection_requirement(2).unwrap();
+        assert!(player.check_connection(bicycle).is_err());
+        player.add_item(32, 1);
+        assert_eq!(player.check_connection(bicycle), Ok(None));
+        // 队伍里没有会冲浪的宝可梦
+        assert!(player.check_connection(location.connection_requirement(3).unwrap()).is_err());
     }
 
     #[test]
@@ -0,0 +1,162 @@
+use super::{Item, Player};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// 一次最多购买或卖出的数量
+pub const MAX_QUANTITY: u32 = 99;
+/// 卖出价格为买入价格的几分之一
+const SELL_PRICE_DIVISOR: u32 = 2;
+
+/// 商店货架上的一种道具 (对应 world.json 中 mart 的一项)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MartItem {
+    pub item_id: u32,
+    /// 需要的徽章 ID，拥有其中每一枚后才会上架
+    #[serde(default)]
+    pub required_badges: Vec<u32>,
+}
+
+impl MartItem {
+    /// 玩家是否已经拥有上架需要的徽章
+    pub fn is_unlocked(&self, player: &Player) -> bool {
+        self.required_badges.iter().all(|&badge_id| player.has_badge(badge_id))
+    }
+}
+
+/// 道具的卖出价格 (买入价格的一半)，没有价格的道具 (如秘传学习器) 不能卖出
+pub fn sell_price(item: &Item) -> Option<u32> {
+    item.price.map(|price| price / SELL_PRICE_DIVISOR)
+}
+
+/// 买卖完成后的收据
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Receipt {
+    pub is_purchase: bool,
+    pub item_name: String,
+    pub quantity: u32,
+    pub unit_price: u32,
+    pub total: u32,
+    /// 交易后的余额
+    pub money_left: u32,
+}
+
+impl fmt::Display for Receipt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (title, action) = if self.is_purchase { ("购买收据", "支付") } else { ("卖出收据", "收入") };
+        writeln!(f, "┌──────────── {} ────────────┐", title)?;
+        writeln!(f, "  {} x{}  @ ¥{}", self.item_name, self.quantity, self.unit_price)?;
+        writeln!(f, "  {}: ¥{}", action, self.total)?;
+        writeln!(f, "  余额: ¥{}", self.money_left)?;
+        write!(f, "└──────────────────────────────────┘")
+    }
+}
+
+/// 检查一次交易的数量
+pub(crate) fn check_quantity(quantity: u32) -> Result<(), String> {
+    if quantity == 0 || quantity > MAX_QUANTITY {
+        return Err(format!("数量必须在 1-{} 之间", MAX_QUANTITY));
+    }
+    Ok(())
+}
+
+/// 一家友好商店：货架来自地点数据，按徽章解锁
+#[derive(Debug, Clone, Default)]
+pub struct Mart {
+    pub stock: Vec<MartItem>,
+}
+
+impl Mart {
+    pub fn new(stock: Vec<MartItem>) -> Self {
+        Mart { stock }
+    }
+
+    /// 以玩家当前的徽章可以购买的道具
+    pub fn available_items(&self, player: &Player) -> Vec<Item> {
+        self.stock
+            .iter()
+            .filter(|entry| entry.is_unlocked(player))
+            .filter_map(|entry| Item::get(entry.item_id))
+            .collect()
+    }
+
+    /// 还没有解锁的商品数量
+    pub fn locked_count(&self, player: &Player) -> usize {
+        self.stock.iter().filter(|entry| !entry.is_unlocked(player)).count()
+    }
+
+    /// 背包中可以卖出的道具 (有价格的道具)
+    pub fn sellable_items(player: &Player) -> Vec<(Item, u32)> {
+        player.items.items_where(|item| sell_price(item).is_some())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::ItemType;
+
+    fn potion() -> Item {
+        Item {
+            id: 2,
+            name: "恢复药".to_string(),
+            english_name: "Potion".to_string(),
+            item_type: ItemType::Potion,
+            description: "恢复宝可梦 50% 的 HP".to_string(),
+            price: Some(150),
+            recovery_percent: Some(50),
+            is_revive: false,
+            is_healing: true,
+        }
+    }
+
+    #[test]
+    fn test_buy_is_atomic() {
+        let mut player = Player::new("测试".to_string());
+        let before = player.item_count(2);
+        player.money = 400;
+
+        assert!(player.buy_item(&potion(), 3).is_err());
+        assert_eq!(player.money, 400);
+        assert_eq!(player.item_count(2), before);
+
+        let receipt = player.buy_item(&potion(), 2).unwrap();
+        assert_eq!(receipt.total, 300);
+        assert_eq!(receipt.money_left, 100);
+        assert_eq!(player.item_count(2), before + 2);
+        assert!(player.buy_item(&potion(), 0).is_err());
+    }
+
+    #[test]
+    fn test_sell_at_half_price() {
+        let mut player = Player::new("测试".to_string());
+        player.money = 0;
+        let owned = player.item_count(2);
+
+        assert!(player.sell_item(&potion(), owned + 1).is_err());
+        let receipt = player.sell_item(&potion(), 1).unwrap();
+        assert_eq!(receipt.unit_price, 75);
+        assert_eq!(player.money, 75);
+        assert_eq!(player.item_count(2), owned - 1);
+
+        let mut hm = potion();
+        hm.price = None;
+        assert!(player.sell_item(&hm, 1).is_err());
+    }
+
+    #[test]
+    fn test_mart_locks_by_badges() {
+        let mart = Mart::new(vec![
+            MartItem { item_id: 1, required_badges: vec![] },
+            MartItem { item_id: 4, required_badges: vec![2] },
+        ]);
+        let mut player = Player::new("测试".to_string());
+        assert_eq!(mart.locked_count(&player), 1);
+
+        // 徽章数量相同但不是需要的那一枚
+        player.award_badge(1);
+        assert_eq!(mart.locked_count(&player), 1);
+
+        player.award_badge(2);
+        assert_eq!(mart.locked_count(&player), 0);
+    }
+}
@@ -5,11 +5,41 @@ pub mod item;
 pub mod location;
 pub mod wild_pokemon;
 pub mod storage;
+pub mod growth_rate;
+pub mod evolution;
+pub mod learnset;
+pub mod field_ability;
+pub mod friendship;
+pub mod breeding;
+pub mod mart;
+pub mod badge;
+pub mod pokedex;
+pub mod stats;
+pub mod achievement;
+pub mod quest;
+pub mod clock;
+pub mod encounter_effect;
+pub mod ability;
 
 pub use pokemon::{Pokemon, Move, MoveType, PokemonType, Stat};
 pub use player::Player;
 pub use battle::{Battle, BattleStatus};
-// pub use item::{Item, ItemType};  // Unused - removed
-pub use location::{Location, EnvironmentType, EnvironmentBonus, LocationRequirement, WildPokemonSpawn, PlayerLocationState};
+pub use item::{BagSort, Inventory, Item, ItemType, Pocket};
+pub use location::{Location, ConnectionRequirement, EncounterMethod, EnvironmentType, EnvironmentBonus, LocationRequirement, LocationService, WildPokemonSpawn, PlayerLocationState};
 pub use wild_pokemon::{WildPokemonPreview, WildPokemonEncounter};
 pub use storage::StorageSystem;  // PokemonBox, StorageStats removed - unused
+pub use growth_rate::GrowthRate;
+pub use evolution::{Evolution, EvolutionMethod, EvolutionTrigger};
+pub use learnset::Learnset;
+pub use field_ability::FieldAbility;
+pub use friendship::FriendshipEvent;
+pub use breeding::{DayCare, Egg};
+pub use mart::MartItem;
+pub use badge::BadgeInfo;
+pub use pokedex::{Pokedex, PokedexEntry};
+pub use stats::{TrainerCard, TrainerStats};
+pub use achievement::Achievement;
+pub use quest::{Quest, QuestLog, QuestObjective, QuestStatus};
+pub use clock::{GameClock, TimeOfDay};
+pub use encounter_effect::{EffectKind, EncounterEffects, EncounterRateModifier, StepEffect};
+pub use ability::Ability;
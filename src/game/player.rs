@@ -1,6 +1,20 @@
-use super::{Pokemon, PlayerLocationState, StorageSystem};
+use super::friendship::MAX_FRIENDSHIP;
+use super::breeding::BreedingItem;
+use super::item::{POKE_BALL_ID, POTION_ID, REVIVE_ID, SUPER_POTION_ID};
+use super::mart::{self, Receipt};
+use super::badge::{self, BadgeInfo};
+use super::{DayCare, EffectKind, Egg, EncounterEffects, EncounterMethod, StepEffect, FieldAbility, GameClock, FriendshipEvent, Inventory, Item, ItemType, Move, Pokedex, Pokemon, PlayerLocationState, Quest, QuestLog, QuestObjective, QuestStatus, StorageSystem, TrainerStats};
+use crate::pokemon_generator::Gender;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashSet};
+
+/// 当前存档格式版本
+/// - 0: 初始版本 (经验值为 等级 × 100 的线性进度)
+/// - 1: 经验值改为按经验值类型累计
+/// - 2: 背包改为以道具 ID 为键
+/// - 3: 宝可梦记录性别
+/// - 4: 加入图鉴
+pub const SAVE_VERSION: u32 = 4;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Badge {
@@ -14,36 +28,98 @@ pub struct Player {
     pub level: u32,
     pub pokemons: Vec<Pokemon>,
     pub badges: Vec<Badge>,
-    pub items: HashMap<String, u32>,
+    pub items: Inventory,        // 背包 (道具 ID → 数量)
     pub money: u32,
     pub visited_pokemon_center: bool,
     pub location_state: PlayerLocationState,
     pub storage: StorageSystem,  // 宠物仓库
+    #[serde(default)]
+    pub save_version: u32,       // 存档格式版本
+    #[serde(default)]
+    pub day_care: DayCare,       // 饲育屋
+    #[serde(default)]
+    pub eggs: Vec<Egg>,          // 随身携带的蛋 (与队伍共用 6 个位置)
+    #[serde(default)]
+    pub hardcore: bool,          // 硬核模式：全队昏迷后只能用道具或付费复活
+    #[serde(default)]
+    pub defeated_trainers: HashSet<u32>, // 已经击败过的训练师 ID
+    #[serde(default)]
+    pub pokedex: Pokedex,        // 见过与捕捉过的物种
+    #[serde(default)]
+    pub stats: TrainerStats,     // 终身统计
+    #[serde(default)]
+    pub achievements: BTreeMap<u32, u64>, // 已解锁的成就 ID 与解锁时间
+    #[serde(default)]
+    pub quests: QuestLog,        // 任务日志与任务标记
+    #[serde(default)]
+    pub clock: GameClock,        // 游戏内时钟
+    #[serde(default)]
+    pub encounter_effects: EncounterEffects, // 正在生效的驱虫喷雾与引虫香水
 }
 
 impl Player {
     pub fn new(name: String) -> Self {
-        let mut items = HashMap::new();
-        items.insert("Poké Ball".to_string(), 5);
-        items.insert("恢复药".to_string(), 3);          // 新增：3个恢复药
-        items.insert("超级恢复药".to_string(), 1);      // 新增：1个超级恢复药
-        items.insert("全复活".to_string(), 1);          // 新增：1个全复活
-
         Player {
             name,
             level: 1,
             pokemons: vec![],
             badges: vec![],
-            items,
+            items: Self::starting_inventory(),
             money: 0,
             visited_pokemon_center: false,
             location_state: PlayerLocationState::default(),
             storage: StorageSystem::new(),
+            save_version: SAVE_VERSION,
+            day_care: DayCare::default(),
+            eggs: Vec::new(),
+            hardcore: false,
+            defeated_trainers: HashSet::new(),
+            pokedex: Pokedex::default(),
+            stats: TrainerStats::new(),
+            achievements: BTreeMap::new(),
+            quests: QuestLog::default(),
+            clock: GameClock::default(),
+            encounter_effects: EncounterEffects::default(),
+        }
+    }
+
+    /// 初始背包：读取 player_defaults.json 中的 starting.inventory，没有数据时使用默认道具
+    fn starting_inventory() -> Inventory {
+        let mut items = Inventory::default();
+        let configured = crate::data::loader::get_game_data().and_then(|data| {
+            data.player_defaults.get("starting")?.get("inventory")?.as_array().cloned()
+        });
+        match configured {
+            Some(entries) => {
+                for entry in entries {
+                    let item_type = entry.get("item_type").and_then(|v| v.as_str()).unwrap_or("");
+                    let quantity = entry.get("quantity").and_then(|v| v.as_u64()).unwrap_or(0) as u32;
+                    let item_id = crate::data::loader::get_item_by_type(item_type)
+                        .and_then(|item| item.get("id")?.as_u64());
+                    if let Some(item_id) = item_id {
+                        items.add(item_id as u32, quantity);
+                    }
+                }
+            }
+            None => {
+                items.add(POKE_BALL_ID, 5);
+                items.add(POTION_ID, 3);
+                items.add(SUPER_POTION_ID, 1);
+                items.add(REVIVE_ID, 1);
+            }
         }
+        items
+    }
+
+    /// 队伍是否已满 (携带的蛋也占队伍的位置)
+    pub fn party_is_full(&self) -> bool {
+        self.pokemons.len() + self.eggs.len() >= 6
     }
 
-    pub fn add_pokemon(&mut self, pokemon: Pokemon) -> bool {
-        if self.pokemons.len() < 6 {
+    /// 把宝可梦加入队伍，没有初训家的宝可梦记为玩家自己的
+    pub fn add_pokemon(&mut self, mut pokemon: Pokemon) -> bool {
+        if !self.party_is_full() {
+            pokemon.original_trainer.get_or_insert_with(|| self.name.clone());
             self.pokemons.push(pokemon);
             true
         } else {
@@ -55,22 +131,40 @@ impl Player {
         self.pokemons.iter_mut().find(|p| !p.is_fainted())
     }
 
-    pub fn add_item(&mut self, item: String, count: u32) {
-        *self.items.entry(item).or_insert(0) += count;
+    pub fn add_item(&mut self, item_id: u32, count: u32) {
+        self.items.add(item_id, count);
     }
 
-    pub fn use_item(&mut self, item: &str, count: u32) -> bool {
-        if let Some(current) = self.items.get_mut(item) {
-            if *current >= count {
-                *current -= count;
-                return true;
-            }
+    pub fn use_item(&mut self, item_id: u32, count: u32) -> bool {
+        self.items.remove(item_id, count)
+    }
+
+    pub fn item_count(&self, item_id: u32) -> u32 {
+        self.items.count(item_id)
+    }
+
+    /// 按 ID 查找道具，背包中没有时返回 "不足" 的错误
+    fn owned_item(&self, item_id: u32) -> Result<Item, String> {
+        let item = Item::get(item_id).ok_or_else(|| format!("未知的道具: {}", item_id))?;
+        if self.item_count(item_id) == 0 {
+            return Err(format!("{}不足", item.name));
         }
-        false
+        Ok(item)
     }
 
     pub fn add_money(&mut self, amount: u32) {
-        self.money += amount;
+        self.money = self.money.saturating_add(amount);
+        self.stats.money_earned += amount as u64;
+    }
+
+    /// 支付金钱，余额不足时不扣款
+    pub fn spend_money(&mut self, amount: u32) -> Result<(), String> {
+        if self.money < amount {
+            return Err(format!("金币不足。需要 ¥{}，但只有 ¥{}", amount, self.money));
+        }
+        self.money -= amount;
+        self.stats.money_spent += amount as u64;
+        Ok(())
     }
 
     pub fn has_active_pokemon(&self) -> bool {
@@ -83,18 +177,228 @@ impl Player {
             let status = if pokemon.is_fainted() { "[昏迷]" } else { "" };
             println!("{}. {} {}", i + 1, pokemon, status);
         }
+        if !self.eggs.is_empty() {
+            println!("🥚 携带的蛋 x{}", self.eggs.len());
+        }
+    }
+
+    /// 丢弃背包中的道具，重要物品与秘传学习器不能丢弃
+    pub fn toss_item(&mut self, item_id: u32, count: u32) -> Result<String, String> {
+        let item = self.owned_item(item_id)?;
+        if !item.can_toss() {
+            return Err(format!("{} 很重要，不能丢掉", item.name));
+        }
+        if count == 0 || !self.use_item(item_id, count) {
+            return Err(format!("{}不足", item.name));
+        }
+        Ok(format!("丢掉了 {} x{}", item.name, count))
     }
 
-    pub fn display_items(&self) {
-        println!("\n=== 背包 ===");
-        if self.items.is_empty() {
-            println!("背包为空");
+    /// 背包中可调整努力值的道具 (营养剂与树果)，按 ID 排序
+    pub fn get_ev_items(&self) -> Vec<(Item, u32)> {
+        self.items.items_where(|item| Self::ev_item_effect(item).is_some())
+    }
+
+    /// 对队伍中的宝可梦使用营养剂或树果；没有效果时不消耗道具
+    pub fn use_ev_item(&mut self, pokemon_index: usize, item_id: u32) -> Result<String, String> {
+        if pokemon_index >= self.pokemons.len() {
+            return Err("宝可梦序号无效".to_string());
+        }
+
+        let item = self.owned_item(item_id)?;
+        let (stat, change) = Self::ev_item_effect(&item)
+            .ok_or_else(|| format!("{} 不能调整努力值", item.name))?;
+
+        // 营养剂和树果还会提升亲密度；树果在努力值降不下去时也能用来提升亲密度
+        let friendship_change = Self::item_friendship_change(&item);
+        let pokemon = &mut self.pokemons[pokemon_index];
+        let message = match pokemon.adjust_effort_value(&stat, change) {
+            Ok(message) => message,
+            Err(_) if friendship_change > 0 && pokemon.friendship < MAX_FRIENDSHIP => {
+                format!("{} 的努力值没有变化", pokemon.display_name())
+            }
+            Err(e) => return Err(e),
+        };
+        let message = if pokemon.change_friendship(friendship_change) > 0 {
+            format!("{}，{} 变得更亲近你了", message, pokemon.display_name())
         } else {
-            for (item, count) in self.items.iter() {
-                println!("{}: {}", item, count);
+            message
+        };
+        self.use_item(item_id, 1);
+        Ok(format!("✓ {}", message))
+    }
+
+    /// 使用驱虫喷雾或引虫香水，同种效果会被新的道具替换
+    pub fn use_encounter_item(&mut self, item_id: u32) -> Result<String, String> {
+        let item = self.owned_item(item_id)?;
+        let kind = match item.item_type {
+            ItemType::Repel => EffectKind::Repel,
+            ItemType::Lure => EffectKind::Lure,
+            _ => return Err(format!("{} 不能在野外使用", item.name)),
+        };
+        let steps = item.effect_steps().ok_or_else(|| format!("{} 没有设置有效步数", item.name))?;
+        let multiplier = match kind {
+            EffectKind::Repel => 1.0,
+            EffectKind::Lure => item.encounter_rate_multiplier().unwrap_or(1.0),
+        };
+
+        self.encounter_effects.activate(kind, StepEffect { item_id, steps_left: steps, multiplier });
+        self.use_item(item_id, 1);
+        Ok(format!("✓ 使用了 {}，效果持续 {} 步", item.name, steps))
+    }
+
+    /// 让队伍中的宝可梦携带道具，原来携带的道具放回背包
+    pub fn give_held_item(&mut self, pokemon_index: usize, item_id: u32) -> Result<String, String> {
+        if pokemon_index >= self.pokemons.len() {
+            return Err("宝可梦序号无效".to_string());
+        }
+        let item = self.owned_item(item_id)?;
+        if !item.can_be_held() {
+            return Err(format!("{} 不能让宝可梦携带", item.name));
+        }
+
+        self.use_item(item_id, 1);
+        let pokemon = &mut self.pokemons[pokemon_index];
+        let previous = pokemon.held_item.replace(item_id);
+        let name = pokemon.display_name().to_string();
+        match previous {
+            Some(previous) => {
+                self.add_item(previous, 1);
+                Ok(format!("✓ {} 放下了 {}，改为携带 {}", name, Item::name_of(previous), item.name))
+            }
+            None => Ok(format!("✓ {} 携带了 {}", name, item.name)),
+        }
+    }
+
+    /// 领头的宝可梦：队伍中第一只未昏迷的宝可梦
+    pub fn lead_pokemon(&self) -> Option<&Pokemon> {
+        self.pokemons.iter().find(|p| !p.is_fainted())
+    }
+
+    /// 和队伍一起移动到新地点，未昏迷的宝可梦亲密度提升
+    pub fn walk_with_team(&mut self) {
+        for pokemon in self.pokemons.iter_mut().filter(|p| !p.is_fainted()) {
+            pokemon.apply_friendship_event(FriendshipEvent::Walk);
+        }
+    }
+
+    /// 背包中的进化道具 (进化石)，按 ID 排序
+    pub fn get_evolution_items(&self) -> Vec<(Item, u32)> {
+        self.items.items_where(|item| item.item_type == ItemType::EvolutionStone)
+    }
+
+    /// 对队伍中的宝可梦使用进化道具；无法进化时不消耗道具
+    pub fn use_evolution_item(&mut self, pokemon_index: usize, item_id: u32) -> Result<String, String> {
+        if pokemon_index >= self.pokemons.len() {
+            return Err("宝可梦序号无效".to_string());
+        }
+
+        let item = self.owned_item(item_id)?;
+        if item.item_type != ItemType::EvolutionStone {
+            return Err(format!("{} 不是进化道具", item.name));
+        }
+
+        let pokemon = &mut self.pokemons[pokemon_index];
+        let target_id = crate::game::evolution::check_evolution(
+            pokemon,
+            crate::game::EvolutionTrigger::UseItem(item_id),
+            self.clock.time_of_day(),
+        )
+        .ok_or_else(|| format!("对 {} 没有效果", pokemon.name))?;
+
+        let message = crate::game::evolution::evolve(pokemon, target_id)?;
+        self.pokedex.mark_caught(target_id);
+        self.use_item(item_id, 1);
+        Ok(format!("✓ {}", message))
+    }
+
+    /// 通过交换 (导入 Showdown 队伍) 获得宝可梦，满足交换进化条件时立即进化，队伍已满时送入仓库
+    pub fn receive_traded_pokemon(&mut self, mut pokemon: Pokemon) -> Result<String, String> {
+        let mut message = format!("交换得到了 {}！", pokemon.display_name());
+
+        if let Some(target_id) = crate::game::evolution::check_evolution(
+            &pokemon,
+            crate::game::EvolutionTrigger::Trade,
+            self.clock.time_of_day(),
+        ) {
+            message = format!("{} {}", message, crate::game::evolution::evolve(&mut pokemon, target_id)?);
+        }
+        self.pokedex.mark_caught(pokemon.id);
+
+        if !self.add_pokemon(pokemon.clone()) {
+            let (box_id, _) = self.storage.add_pokemon(pokemon)?;
+            message = format!("{} (已送往仓库 {} 号箱)", message, box_id);
+        }
+        Ok(message)
+    }
+
+    /// 背包中的招式学习器与秘传学习器，按 ID 排序
+    pub fn get_machine_items(&self) -> Vec<(Item, u32)> {
+        self.items.items_where(|item| Self::machine_item_info(item).is_some())
+    }
+
+    /// 检查队伍中的宝可梦能否用该学习器学习招式，返回要学习的招式
+    pub fn check_machine_move(&self, pokemon_index: usize, item_id: u32) -> Result<Move, String> {
+        let pokemon = self.pokemons.get(pokemon_index).ok_or("宝可梦序号无效")?;
+        let item = self.owned_item(item_id)?;
+        let (move_id, _) = Self::machine_item_info(&item)
+            .ok_or_else(|| format!("{} 不是招式学习器", item.name))?;
+
+        let move_data = crate::data::pokemon_data::get_move_by_id(move_id)
+            .ok_or_else(|| format!("招式 {} 不存在", move_id))?;
+        if !crate::data::pokemon_data::can_learn_machine_move(pokemon.id, move_id) {
+            return Err(format!("{} 无法学会 {}", pokemon.name, move_data.name));
+        }
+        if pokemon.knows_move(move_id) {
+            return Err(format!("{} 已经学会了 {}", pokemon.name, move_data.name));
+        }
+        Ok(move_data)
+    }
+
+    /// 学会招式后消耗学习器 (秘传学习器可以反复使用)
+    pub fn consume_machine(&mut self, item_id: u32) {
+        if let Some((_, false)) = Item::get(item_id).as_ref().and_then(Self::machine_item_info) {
+            self.use_item(item_id, 1);
+        }
+    }
+
+    /// 队伍中未昏迷的宝可梦可以在野外使用的秘传招式能力 (能力, 宝可梦名称)
+    pub fn get_field_abilities(&self) -> Vec<(FieldAbility, String)> {
+        let mut abilities: Vec<(FieldAbility, String)> = Vec::new();
+        for pokemon in self.pokemons.iter().filter(|p| !p.is_fainted()) {
+            for move_data in &pokemon.moves {
+                if let Some(ability) = crate::data::pokemon_data::get_field_ability(move_data.id) {
+                    if self.can_use_field_ability(ability) && !abilities.iter().any(|(a, _)| *a == ability) {
+                        abilities.push((ability, pokemon.name.clone()));
+                    }
+                }
             }
         }
-        println!("\n金钱: ¥{}", self.money);
+        abilities
+    }
+
+    /// 从 items.json 读取学习器的招式 ID，以及是否为秘传学习器
+    fn machine_item_info(item: &Item) -> Option<(u32, bool)> {
+        let is_hm = match item.item_type {
+            ItemType::TM => false,
+            ItemType::HM => true,
+            _ => return None,
+        };
+        Some((item.field("move_id")?.as_u64()? as u32, is_hm))
+    }
+
+    /// 从 items.json 读取道具的努力值效果 (属性, 变化量)
+    fn ev_item_effect(item: &Item) -> Option<(String, i32)> {
+        let stat = item.field("ev_stat")?.as_str()?.to_string();
+        let change = item.field("ev_change")?.as_i64()? as i32;
+        Some((stat, change))
+    }
+
+    /// 从 items.json 读取道具带来的亲密度变化 (没有时为 0)
+    fn item_friendship_change(item: &Item) -> i32 {
+        item.field("friendship_change")
+            .and_then(|v| v.as_i64())
+            .unwrap_or(0) as i32
     }
 
     // 复活系统方法
@@ -111,7 +415,12 @@ impl Player {
         !self.has_active_pokemon()
     }
 
-    pub fn revive_pokemon_with_item(&mut self, pokemon_index: usize, item_name: &str) -> Result<String, String> {
+    /// 背包中的恢复道具 (恢复药与复活道具)，按 ID 排序
+    pub fn get_recovery_items(&self) -> Vec<(Item, u32)> {
+        self.items.items_where(Item::is_recovery_item)
+    }
+
+    pub fn revive_pokemon_with_item(&mut self, pokemon_index: usize, item_id: u32) -> Result<String, String> {
         if pokemon_index >= self.pokemons.len() {
             return Err("宝可梦序号无效".to_string());
         }
@@ -121,32 +430,29 @@ impl Player {
         let pokemon_name = self.pokemons[pokemon_index].name.clone();
         let max_hp = self.pokemons[pokemon_index].max_hp;
 
-        // 根据道具名称确定复活效果
-        let recovery_percent = match item_name {
-            "恢复药" => {
-                if is_fainted {
-                    return Err("需要使用全复活或完全恢复来复活昏迷的宝可梦".to_string());
-                }
-                0.5  // 50% HP
-            },
-            "超级恢复药" => {
-                if is_fainted {
-                    return Err("需要使用全复活或完全恢复来复活昏迷的宝可梦".to_string());
-                }
-                1.0  // 100% HP
-            },
-            "全复活" => 0.5,  // 复活 + 50% HP
-            "完全恢复" => 1.0,  // 复活 + 100% HP
-            _ => return Err(format!("未知的恢复道具: {}", item_name)),
-        };
+        // 根据 items.json 中的 recovery_percent 与 is_revive 确定效果
+        let item = Item::get(item_id).ok_or_else(|| format!("未知的恢复道具: {}", item_id))?;
+        let recovery_percent = item
+            .get_hp_recovery()
+            .filter(|_| item.is_recovery_item())
+            .ok_or_else(|| format!("未知的恢复道具: {}", item.name))?;
+        if is_fainted && !item.is_revive_item() {
+            return Err("需要使用复活道具来复活昏迷的宝可梦".to_string());
+        }
+        if !is_fainted && item.is_revive_item() {
+            return Err(format!("{}没有昏迷，不需要使用{}", pokemon_name, item.name));
+        }
+        if !is_fainted && self.pokemons[pokemon_index].hp >= max_hp {
+            return Err(format!("{}的 HP 已经是满的", pokemon_name));
+        }
 
         // 检查是否有该道具
-        if !self.use_item(item_name, 1) {
-            return Err(format!("{}不足", item_name));
+        if !self.use_item(item_id, 1) {
+            return Err(format!("{}不足", item.name));
         }
 
         // 执行复活/治疗
-        if item_name == "全复活" || item_name == "完全恢复" {
+        if item.is_revive_item() {
             self.pokemons[pokemon_index].revive(recovery_percent);
             let hp = self.pokemons[pokemon_index].hp;
             Ok(format!("✓ {}复活了，HP 恢复至 {}/{}", pokemon_name, hp, max_hp))
@@ -167,11 +473,7 @@ impl Player {
         // VIP 折扣：已拜访过精灵中心的玩家打 50% 折扣
         let final_cost = if self.visited_pokemon_center { cost / 2 } else { cost };
 
-        if self.money < final_cost {
-            return Err(format!("金币不足。需要 ¥{}，但只有 ¥{}", final_cost, self.money));
-        }
-
-        self.money -= final_cost;
+        self.spend_money(final_cost)?;
         self.pokemons[pokemon_index].revive(1.0);  // 100% HP 恢复
         self.visited_pokemon_center = true;
 
@@ -191,11 +493,7 @@ impl Player {
         let total_cost = fainted_count as u32 * cost_per_pokemon;
         let final_cost = if self.visited_pokemon_center { total_cost / 2 } else { total_cost };
 
-        if self.money < final_cost {
-            return Err(format!("金币不足。需要 ¥{}，但只有 ¥{}", final_cost, self.money));
-        }
-
-        self.money -= final_cost;
+        self.spend_money(final_cost)?;
 
         // 复活所有昏迷的宝可梦
         for pokemon in self.pokemons.iter_mut() {
@@ -212,13 +510,37 @@ impl Player {
         ))
     }
 
+    /// 全队昏迷时损失的金钱比例 (%)，随徽章数增加
+    pub fn blackout_loss_percent(&self) -> u32 {
+        let constant = |key: &str, default: u64| {
+            crate::data::loader::get_game_constant("blackout", key).unwrap_or(default) as u32
+        };
+        let percent = constant("base_loss_percent", 10) + constant("loss_percent_per_badge", 5) * self.badges.len() as u32;
+        percent.min(constant("max_loss_percent", 50))
+    }
+
+    /// 全队昏迷：损失部分金钱，回到最后到过的宝可梦中心，全队完全恢复。返回损失的金钱
+    pub fn black_out(&mut self) -> u32 {
+        let lost = (self.money as u64 * self.blackout_loss_percent() as u64 / 100) as u32;
+        // 损失的金钱不是花费，不计入训练师卡的消费统计
+        self.money -= lost;
+        self.location_state.current_location_id = self.location_state.last_pokemon_center_id;
+        self.heal_at_pokemon_center();
+        lost
+    }
+
+    /// 在宝可梦中心免费恢复全队
+    pub fn heal_at_pokemon_center(&mut self) {
+        for pokemon in self.pokemons.iter_mut() {
+            pokemon.full_heal();
+        }
+        self.visited_pokemon_center = true;
+    }
+
     pub fn display_recovery_items(&self) {
         println!("\n=== 恢复道具 ===");
-        let recovery_items = vec!["恢复药", "超级恢复药", "全复活", "完全恢复"];
-        for (i, item_name) in recovery_items.iter().enumerate() {
-            if let Some(count) = self.items.get(*item_name) {
-                println!("{}. {} x{}", i + 1, item_name, count);
-            }
+        for (i, (item, count)) in self.get_recovery_items().iter().enumerate() {
+            println!("{}. {} x{}", i + 1, item.name, count);
         }
         println!("0. 返回");
     }
@@ -249,8 +571,8 @@ impl Player {
             }
         }
 
-        // 检查徽章要求
-        if self.badges.len() < requirement.required_badges.len() {
+        // 检查徽章要求 (需要拥有指定的每一枚徽章)
+        if !requirement.required_badges.iter().all(|&badge_id| self.has_badge(badge_id)) {
             return false;
         }
 
@@ -261,7 +583,93 @@ impl Player {
             }
         }
 
-        true
+        // 检查任务标记要求
+        requirement.required_flags.iter().all(|flag| self.quests.has_flag(flag))
+    }
+
+    /// 检查能否通过需要重要物品或秘传招式的连接，不能时返回原因；
+    /// 用到秘传招式时返回使用的提示
+    pub fn check_connection(&self, requirement: &crate::game::ConnectionRequirement) -> Result<Option<String>, String> {
+        if let Some(item_id) = requirement.key_item {
+            if self.item_count(item_id) == 0 {
+                return Err(format!("需要 {} 才能通过", Item::name_of(item_id)));
+            }
+        }
+        match requirement.field_ability {
+            Some(ability) => match self.get_field_abilities().into_iter().find(|(a, _)| *a == ability) {
+                Some((_, pokemon_name)) => Ok(Some(format!("{} 使用了 {}！", pokemon_name, ability.name()))),
+                None if !self.can_use_field_ability(ability) => {
+                    let badge = badge::required_badge_for(ability, &BadgeInfo::all()).map(|b| b.name.clone());
+                    Err(format!(
+                        "{}，需要 {} 才能在野外使用 {}",
+                        ability.obstacle(),
+                        badge.unwrap_or_default(),
+                        ability.name()
+                    ))
+                }
+                None => Err(format!("{}，需要会 {} 的宝可梦", ability.obstacle(), ability.name())),
+            },
+            None => Ok(None),
+        }
+    }
+
+    /// 能否使用某种遭遇方式：冲浪与碎岩需要会对应秘传招式的宝可梦，钓鱼需要对应的钓竿
+    pub fn can_use_encounter_method(&self, method: EncounterMethod) -> bool {
+        let has_ability = |ability: FieldAbility| self.get_field_abilities().iter().any(|(a, _)| *a == ability);
+        match method {
+            EncounterMethod::Walk => true,
+            EncounterMethod::Surf => has_ability(FieldAbility::Surf),
+            EncounterMethod::RockSmash => has_ability(FieldAbility::RockSmash),
+            rod => !self.items.items_where(|item| item.encounter_method() == Some(rod)).is_empty(),
+        }
+    }
+
+    /// 玩家在地点可以使用的遭遇方式 (地点环境允许、有对应的野生宝可梦且玩家具备条件)
+    pub fn available_encounter_methods(&self, location: &crate::game::Location) -> Vec<EncounterMethod> {
+        location
+            .encounter_methods()
+            .into_iter()
+            .filter(|method| self.can_use_encounter_method(*method))
+            .collect()
+    }
+
+    // ==================== 道馆徽章 ====================
+
+    pub fn has_badge(&self, badge_id: u32) -> bool {
+        self.badges.iter().any(|badge| badge.id == badge_id)
+    }
+
+    /// 获得徽章 (已经拥有时返回 None)
+    pub fn award_badge(&mut self, badge_id: u32) -> Option<Badge> {
+        if self.has_badge(badge_id) {
+            return None;
+        }
+        let badge = Badge { name: BadgeInfo::name_of(badge_id), id: badge_id };
+        self.badges.push(badge.clone());
+        Some(badge)
+    }
+
+    /// 拥有的徽章在 badges.json 中的数据
+    fn badge_infos(&self) -> Vec<BadgeInfo> {
+        self.badges.iter().filter_map(|badge| BadgeInfo::get(badge.id)).collect()
+    }
+
+    /// 听从指挥的最高等级，由拥有的徽章决定
+    pub fn obedience_level_cap(&self) -> u32 {
+        badge::obedience_level(&self.badge_infos())
+    }
+
+    /// 徽章带来的战斗属性加成
+    pub fn badge_stat_bonus(&self) -> crate::game::EnvironmentBonus {
+        badge::stat_bonus(&self.badge_infos())
+    }
+
+    /// 能否在野外使用秘传招式：需要徽章许可的招式要先拿到对应的徽章
+    pub fn can_use_field_ability(&self, ability: FieldAbility) -> bool {
+        match badge::required_badge_for(ability, &BadgeInfo::all()) {
+            Some(required) => self.has_badge(required.id),
+            None => true,
+        }
     }
 
     /// 获取队伍最高等级
@@ -303,14 +711,9 @@ impl Player {
             }
         }
 
-        if !requirement.required_badges.is_empty() {
-            let needed = requirement.required_badges.len();
-            let have = self.badges.len();
-            if have >= needed {
-                status.push(format!("✓ 徽章 {}/{}", have, needed));
-            } else {
-                status.push(format!("✗ 徽章 {}/{}", have, needed));
-            }
+        for &badge_id in &requirement.required_badges {
+            let mark = if self.has_badge(badge_id) { "✓" } else { "✗" };
+            status.push(format!("{} {}", mark, BadgeInfo::name_of(badge_id)));
         }
 
         if let Some(required_count) = requirement.required_pokemon_count {
@@ -321,6 +724,11 @@ impl Player {
             }
         }
 
+        for flag in &requirement.required_flags {
+            let mark = if self.quests.has_flag(flag) { "✓" } else { "✗" };
+            status.push(format!("{} 任务: {}", mark, crate::game::quest::flag_source(flag)));
+        }
+
         if status.is_empty() {
             "无条件".to_string()
         } else {
@@ -331,6 +739,23 @@ impl Player {
     /// 从旧存档迁移数据 (Task 8.1)
     /// 为不含位置信息的旧存档添加位置系统数据
     pub fn migrate_from_old_save(mut self) -> Self {
+        if self.save_version < 1 {
+            self.migrate_experience_to_growth_rates();
+        }
+        if self.save_version < 2 {
+            for name in self.items.migrate_legacy_names() {
+                println!("⚠ 无法识别旧存档中的道具: {}", name);
+            }
+        }
+        if self.save_version < 3 {
+            self.migrate_missing_genders();
+        }
+        if self.save_version < 4 {
+            self.seed_pokedex_from_owned_pokemon();
+        }
+        self.save_version = SAVE_VERSION;
+        self.day_care.migrate_legacy_held_items();
+
         // 如果已经有位置信息，则无需迁移
         if self.location_state.current_location_id != 0 {
             return self;
@@ -348,6 +773,57 @@ impl Player {
         self
     }
 
+    /// 将旧存档中的线性经验值 (等级 × 100) 换算为经验曲线上的累计经验值
+    fn migrate_experience_to_growth_rates(&mut self) {
+        let migrate = |pokemon: &mut Pokemon| {
+            // 旧的线性曲线: 经验值累计，达到 等级×100 时升级，
+            // 所以 Lv.L 的宝可梦经验值在 (L-1)×100 到 L×100 之间
+            let old_floor = pokemon.level.saturating_sub(1) * 100;
+            let progress = (pokemon.experience.saturating_sub(old_floor) as f64 / 100.0).clamp(0.0, 1.0);
+
+            pokemon.growth_rate = crate::data::pokemon_data::get_growth_rate(pokemon.id);
+            let floor = pokemon.growth_rate.experience_for_level(pokemon.level);
+            let span = pokemon.growth_rate.experience_for_level(pokemon.level + 1) - floor;
+            pokemon.experience = floor + (span as f64 * progress) as u32;
+            if pokemon.level >= super::pokemon::MAX_LEVEL {
+                pokemon.experience = floor;
+            }
+        };
+
+        self.pokemons.iter_mut().for_each(migrate);
+        self.storage
+            .boxes
+            .iter_mut()
+            .flat_map(|b| b.pokemon.iter_mut())
+            .for_each(migrate);
+    }
+
+    /// 旧存档没有性别字段，读入后都是无性别；按物种的性别比例重新决定
+    /// (真正无性别的物种仍然是无性别)
+    fn migrate_missing_genders(&mut self) {
+        for pokemon in self.owned_pokemon_mut() {
+            if pokemon.gender == Gender::Genderless {
+                pokemon.gender = Gender::random_for_species(pokemon.id);
+            }
+        }
+    }
+
+    /// 旧存档没有图鉴：已经拥有的宝可梦记为捕捉过
+    fn seed_pokedex_from_owned_pokemon(&mut self) {
+        let owned: Vec<u32> = self.owned_pokemon_mut().map(|pokemon| pokemon.id).collect();
+        for species_id in owned {
+            self.pokedex.mark_caught(species_id);
+        }
+    }
+
+    /// 玩家拥有的所有宝可梦：队伍、仓库与饲育屋
+    fn owned_pokemon_mut(&mut self) -> impl Iterator<Item = &mut Pokemon> {
+        self.pokemons
+            .iter_mut()
+            .chain(self.storage.boxes.iter_mut().flat_map(|b| b.pokemon.iter_mut()))
+            .chain(self.day_care.slots.iter_mut().map(|slot| &mut slot.pokemon))
+    }
+
     /// 将队伍中的宝可梦放入仓库
     pub fn store_pokemon(&mut self, team_index: usize) -> Result<String, String> {
         if team_index >= self.pokemons.len() {
@@ -367,7 +843,7 @@ impl Player {
 
     /// 从仓库取出宝可梦到队伍
     pub fn retrieve_pokemon(&mut self, box_id: u32, storage_index: usize) -> Result<String, String> {
-        if self.pokemons.len() >= 6 {
+        if self.party_is_full() {
             return Err("队伍已满 (6/6)，无法添加更多宝可梦".to_string());
         }
 
@@ -403,4 +879,314 @@ impl Player {
 
         messages
     }
+
+    // 友好商店
+
+    /// 购买道具：金钱足够时同时扣款并放入背包，否则两者都不变
+    pub fn buy_item(&mut self, item: &Item, quantity: u32) -> Result<Receipt, String> {
+        mart::check_quantity(quantity)?;
+        let unit_price = item.price.ok_or_else(|| format!("{} 不出售", item.name))?;
+        let total = unit_price
+            .checked_mul(quantity)
+            .ok_or_else(|| "购买数量过多".to_string())?;
+
+        self.spend_money(total)?;
+        self.add_item(item.id, quantity);
+        Ok(Receipt {
+            is_purchase: true,
+            item_name: item.name.clone(),
+            quantity,
+            unit_price,
+            total,
+            money_left: self.money,
+        })
+    }
+
+    /// 卖出道具：以半价卖出，道具足够时同时移出背包并获得金钱
+    pub fn sell_item(&mut self, item: &Item, quantity: u32) -> Result<Receipt, String> {
+        mart::check_quantity(quantity)?;
+        let unit_price = mart::sell_price(item).ok_or_else(|| format!("{} 不能卖出", item.name))?;
+        let owned = self.item_count(item.id);
+        if owned < quantity {
+            return Err(format!("{}不足 (持有 {} 个)", item.name, owned));
+        }
+        let total = unit_price * quantity;
+
+        self.use_item(item.id, quantity);
+        self.add_money(total);
+        Ok(Receipt {
+            is_purchase: false,
+            item_name: item.name.clone(),
+            quantity,
+            unit_price,
+            total,
+            money_left: self.money,
+        })
+    }
+
+    // 饲育屋
+
+    /// 背包中可以在饲育屋携带的道具，按 ID 排序
+    pub fn get_breeding_items(&self) -> Vec<(Item, u32)> {
+        self.items.items_where(|item| BreedingItem::from_item_id(item.id).is_some())
+    }
+
+    /// 把队伍中的宝可梦连同它携带的道具一起寄放到饲育屋
+    pub fn deposit_to_day_care(&mut self, team_index: usize) -> Result<String, String> {
+        if team_index >= self.pokemons.len() {
+            return Err("宝可梦序号无效".to_string());
+        }
+        let others_active = self
+            .pokemons
+            .iter()
+            .enumerate()
+            .any(|(i, p)| i != team_index && !p.is_fainted());
+        if !others_active {
+            return Err("队伍中至少要留下一只能战斗的宝可梦".to_string());
+        }
+
+        let pokemon = self.pokemons.remove(team_index);
+        let message = match pokemon.held_item {
+            Some(item_id) => format!("✓ 把携带着{}的 {} 寄放在了饲育屋", Item::name_of(item_id), pokemon.display_name()),
+            None => format!("✓ 把 {} 寄放在了饲育屋", pokemon.display_name()),
+        };
+        if let Err(e) = self.day_care.deposit(pokemon.clone()) {
+            self.pokemons.insert(team_index, pokemon);
+            return Err(e);
+        }
+        Ok(message)
+    }
+
+    /// 从饲育屋领回宝可梦 (仍然携带着寄放时的道具)
+    pub fn withdraw_from_day_care(&mut self, index: usize) -> Result<String, String> {
+        if self.party_is_full() {
+            return Err("队伍已满 (6/6)，无法领回宝可梦".to_string());
+        }
+        let slot = self.day_care.withdraw(index)?;
+        let message = format!("✓ 领回了 {}", slot.pokemon.display_name());
+        self.pokemons.push(slot.pokemon);
+        Ok(message)
+    }
+
+    /// 领取饲育屋中等待的蛋
+    pub fn collect_egg(&mut self) -> Result<String, String> {
+        if self.day_care.egg.is_none() {
+            return Err("饲育屋里还没有蛋".to_string());
+        }
+        if self.party_is_full() {
+            return Err("队伍已满 (6/6)，无法带上蛋".to_string());
+        }
+        if let Some(egg) = self.day_care.take_egg() {
+            self.eggs.push(egg);
+        }
+        Ok("✓ 收下了宝可梦蛋！带着它走一走就会孵化".to_string())
+    }
+
+    /// 带着蛋走一步，返回孵化的蛋
+    pub fn advance_eggs(&mut self) -> Vec<Egg> {
+        let mut hatched = Vec::new();
+        let mut remaining = Vec::new();
+        for mut egg in self.eggs.drain(..) {
+            if egg.step() {
+                hatched.push(egg);
+            } else {
+                remaining.push(egg);
+            }
+        }
+        self.eggs = remaining;
+        hatched
+    }
+
+    // 任务
+
+    /// 任务对玩家的状态
+    pub fn quest_status(&self, quest: &Quest) -> QuestStatus {
+        self.quests.status(quest)
+    }
+
+    /// 进行中的任务是否已经达成目标
+    pub fn quest_objective_met(&self, quest: &Quest) -> bool {
+        self.quests
+            .active
+            .get(&quest.id)
+            .is_some_and(|&accepted_at| quest.objective.is_met(self, accepted_at))
+    }
+
+    /// 接受任务
+    pub fn accept_quest(&mut self, quest: &Quest) -> Result<String, String> {
+        match self.quest_status(quest) {
+            QuestStatus::Available => {
+                self.quests.active.insert(quest.id, crate::game::stats::now_timestamp());
+                Ok(format!("✓ 接受了任务「{}」", quest.name))
+            }
+            QuestStatus::Active => Err(format!("任务「{}」已经在进行中", quest.name)),
+            QuestStatus::Completed => Err(format!("任务「{}」已经完成", quest.name)),
+            QuestStatus::Locked => Err(format!("还不能接受任务「{}」", quest.name)),
+        }
+    }
+
+    /// 完成任务：交付需要的道具，发放奖励并记录任务标记
+    pub fn complete_quest(&mut self, quest: &Quest) -> Result<String, String> {
+        if self.quest_status(quest) != QuestStatus::Active {
+            return Err(format!("任务「{}」不在进行中", quest.name));
+        }
+        if !self.quest_objective_met(quest) {
+            return Err(format!("还没有达成目标: {}", quest.objective.description()));
+        }
+        if let QuestObjective::DeliverItem { item_id, count } = quest.objective {
+            self.use_item(item_id, count);
+        }
+
+        self.quests.active.remove(&quest.id);
+        self.quests.completed.insert(quest.id);
+        if let Some(flag) = &quest.flag {
+            self.quests.flags.insert(flag.clone());
+        }
+        self.add_money(quest.reward.money);
+        for item in &quest.reward.items {
+            self.add_item(item.item_id, item.count);
+        }
+        Ok(format!("✓ 完成了任务「{}」！", quest.name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::pokemon::tests::create_pikachu;
+    use crate::pokemon_generator::PokemonInstance;
+
+    #[test]
+    fn test_day_care_deposit_and_withdraw() {
+        let mut player = Player::new("测试".to_string());
+        player.add_pokemon(create_pikachu());
+        assert!(player.deposit_to_day_care(0).is_err());
+
+        let mut holder = create_pikachu();
+        holder.held_item = Some(31);
+        player.add_pokemon(holder);
+        assert!(player.deposit_to_day_care(1).is_ok());
+        assert_eq!(player.pokemons.len(), 1);
+        assert_eq!(player.day_care.slots.len(), 1);
+        assert_eq!(player.day_care.slots[0].pokemon.held_item, Some(31));
+        assert!(player.collect_egg().is_err());
+
+        assert!(player.withdraw_from_day_care(0).is_ok());
+        assert_eq!(player.pokemons.len(), 2);
+        assert_eq!(player.pokemons[1].held_item, Some(31));
+        assert!(player.day_care.slots.is_empty());
+    }
+
+    #[test]
+    fn test_eggs_hatch_after_steps() {
+        let mut player = Player::new("测试".to_string());
+        player.eggs.push(Egg { instance: PokemonInstance::new(25, 1), steps_remaining: 1 });
+        player.eggs.push(Egg { instance: PokemonInstance::new(25, 1), steps_remaining: 3 });
+
+        assert_eq!(player.advance_eggs().len(), 1);
+        assert_eq!(player.eggs.len(), 1);
+        assert_eq!(player.eggs[0].steps_remaining, 2);
+    }
+
+    #[test]
+    fn test_migration_rolls_missing_genders() {
+        let mut player = Player::new("测试".to_string());
+        player.add_pokemon(create_pikachu());
+        player.pokemons[0].gender = Gender::Genderless;
+        player.save_version = 2;
+        player.location_state.current_location_id = 101;
+
+        let player = player.migrate_from_old_save();
+        assert_ne!(player.pokemons[0].gender, Gender::Genderless);
+        assert_eq!(player.save_version, SAVE_VERSION);
+    }
+
+    #[test]
+    fn test_traded_pokemon_overflow_to_storage() {
+        let mut player = Player::new("测试".to_string());
+        for _ in 0..6 {
+            player.add_pokemon(create_pikachu());
+        }
+
+        let message = player.receive_traded_pokemon(create_pikachu()).unwrap();
+        assert!(message.contains("仓库"));
+        assert_eq!(player.pokemons.len(), 6);
+        assert_eq!(player.storage.get_box_pokemon(1).unwrap().len(), 1);
+        assert!(player.pokedex.is_caught(25));
+    }
+
+    #[test]
+    fn test_add_money_saturates() {
+        let mut player = Player::new("测试".to_string());
+        player.money = u32::MAX - 10;
+        player.add_money(100);
+        assert_eq!(player.money, u32::MAX);
+        assert_eq!(player.stats.money_earned, 100);
+    }
+
+    #[test]
+    fn test_migration_seeds_pokedex() {
+        let mut player = Player::new("测试".to_string());
+        player.add_pokemon(create_pikachu());
+        let mut stored = create_pikachu();
+        stored.id = 1;
+        player.storage.add_pokemon(stored).unwrap();
+        player.save_version = 3;
+        player.location_state.current_location_id = 101;
+
+        let player = player.migrate_from_old_save();
+        assert!(player.pokedex.is_caught(25));
+        assert!(player.pokedex.is_caught(1));
+        assert_eq!(player.pokedex.caught.len(), 2);
+    }
+
+    #[test]
+    fn test_eggs_count_towards_party_size() {
+        let mut player = Player::new("测试".to_string());
+        for _ in 0..5 {
+            assert!(player.add_pokemon(create_pikachu()));
+        }
+        player.eggs.push(Egg { instance: PokemonInstance::new(25, 1), steps_remaining: 1 });
+
+        assert!(player.party_is_full());
+        assert!(!player.add_pokemon(create_pikachu()));
+        assert_eq!(player.pokemons.len(), 5);
+    }
+
+    #[test]
+    fn test_player_spend_money() {
+        // 测试付费服务扣款：余额不足时不扣款
+        let mut player = Player::new("测试".to_string());
+        player.add_money(1000);
+
+        assert!(player.spend_money(3000).is_err());
+        assert_eq!(player.money, 1000);
+
+        assert!(player.spend_money(1000).is_ok());
+        assert_eq!(player.money, 0);
+    }
+
+    #[test]
+    fn test_player_black_out() {
+        // 测试全队昏迷：按徽章数损失金钱，回到最后的宝可梦中心并完全恢复
+        let mut player = Player::new("测试".to_string());
+        player.add_pokemon(create_pikachu());
+        player.pokemons[0].take_damage(9999);
+        player.add_money(1000);
+        player.location_state.current_location_id = 104;
+        player.location_state.last_pokemon_center_id = 103;
+
+        assert_eq!(player.blackout_loss_percent(), 10);
+        assert_eq!(player.black_out(), 100);
+        assert_eq!(player.money, 900);
+        assert_eq!(player.stats.money_spent, 0);
+        assert_eq!(player.location_state.current_location_id, 103);
+        assert!(!player.all_pokemon_fainted());
+        assert_eq!(player.pokemons[0].hp, player.pokemons[0].max_hp);
+
+        for id in 1..=10 {
+            player.badges.push(Badge { name: format!("徽章{}", id), id });
+        }
+        assert_eq!(player.blackout_loss_percent(), 50);
+    }
 }
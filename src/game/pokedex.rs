@@ -0,0 +1,119 @@
+use super::{Location, PokemonType};
+use crate::data::{locations_data, pokemon_data};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+/// 玩家的宝可梦图鉴：记录见过和捕捉过的物种
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Pokedex {
+    pub seen: BTreeSet<u32>,
+    pub caught: BTreeSet<u32>,
+}
+
+impl Pokedex {
+    /// 记录见过的物种
+    pub fn mark_seen(&mut self, species_id: u32) {
+        self.seen.insert(species_id);
+    }
+
+    /// 记录捕捉到的物种 (同时视为见过)
+    pub fn mark_caught(&mut self, species_id: u32) {
+        self.seen.insert(species_id);
+        self.caught.insert(species_id);
+    }
+
+    /// 是否见过该物种
+    pub fn is_seen(&self, species_id: u32) -> bool {
+        self.seen.contains(&species_id)
+    }
+
+    /// 是否捕捉过该物种
+    pub fn is_caught(&self, species_id: u32) -> bool {
+        self.caught.contains(&species_id)
+    }
+
+    /// 图鉴完成度 (捕捉过的物种占全部物种的百分比)
+    pub fn completion_percent(&self, total_species: usize) -> f32 {
+        if total_species == 0 {
+            return 0.0;
+        }
+        self.caught.len() as f32 / total_species as f32 * 100.0
+    }
+}
+
+/// 图鉴中的一个物种条目
+#[derive(Debug, Clone)]
+pub struct PokedexEntry {
+    pub species_id: u32,
+    pub name: String,
+    pub types: (PokemonType, Option<PokemonType>),
+    pub description: String,
+    pub height: f32, // 米
+    pub weight: f32, // 千克
+    pub locations: Vec<String>, // 野生出没的地点
+}
+
+impl PokedexEntry {
+    /// species.json 中所有物种的条目，按编号排序
+    pub fn all() -> Vec<PokedexEntry> {
+        let locations = locations_data::get_all_locations();
+        pokemon_data::get_all_species_ids()
+            .into_iter()
+            .filter_map(|species_id| Self::build(species_id, &locations))
+            .collect()
+    }
+
+    fn build(species_id: u32, locations: &[Location]) -> Option<PokedexEntry> {
+        let species = pokemon_data::get_pokemon_by_id(species_id)?;
+        let (description, height, weight) = pokemon_data::get_pokedex_data(species_id)?;
+        Some(PokedexEntry {
+            species_id,
+            name: species.name,
+            types: species.pokemon_type,
+            description,
+            height,
+            weight,
+            locations: habitats(species_id, locations),
+        })
+    }
+}
+
+/// 野生宝可梦池中出现该物种的地点名称
+pub fn habitats(species_id: u32, locations: &[Location]) -> Vec<String> {
+    locations
+        .iter()
+        .filter(|location| location.wild_pokemon_pool.iter().any(|spawn| spawn.pokemon_id == species_id))
+        .map(|location| location.name.clone())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::{EncounterMethod, EnvironmentType, WildPokemonSpawn};
+
+    #[test]
+    fn test_pokedex_seen_and_caught() {
+        let mut pokedex = Pokedex::default();
+        pokedex.mark_seen(1);
+        pokedex.mark_caught(25);
+        pokedex.mark_caught(25);
+
+        assert!(pokedex.is_seen(1) && !pokedex.is_caught(1));
+        assert!(pokedex.is_seen(25) && pokedex.is_caught(25));
+        assert_eq!(pokedex.seen.len(), 2);
+        assert_eq!(pokedex.completion_percent(4), 25.0);
+        assert_eq!(pokedex.completion_percent(0), 0.0);
+    }
+
+    #[test]
+    fn test_habitats() {
+        let mut forest = Location::new(1, "森林".to_string(), String::new(), EnvironmentType::Forest);
+        forest.add_wild_pokemon(WildPokemonSpawn { pokemon_id: 10, spawn_rate: 1.0, level_min: 2, level_max: 4, times: Vec::new(), method: EncounterMethod::Walk });
+        let city = Location::new(2, "城市".to_string(), String::new(), EnvironmentType::City);
+
+        let locations = vec![forest, city];
+        assert_eq!(habitats(10, &locations), vec!["森林".to_string()]);
+        assert!(habitats(25, &locations).is_empty());
+    }
+}
@@ -1,5 +1,20 @@
+use super::GrowthRate;
+use super::friendship::{self, FriendshipEvent, MAX_FRIENDSHIP};
+use super::learnset::MAX_MOVES;
+use crate::pokemon_generator::{roll_shiny, EffortValues, Gender, IndividualValues, Nature, PokemonInstance, Talent};
 use serde::{Deserialize, Serialize};
 
+/// 宝可梦等级上限
+pub const MAX_LEVEL: u32 = 100;
+/// 新宝可梦的初始亲密度
+pub const BASE_FRIENDSHIP: u32 = 70;
+/// 昵称最大长度 (字符数)
+pub const MAX_NICKNAME_LENGTH: usize = 12;
+
+fn default_friendship() -> u32 {
+    BASE_FRIENDSHIP
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum PokemonType {
     Normal,
@@ -54,19 +69,48 @@ pub struct Stat {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Pokemon {
     pub id: u32,
-    pub name: String,
+    pub name: String,                 // 物种名称
+    #[serde(default)]
+    pub nickname: Option<String>,     // 昵称
+    #[serde(default)]
+    pub gender: Gender,               // 性别 (旧存档读档时按物种补全)
+    #[serde(default)]
+    pub is_shiny: bool,               // 是否为异色
     pub pokemon_type: (PokemonType, Option<PokemonType>),
     pub level: u32,
-    pub experience: u32,
+    pub experience: u32,              // 累计经验值
+    #[serde(default)]
+    pub growth_rate: GrowthRate,      // 经验值类型
     pub hp: u32,
     pub max_hp: u32,
     pub stats: Stat,
+    #[serde(default = "IndividualValues::perfect")]
+    pub individual_values: IndividualValues, // 个体值 (旧存档视为全 31)
+    #[serde(default)]
+    pub effort_values: EffortValues,  // 努力值
+    #[serde(default)]
+    pub nature: Nature,               // 性格
+    #[serde(default)]
+    pub talent: Talent,               // 天赋
+    #[serde(default = "generate_unique_id")]
+    pub unique_id: String,            // 唯一 ID
+    #[serde(default = "default_friendship")]
+    pub friendship: u32,              // 亲密度 (0-255)
     pub moves: Vec<Move>,
     pub catch_rate: u32,
     // 捕捉信息
     pub caught_with: String,          // 捕捉球类型
     pub caught_location_id: u32,      // 捕捉地点 ID
     pub caught_date: u64,             // 捕捉时间戳
+    #[serde(default)]
+    pub original_trainer: Option<String>, // 初训家 (旧存档视为玩家自己)
+    #[serde(default)]
+    pub held_item: Option<u32>,       // 携带的道具 ID
+}
+
+/// 生成宝可梦的唯一 ID
+fn generate_unique_id() -> String {
+    uuid::Uuid::new_v4().to_string()
 }
 
 impl Pokemon {
@@ -77,13 +121,18 @@ impl Pokemon {
         base_stats: Stat,
         catch_rate: u32,
     ) -> Self {
-        let (hp, max_hp) = Pokemon::calculate_hp(base_stats.hp, 1);
+        let individual_values = IndividualValues::random();
+        let (hp, max_hp) = Pokemon::calculate_hp(base_stats.hp, individual_values.hp, 0, 1);
         Pokemon {
             id,
             name,
+            nickname: None,
+            gender: Gender::random_for_species(id),
+            is_shiny: roll_shiny(crate::data::pokemon_data::get_shiny_odds()),
             pokemon_type,
             level: 1,
             experience: 0,
+            growth_rate: GrowthRate::default(),
             hp,
             max_hp,
             stats: Stat {
@@ -94,11 +143,109 @@ impl Pokemon {
                 sp_defense: base_stats.sp_defense,
                 speed: base_stats.speed,
             },
+            individual_values,
+            effort_values: EffortValues::default(),
+            nature: Nature::random(),
+            talent: Talent::random(),
+            unique_id: generate_unique_id(),
+            friendship: BASE_FRIENDSHIP,
             moves: vec![],
             catch_rate,
             caught_with: "Poké Ball".to_string(),
             caught_location_id: 101, // 默认常青小镇
             caught_date: 0,
+            original_trainer: None,
+            held_item: None,
+        }
+    }
+
+    /// 设置经验值类型，并将经验值校准到当前等级的起点
+    pub fn with_growth_rate(mut self, growth_rate: GrowthRate) -> Self {
+        self.growth_rate = growth_rate;
+        self.experience = growth_rate.experience_for_level(self.level);
+        self
+    }
+
+    /// 套用生成器产生的个体数据 (等级、经验、个体值、努力值、性格、天赋、性别、异色与唯一 ID)
+    pub fn with_instance(mut self, instance: &PokemonInstance) -> Self {
+        self.gender = instance.gender;
+        self.is_shiny = instance.is_shiny;
+        self.individual_values = instance.individual_values;
+        self.effort_values = instance.effort_values;
+        self.nature = instance.nature;
+        self.talent = instance.talent;
+        self.unique_id = instance.unique_id.clone();
+        self.set_level(instance.level);
+        self.experience = instance.experience.max(self.experience);
+        self
+    }
+
+    /// 是否是从其他训练师那里得到的宝可梦
+    pub fn is_traded(&self, trainer_name: &str) -> bool {
+        self.original_trainer.as_deref().is_some_and(|ot| ot != trainer_name)
+    }
+
+    /// 显示用的名称 (有昵称时显示昵称)
+    pub fn display_name(&self) -> &str {
+        self.nickname.as_deref().unwrap_or(&self.name)
+    }
+
+    /// 带异色标记与性别符号的名称，如 "★小电♂"
+    pub fn display_label(&self) -> String {
+        format!(
+            "{}{}{}",
+            if self.is_shiny { "★" } else { "" },
+            self.display_name(),
+            self.gender.symbol()
+        )
+    }
+
+    /// 设置昵称，输入为空时清除昵称
+    pub fn set_nickname(&mut self, nickname: &str) -> Result<(), String> {
+        let nickname = nickname.trim();
+        if nickname.is_empty() {
+            self.nickname = None;
+            return Ok(());
+        }
+        if nickname.chars().count() > MAX_NICKNAME_LENGTH {
+            return Err(format!("昵称不能超过 {} 个字符", MAX_NICKNAME_LENGTH));
+        }
+        self.nickname = Some(nickname.to_string());
+        Ok(())
+    }
+
+    /// 直接设置等级，重新计算最大 HP 并回满，经验值校准到该等级的起点
+    pub fn set_level(&mut self, level: u32) {
+        self.level = level.clamp(1, MAX_LEVEL);
+        self.experience = self.growth_rate.experience_for_level(self.level);
+        let (_, max_hp) = Pokemon::calculate_hp(
+            self.stats.hp,
+            self.individual_values.hp,
+            self.effort_values.hp,
+            self.level,
+        );
+        self.max_hp = max_hp;
+        self.hp = max_hp;
+    }
+
+    /// 进化时套用新物种的编号、名称、属性与种族值，保留个体数据、经验和招式
+    pub fn apply_species(&mut self, species: &Pokemon) {
+        self.id = species.id;
+        self.name = species.name.clone();
+        self.pokemon_type = species.pokemon_type;
+        self.stats = species.stats.clone();
+        self.catch_rate = species.catch_rate;
+
+        let damage = self.max_hp.saturating_sub(self.hp);
+        let (_, max_hp) = Pokemon::calculate_hp(
+            self.stats.hp,
+            self.individual_values.hp,
+            self.effort_values.hp,
+            self.level,
+        );
+        self.max_hp = max_hp;
+        if !self.is_fainted() {
+            self.hp = max_hp.saturating_sub(damage).max(1);
         }
     }
 
@@ -110,21 +257,21 @@ impl Pokemon {
         self
     }
 
-    pub fn calculate_hp(base_hp: u32, level: u32) -> (u32, u32) {
+    pub fn calculate_hp(base_hp: u32, iv: u32, ev: u32, level: u32) -> (u32, u32) {
         let max_hp = if base_hp == 1 {
             1
         } else {
-            ((2 * base_hp as u32 + 31 + 0) * level) / 100 + level + 1
+            ((2 * base_hp + iv + ev / 4) * level) / 100 + level + 1
         };
         (max_hp, max_hp)
     }
 
-    pub fn calculate_stat(base_stat: u32, level: u32) -> u32 {
-        ((2 * base_stat as u32 + 31 + 0) * level) / 100 + 5
+    pub fn calculate_stat(base_stat: u32, iv: u32, ev: u32, level: u32) -> u32 {
+        ((2 * base_stat + iv + ev / 4) * level) / 100 + 5
     }
 
     pub fn add_move(&mut self, move_data: Move) -> bool {
-        if self.moves.len() < 4 {
+        if self.moves.len() < MAX_MOVES {
             self.moves.push(move_data);
             true
         } else {
@@ -132,15 +279,149 @@ impl Pokemon {
         }
     }
 
+    /// 是否已经掌握该招式
+    pub fn knows_move(&self, move_id: u32) -> bool {
+        self.moves.iter().any(|m| m.id == move_id)
+    }
+
+    /// 忘记指定位置的招式并学会新招式，返回被忘记的招式
+    pub fn replace_move(&mut self, index: usize, move_data: Move) -> Result<Move, String> {
+        if index >= self.moves.len() {
+            return Err("无效的招式位置".to_string());
+        }
+        if self.knows_move(move_data.id) {
+            return Err(format!("{} 已经学会了 {}", self.name, move_data.name));
+        }
+        Ok(std::mem::replace(&mut self.moves[index], move_data))
+    }
+
     pub fn level_up(&mut self) {
         self.level += 1;
-        let (new_hp, new_max_hp) = Pokemon::calculate_hp(self.stats.hp, self.level);
+        let (new_hp, new_max_hp) = Pokemon::calculate_hp(
+            self.stats.hp,
+            self.individual_values.hp,
+            self.effort_values.hp,
+            self.level,
+        );
         self.max_hp = new_max_hp;
         if self.hp < new_hp {
             self.hp = new_hp;
         }
     }
 
+    /// 努力值变化后重新计算最大 HP，保持已受到的伤害不变
+    fn refresh_max_hp(&mut self) {
+        let (_, new_max_hp) = Pokemon::calculate_hp(
+            self.stats.hp,
+            self.individual_values.hp,
+            self.effort_values.hp,
+            self.level,
+        );
+        let damage = self.max_hp.saturating_sub(self.hp);
+        self.max_hp = new_max_hp;
+        if !self.is_fainted() {
+            self.hp = new_max_hp.saturating_sub(damage).max(1);
+        }
+    }
+
+    /// 改变亲密度 (限制在 0-255)，返回实际变化量
+    pub fn change_friendship(&mut self, delta: i32) -> i32 {
+        let before = self.friendship.min(MAX_FRIENDSHIP);
+        let after = (before as i32 + delta).clamp(0, MAX_FRIENDSHIP as i32) as u32;
+        self.friendship = after;
+        after as i32 - before as i32
+    }
+
+    /// 按事件改变亲密度，返回实际变化量
+    pub fn apply_friendship_event(&mut self, event: FriendshipEvent) -> i32 {
+        self.change_friendship(event.change(self.friendship))
+    }
+
+    /// 亲密度的文字描述
+    pub fn friendship_description(&self) -> &'static str {
+        friendship::friendship_description(self.friendship)
+    }
+
+    /// 招式的实际威力 (报恩 / 迁怒 取决于亲密度)
+    pub fn move_power(&self, mv: &Move) -> u32 {
+        friendship::friendship_move_power(mv.id, self.friendship).unwrap_or(mv.power)
+    }
+
+    /// 击败宝可梦后获得努力值，返回实际增加的总点数
+    pub fn gain_effort_values(&mut self, ev_yield: &EffortValues) -> u32 {
+        let gained = self.effort_values.add_yield(ev_yield);
+        if gained > 0 {
+            self.refresh_max_hp();
+        }
+        gained
+    }
+
+    /// 使用营养剂 (正数) 或树果 (负数) 调整某项努力值
+    pub fn adjust_effort_value(&mut self, stat: &str, delta: i32) -> Result<String, String> {
+        if !EffortValues::STAT_KEYS.contains(&stat) {
+            return Err(format!("未知的属性: {}", stat));
+        }
+
+        let stat_name = EffortValues::stat_name(stat);
+        let changed = if delta >= 0 {
+            self.effort_values.add(stat, delta as u32)
+        } else {
+            self.effort_values.reduce(stat, delta.unsigned_abs())
+        };
+
+        if changed == 0 {
+            return Err(format!("对 {} 没有效果", self.name));
+        }
+
+        self.refresh_max_hp();
+        let verb = if delta >= 0 { "提升" } else { "降低" };
+        Ok(format!(
+            "{} 的{}努力值{}了 {} (当前: {})",
+            self.name,
+            stat_name,
+            verb,
+            changed,
+            self.effort_values.get(stat)
+        ))
+    }
+
+    /// 升到下一级还需要的经验值 (满级时为 0)
+    pub fn experience_to_next_level(&self) -> u32 {
+        if self.level >= MAX_LEVEL {
+            return 0;
+        }
+        self.growth_rate
+            .experience_for_level(self.level + 1)
+            .saturating_sub(self.experience)
+    }
+
+    /// 当前等级内的经验进度 (已获得, 本级所需)
+    pub fn experience_progress(&self) -> (u32, u32) {
+        if self.level >= MAX_LEVEL {
+            return (0, 0);
+        }
+        let current_floor = self.growth_rate.experience_for_level(self.level);
+        let next_floor = self.growth_rate.experience_for_level(self.level + 1);
+        (
+            self.experience.saturating_sub(current_floor).min(next_floor - current_floor),
+            next_floor - current_floor,
+        )
+    }
+
+    /// 获得经验值并按经验曲线升级，返回升级次数
+    pub fn gain_experience(&mut self, amount: u32) -> u32 {
+        let max_experience = self.growth_rate.experience_for_level(MAX_LEVEL);
+        self.experience = self.experience.saturating_add(amount).min(max_experience);
+
+        let target_level = self.growth_rate.level_for_experience(self.experience);
+        let mut levels_gained = 0;
+        while self.level < target_level {
+            self.level_up();
+            levels_gained += 1;
+        }
+        levels_gained
+    }
+
     pub fn take_damage(&mut self, damage: u32) {
         self.hp = if damage >= self.hp { 0 } else { self.hp - damage };
     }
@@ -153,6 +434,14 @@ impl Pokemon {
         self.hp == 0
     }
 
+    /// 完全恢复 HP 与所有招式的 PP (宝可梦中心)
+    pub fn full_heal(&mut self) {
+        self.hp = self.max_hp;
+        for mv in &mut self.moves {
+            mv.pp = mv.max_pp;
+        }
+    }
+
     pub fn revive(&mut self, recovery_percent: f32) {
         let recovery_amount = (self.max_hp as f32 * recovery_percent) as u32;
         self.hp = std::cmp::min(recovery_amount, self.max_hp);
@@ -168,7 +457,13 @@ impl Pokemon {
             "speed" => self.stats.speed,
             _ => 0,
         };
-        Pokemon::calculate_stat(base_stat, level)
+        let raw = Pokemon::calculate_stat(
+            base_stat,
+            self.individual_values.get(stat),
+            self.effort_values.get(stat),
+            level,
+        );
+        (raw as f32 * self.nature.multipliers().get(stat)) as u32
     }
 }
 
@@ -177,7 +472,166 @@ impl std::fmt::Display for Pokemon {
         write!(
             f,
             "{} (等级: {}) HP: {}/{}",
-            self.name, self.level, self.hp, self.max_hp
+            self.display_label(), self.level, self.hp, self.max_hp
         )
     }
 }
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// 测试用的 Lv.1 皮卡丘 (不依赖 species.json)
+    pub(crate) fn create_pikachu() -> Pokemon {
+        let stats = Stat { hp: 35, attack: 55, defense: 40, sp_attack: 50, sp_defense: 50, speed: 90 };
+        Pokemon::new(25, "皮卡丘".to_string(), (PokemonType::Electric, None), stats, 190)
+    }
+
+    pub(crate) fn create_move(id: u32, name: &str) -> Move {
+        Move {
+            id,
+            name: name.to_string(),
+            move_type: MoveType::Special,
+            pokemon_type: PokemonType::Electric,
+            power: 40,
+            accuracy: 100,
+            pp: 30,
+            max_pp: 30,
+        }
+    }
+
+    #[test]
+    fn test_pokemon_keeps_instance_data() {
+        let mut instance = PokemonInstance::new(25, 30);
+        instance.nature = Nature::Timid;
+        instance.effort_values.add("speed", 100);
+
+        let pokemon = create_pikachu().with_instance(&instance);
+        assert_eq!(pokemon.level, 30);
+        assert_eq!(pokemon.nature, Nature::Timid);
+        assert_eq!(pokemon.talent, instance.talent);
+        assert_eq!(pokemon.unique_id, instance.unique_id);
+        assert_eq!(pokemon.individual_values.total(), instance.individual_values.total());
+        assert_eq!(pokemon.hp, pokemon.max_hp);
+        assert_eq!(pokemon.effort_values, instance.effort_values);
+    }
+
+    #[test]
+    fn test_nature_and_ivs_affect_battle_stats() {
+        let mut pokemon = create_pikachu();
+        pokemon.set_level(50);
+        pokemon.individual_values = IndividualValues::perfect();
+
+        pokemon.nature = Nature::Hardy;
+        let neutral_speed = pokemon.get_effective_stat("speed", 50);
+        pokemon.nature = Nature::Timid;
+        assert_eq!(pokemon.get_effective_stat("speed", 50), (neutral_speed as f32 * 1.1) as u32);
+
+        pokemon.individual_values.speed = 0;
+        assert!(pokemon.get_effective_stat("speed", 50) < (neutral_speed as f32 * 1.1) as u32);
+    }
+
+    #[test]
+    fn test_effort_values_gained_and_adjusted() {
+        let mut pokemon = create_pikachu();
+        pokemon.set_level(50);
+        let max_hp_before = pokemon.max_hp;
+        let speed_before = pokemon.get_effective_stat("speed", pokemon.level);
+
+        let ev_yield = EffortValues { hp: 2, speed: 1, ..Default::default() };
+        for _ in 0..100 {
+            pokemon.gain_effort_values(&ev_yield);
+        }
+        assert_eq!(pokemon.effort_values.hp, 200);
+        assert_eq!(pokemon.effort_values.speed, 100);
+        assert!(pokemon.max_hp > max_hp_before, "HP 努力值应提升最大 HP");
+        assert!(pokemon.get_effective_stat("speed", pokemon.level) > speed_before);
+
+        assert!(pokemon.adjust_effort_value("hp", -10).is_ok());
+        assert_eq!(pokemon.effort_values.hp, 190);
+        assert!(pokemon.adjust_effort_value("attack", -10).is_err(), "没有努力值时树果无效");
+        assert!(pokemon.adjust_effort_value("luck", 10).is_err());
+    }
+
+    #[test]
+    fn test_pokemon_save_roundtrip_preserves_identity() {
+        let pokemon = create_pikachu();
+        let json = serde_json::to_string(&pokemon).unwrap();
+        let loaded: Pokemon = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.unique_id, pokemon.unique_id);
+        assert_eq!(loaded.nature, pokemon.nature);
+        assert_eq!(loaded.individual_values.total(), pokemon.individual_values.total());
+
+        // 旧存档缺少个体数据时，视为满个体值并分配新的唯一 ID
+        let mut old_save: serde_json::Value = serde_json::from_str(&json).unwrap();
+        for key in ["individual_values", "nature", "talent", "unique_id"] {
+            old_save.as_object_mut().unwrap().remove(key);
+        }
+        let migrated: Pokemon = serde_json::from_value(old_save).unwrap();
+        assert_eq!(migrated.individual_values.total(), 186);
+        assert_eq!(migrated.nature, Nature::Hardy);
+        assert!(!migrated.unique_id.is_empty());
+    }
+
+    #[test]
+    fn test_pokemon_replace_move_when_full() {
+        let mut pokemon = create_pikachu();
+        for (id, name) in [(1, "撞击"), (97, "电击"), (39, "摇尾巴"), (98, "电光一闪")] {
+            assert!(pokemon.add_move(create_move(id, name)));
+        }
+        assert!(!pokemon.add_move(create_move(85, "十万伏特")));
+        assert!(pokemon.knows_move(39));
+
+        let forgotten = pokemon.replace_move(2, create_move(85, "十万伏特")).unwrap();
+        assert_eq!(forgotten.name, "摇尾巴");
+        assert!(pokemon.knows_move(85));
+        assert!(!pokemon.knows_move(39));
+        assert_eq!(pokemon.moves.len(), 4);
+
+        assert!(pokemon.replace_move(4, create_move(87, "打雷")).is_err());
+        assert!(pokemon.replace_move(0, create_move(97, "电击")).is_err());
+    }
+
+    #[test]
+    fn test_pokemon_nickname_and_label() {
+        let mut pokemon = create_pikachu();
+        pokemon.gender = Gender::Female;
+        pokemon.is_shiny = true;
+        assert_eq!(pokemon.display_name(), "皮卡丘");
+
+        assert!(pokemon.set_nickname("小电").is_ok());
+        assert_eq!(pokemon.name, "皮卡丘");
+        assert_eq!(pokemon.display_label(), "★小电♀");
+
+        assert!(pokemon.set_nickname("一二三四五六七八九十一二三").is_err());
+        assert_eq!(pokemon.display_name(), "小电");
+
+        // 输入为空时清除昵称
+        assert!(pokemon.set_nickname("  ").is_ok());
+        assert_eq!(pokemon.nickname, None);
+        assert_eq!(pokemon.display_name(), "皮卡丘");
+    }
+
+    #[test]
+    fn test_pokemon_identity_survives_save() {
+        let mut pokemon = create_pikachu();
+        pokemon.set_nickname("小电").unwrap();
+        pokemon.gender = Gender::Male;
+        pokemon.is_shiny = true;
+        let json = serde_json::to_string(&pokemon).unwrap();
+        let loaded: Pokemon = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.nickname.as_deref(), Some("小电"));
+        assert_eq!(loaded.gender, Gender::Male);
+        assert!(loaded.is_shiny);
+
+        // 旧存档没有昵称、性别和异色字段
+        let mut old_save: serde_json::Value = serde_json::from_str(&json).unwrap();
+        for key in ["nickname", "gender", "is_shiny"] {
+            old_save.as_object_mut().unwrap().remove(key);
+        }
+        let migrated: Pokemon = serde_json::from_value(old_save).unwrap();
+        assert_eq!(migrated.nickname, None);
+        assert_eq!(migrated.gender, Gender::Genderless);
+        assert!(!migrated.is_shiny);
+    }
+}
@@ -0,0 +1,293 @@
+use super::{Item, Player};
+use crate::data::{loader, locations_data, pokemon_data};
+use crate::npc::TrainerData;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// 任务目标 (对应 quests.json 中的 objective)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum QuestObjective {
+    CatchSpecies { species_id: u32 },        // 接受任务后捕捉指定物种
+    DefeatTrainer { trainer_id: u32 },       // 接受任务后击败指定训练师
+    DeliverItem { item_id: u32, count: u32 }, // 交付道具 (完成时扣除)
+    ReachLocation { location_id: u32 },      // 接受任务后到达指定地点
+}
+
+impl QuestObjective {
+    /// 当前进度与目标值 (进度不超过目标)，accepted_at 为接受任务的时间
+    ///
+    /// 捕捉、击败和到达都只算接受任务之后发生的，以任务日志在当时记下的时间为准
+    pub fn progress(&self, player: &Player, accepted_at: u64) -> (u32, u32) {
+        let since_accepted = |at: Option<&u64>| at.is_some_and(|&at| at >= accepted_at) as u32;
+        let (current, target) = match *self {
+            QuestObjective::CatchSpecies { species_id } => {
+                (since_accepted(player.quests.caught_at.get(&species_id)), 1)
+            }
+            QuestObjective::DefeatTrainer { trainer_id } => {
+                (since_accepted(player.quests.defeated_at.get(&trainer_id)), 1)
+            }
+            QuestObjective::DeliverItem { item_id, count } => (player.item_count(item_id), count),
+            QuestObjective::ReachLocation { location_id } => {
+                (since_accepted(player.quests.reached_at.get(&location_id)), 1)
+            }
+        };
+        (current.min(target), target)
+    }
+
+    /// 是否已经达成
+    pub fn is_met(&self, player: &Player, accepted_at: u64) -> bool {
+        let (current, target) = self.progress(player, accepted_at);
+        current >= target
+    }
+
+    /// 目标的说明文本
+    pub fn description(&self) -> String {
+        match *self {
+            QuestObjective::CatchSpecies { species_id } => {
+                let name = pokemon_data::get_pokemon_by_id(species_id)
+                    .map(|p| p.name)
+                    .unwrap_or_else(|| format!("#{:03}", species_id));
+                format!("捕捉 {}", name)
+            }
+            QuestObjective::DefeatTrainer { trainer_id } => {
+                let name = TrainerData::get(trainer_id)
+                    .map(|t| t.name)
+                    .unwrap_or_else(|| format!("训练师 {}", trainer_id));
+                format!("击败 {}", name)
+            }
+            QuestObjective::DeliverItem { item_id, count } => {
+                format!("交付 {} ×{}", Item::name_of(item_id), count)
+            }
+            QuestObjective::ReachLocation { location_id } => {
+                let name = locations_data::get_location_by_id(location_id)
+                    .map(|l| l.name)
+                    .unwrap_or_else(|| format!("地点 {}", location_id));
+                format!("到达 {}", name)
+            }
+        }
+    }
+}
+
+/// 奖励中的一种道具
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuestItem {
+    pub item_id: u32,
+    pub count: u32,
+}
+
+/// 完成任务的奖励
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuestReward {
+    pub money: u32,
+    pub items: Vec<QuestItem>,
+}
+
+impl QuestReward {
+    /// 奖励的说明文本，每种奖励一条
+    pub fn descriptions(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        if self.money > 0 {
+            lines.push(format!("¥{}", self.money));
+        }
+        for item in &self.items {
+            lines.push(format!("{} ×{}", Item::name_of(item.item_id), item.count));
+        }
+        lines
+    }
+}
+
+/// quests.json 中定义的一个任务
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Quest {
+    pub id: u32,
+    pub name: String,
+    pub description: String,
+    pub npc_id: u32,                 // 发布任务的 NPC
+    #[serde(default)]
+    pub prerequisites: Vec<u32>,     // 需要先完成的任务
+    pub objective: QuestObjective,
+    #[serde(default)]
+    pub reward: QuestReward,
+    /// 完成后获得的任务标记 (可作为地点的解锁条件)
+    #[serde(default)]
+    pub flag: Option<String>,
+}
+
+impl Quest {
+    /// quests.json 中的所有任务 (加载数据时已经校验过，每一条都能解析)
+    pub fn all() -> Vec<Quest> {
+        loader::get_game_data()
+            .map(|data| {
+                data.quests
+                    .iter()
+                    .map(|quest| serde_json::from_value(quest.clone()).expect("任务数据在加载时已校验"))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// 按 ID 查找任务
+    pub fn get(id: u32) -> Option<Quest> {
+        Self::all().into_iter().find(|quest| quest.id == id)
+    }
+}
+
+/// 获得任务标记的任务名称 (没有任务给出该标记时显示标记本身)
+pub fn flag_source(flag: &str) -> String {
+    Quest::all()
+        .into_iter()
+        .find(|quest| quest.flag.as_deref() == Some(flag))
+        .map(|quest| quest.name)
+        .unwrap_or_else(|| flag.to_string())
+}
+
+/// 任务对玩家的状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuestStatus {
+    Locked,    // 前置任务未完成
+    Available, // 可以接受
+    Active,    // 进行中
+    Completed, // 已完成
+}
+
+/// 玩家的任务日志
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuestLog {
+    pub active: BTreeMap<u32, u64>,  // 进行中的任务 ID 与接受时间
+    pub completed: BTreeSet<u32>,
+    pub flags: BTreeSet<String>,     // 已获得的任务标记
+    pub caught_at: BTreeMap<u32, u64>,  // 每个物种最近一次被捕捉的时间 (之后进化也不影响)
+    pub defeated_at: BTreeMap<u32, u64>, // 每个训练师最近一次被击败的时间
+    pub reached_at: BTreeMap<u32, u64>, // 每个地点最近一次到达的时间
+}
+
+impl QuestLog {
+    /// 某个任务的状态
+    pub fn status(&self, quest: &Quest) -> QuestStatus {
+        if self.completed.contains(&quest.id) {
+            QuestStatus::Completed
+        } else if self.active.contains_key(&quest.id) {
+            QuestStatus::Active
+        } else if quest.prerequisites.iter().all(|id| self.completed.contains(id)) {
+            QuestStatus::Available
+        } else {
+            QuestStatus::Locked
+        }
+    }
+
+    /// 是否拥有任务标记
+    pub fn has_flag(&self, flag: &str) -> bool {
+        self.flags.contains(flag)
+    }
+
+    /// 记录捕捉到某个物种的时间
+    pub fn record_catch(&mut self, species_id: u32, timestamp: u64) {
+        self.caught_at.insert(species_id, timestamp);
+    }
+
+    /// 记录击败某个训练师的时间
+    pub fn record_defeat(&mut self, trainer_id: u32, timestamp: u64) {
+        self.defeated_at.insert(trainer_id, timestamp);
+    }
+
+    /// 记录到达某个地点的时间
+    pub fn record_arrival(&mut self, location_id: u32, timestamp: u64) {
+        self.reached_at.insert(location_id, timestamp);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quest(id: u32, prerequisites: Vec<u32>, objective: QuestObjective) -> Quest {
+        Quest {
+            id,
+            name: format!("任务{}", id),
+            description: String::new(),
+            npc_id: 1,
+            prerequisites,
+            objective,
+            reward: QuestReward { money: 100, items: vec![QuestItem { item_id: 1, count: 2 }] },
+            flag: Some("test_flag".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_objective_from_json() {
+        let objective: QuestObjective =
+            serde_json::from_str(r#"{"type": "deliver_item", "item_id": 2, "count": 3}"#).unwrap();
+        assert_eq!(objective, QuestObjective::DeliverItem { item_id: 2, count: 3 });
+        let objective: QuestObjective =
+            serde_json::from_str(r#"{"type": "defeat_trainer", "trainer_id": 8}"#).unwrap();
+        assert_eq!(objective, QuestObjective::DefeatTrainer { trainer_id: 8 });
+    }
+
+    #[test]
+    fn test_quest_status_follows_prerequisites() {
+        let first = quest(1, vec![], QuestObjective::ReachLocation { location_id: 105 });
+        let second = quest(2, vec![1], QuestObjective::ReachLocation { location_id: 105 });
+        let mut log = QuestLog::default();
+        assert_eq!(log.status(&first), QuestStatus::Available);
+        assert_eq!(log.status(&second), QuestStatus::Locked);
+
+        log.active.insert(1, 0);
+        assert_eq!(log.status(&first), QuestStatus::Active);
+        log.active.remove(&1);
+        log.completed.insert(1);
+        assert_eq!(log.status(&first), QuestStatus::Completed);
+        assert_eq!(log.status(&second), QuestStatus::Available);
+    }
+
+    #[test]
+    fn test_complete_quest_grants_reward_and_flag() {
+        let quest = quest(1, vec![], QuestObjective::DeliverItem { item_id: 2, count: 2 });
+        let mut player = Player::new("测试".to_string());
+        let potions = player.item_count(2);
+        let balls = player.item_count(1);
+        assert!(player.complete_quest(&quest).is_err());
+
+        player.accept_quest(&quest).unwrap();
+        assert!(player.accept_quest(&quest).is_err());
+        player.add_item(2, 2);
+        assert!(player.complete_quest(&quest).is_ok());
+        assert_eq!(player.item_count(2), potions);
+        assert_eq!(player.item_count(1), balls + 2);
+        assert_eq!(player.money, 100);
+        assert!(player.quests.has_flag("test_flag"));
+        assert_eq!(player.quests.status(&quest), QuestStatus::Completed);
+    }
+
+    #[test]
+    fn test_catch_and_reach_only_count_after_accepting() {
+        let catch = QuestObjective::CatchSpecies { species_id: 10 };
+        let reach = QuestObjective::ReachLocation { location_id: 105 };
+        let mut player = Player::new("测试".to_string());
+        player.quests.record_catch(10, 50);
+        player.quests.record_arrival(105, 50);
+        assert_eq!(catch.progress(&player, 100), (0, 1));
+        assert_eq!(reach.progress(&player, 100), (0, 1));
+
+        // 捕捉后进化的宝可梦也算数：只看捕捉时记下的物种
+        player.quests.record_catch(10, 120);
+        player.quests.record_arrival(105, 130);
+        assert!(catch.is_met(&player, 100));
+        assert!(reach.is_met(&player, 100));
+    }
+
+    #[test]
+    fn test_defeat_trainer_objective() {
+        let objective = QuestObjective::DefeatTrainer { trainer_id: 8 };
+        let mut player = Player::new("测试".to_string());
+        assert_eq!(objective.progress(&player, 100), (0, 1));
+
+        // 接受任务之前击败的不算
+        player.quests.record_defeat(8, 50);
+        assert_eq!(objective.progress(&player, 100), (0, 1));
+        player.quests.record_defeat(8, 150);
+        assert!(objective.is_met(&player, 100));
+    }
+}
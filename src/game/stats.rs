@@ -0,0 +1,148 @@
+use super::Player;
+use serde::{Deserialize, Serialize};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// 当前的 Unix 时间戳 (秒)
+pub fn now_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// 把 Unix 时间戳格式化为 YYYY-MM-DD (UTC)
+pub fn format_date(timestamp: u64) -> String {
+    // 按公历从 1970-01-01 起推算年月日
+    let days = (timestamp / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// 把秒数格式化为 时:分
+pub fn format_playtime(seconds: u64) -> String {
+    format!("{}:{:02}", seconds / 3600, seconds / 60 % 60)
+}
+
+/// 玩家的终身统计数据
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TrainerStats {
+    pub wild_battles_won: u32,
+    pub wild_battles_lost: u32,
+    pub trainer_battles_won: u32,
+    pub trainer_battles_lost: u32,
+    pub wild_pokemon_defeated: u32, // 击倒的野生宝可梦
+    pub flawless_gym_wins: u32,     // 没有宝可梦昏迷地击败馆主的次数
+    pub pokemon_caught: u32,
+    pub steps: u32,                 // 在地点之间移动的次数
+    pub money_earned: u64,
+    pub money_spent: u64,
+    pub playtime_seconds: u64,
+    pub start_date: u64,            // 开始冒险的时间戳 (旧存档为 0)
+}
+
+impl TrainerStats {
+    /// 新冒险的统计，记录开始时间
+    pub fn new() -> Self {
+        TrainerStats {
+            start_date: now_timestamp(),
+            ..TrainerStats::default()
+        }
+    }
+
+    /// 记录一场战斗的胜负 (逃跑不计)
+    pub fn record_battle(&mut self, is_wild: bool, won: bool) {
+        let counter = match (is_wild, won) {
+            (true, true) => &mut self.wild_battles_won,
+            (true, false) => &mut self.wild_battles_lost,
+            (false, true) => &mut self.trainer_battles_won,
+            (false, false) => &mut self.trainer_battles_lost,
+        };
+        *counter += 1;
+    }
+
+    /// 全部战斗的胜率 (%)，还没有战斗过时为 0
+    pub fn win_rate(&self) -> f32 {
+        let won = self.wild_battles_won + self.trainer_battles_won;
+        let total = won + self.wild_battles_lost + self.trainer_battles_lost;
+        if total == 0 {
+            return 0.0;
+        }
+        won as f32 / total as f32 * 100.0
+    }
+}
+
+/// 训练家卡片：玩家的概况与终身统计，可以导出为 JSON
+#[derive(Debug, Clone, Serialize)]
+pub struct TrainerCard {
+    pub name: String,
+    pub money: u32,
+    pub badges: Vec<String>,
+    pub pokedex_seen: usize,
+    pub pokedex_caught: usize,
+    pub achievements_unlocked: usize,
+    pub stats: TrainerStats,
+}
+
+impl TrainerCard {
+    pub fn from_player(player: &Player) -> Self {
+        TrainerCard {
+            name: player.name.clone(),
+            money: player.money,
+            badges: player.badges.iter().map(|badge| badge.name.clone()).collect(),
+            pokedex_seen: player.pokedex.seen.len(),
+            pokedex_caught: player.pokedex.caught.len(),
+            achievements_unlocked: player.achievements.len(),
+            stats: player.stats.clone(),
+        }
+    }
+
+    /// 导出为格式化的 JSON
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| format!("导出训练家卡片失败: {}", e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_battle() {
+        let mut stats = TrainerStats::default();
+        assert_eq!(stats.win_rate(), 0.0);
+
+        stats.record_battle(true, true);
+        stats.record_battle(true, true);
+        stats.record_battle(false, true);
+        stats.record_battle(false, false);
+        assert_eq!((stats.wild_battles_won, stats.wild_battles_lost), (2, 0));
+        assert_eq!((stats.trainer_battles_won, stats.trainer_battles_lost), (1, 1));
+        assert_eq!(stats.win_rate(), 75.0);
+    }
+
+    #[test]
+    fn test_format_date_and_playtime() {
+        assert_eq!(format_date(0), "1970-01-01");
+        assert_eq!(format_date(951_782_400), "2000-02-29");
+        assert_eq!(format_date(1_699_012_445), "2023-11-03");
+        assert_eq!(format_playtime(3_725), "1:02");
+    }
+
+    #[test]
+    fn test_trainer_card_json() {
+        let mut player = Player::new("小智".to_string());
+        player.stats.pokemon_caught = 3;
+        let json = TrainerCard::from_player(&player).to_json().unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["name"], "小智");
+        assert_eq!(value["stats"]["pokemon_caught"], 3);
+    }
+}
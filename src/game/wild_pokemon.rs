@@ -1,7 +1,8 @@
 use crate::game::EnvironmentBonus;
 use crate::game::PokemonType;
+use crate::game::TimeOfDay;
 use crate::pokemon_generator::generator::{get_species, calculate_pokemon_stats, PokemonStats};
-use crate::pokemon_generator::PokemonInstance;
+use crate::pokemon_generator::{Gender, PokemonInstance};
 use rand::Rng;
 
 /// 野生宝可梦遭遇信息 (包含环境加成)
@@ -10,6 +11,9 @@ pub struct WildPokemonPreview {
     pub pokemon_id: u32,
     pub pokemon_name: String,
     pub level: u32,
+    pub gender: Gender,
+    /// 是否为异色
+    pub is_shiny: bool,
     pub pokemon_type: (PokemonType, Option<PokemonType>),
     /// 原始属性 (不含环境加成)
     pub base_stats: PokemonStats,
@@ -27,7 +31,16 @@ impl WildPokemonPreview {
         println!("\n╭─────────────────────────────╮");
         println!("│     野生宝可梦出现!        │");
         println!("├─────────────────────────────┤");
-        println!("│ 名字: {}", format!("{:<18}", self.pokemon_name));
+        let name = format!(
+            "{}{}{}",
+            if self.is_shiny { "★" } else { "" },
+            self.pokemon_name,
+            self.gender.symbol()
+        );
+        println!("│ 名字: {}", format!("{:<18}", name));
+        if self.is_shiny {
+            println!("│ ✨ 是异色宝可梦！");
+        }
         println!("│ 等级: {}", format!("{:<18}", self.level));
 
         let type_str = match self.pokemon_type {
@@ -84,7 +97,7 @@ impl WildPokemonEncounter {
         }
 
         // 使用加权随机选择
-        let selected = Self::weighted_random_selection(wild_pokemon_pool)?;
+        let selected = Self::weighted_random_selection(wild_pokemon_pool, &mut rand::thread_rng())?;
 
         // 随机化等级
         let level = Self::random_level(selected.level_min, selected.level_max);
@@ -93,9 +106,34 @@ impl WildPokemonEncounter {
         crate::pokemon_generator::generator::generate_pokemon(selected.pokemon_id, level)
     }
 
-    /// 加权随机选择 (根据 spawn_rate)
+    /// 在指定时段从地点的宝可梦池中随机生成一只野生宝可梦
+    pub fn generate_wild_pokemon_at(
+        wild_pokemon_pool: &[crate::game::WildPokemonSpawn],
+        time: TimeOfDay,
+        rng: &mut impl Rng,
+    ) -> Result<PokemonInstance, String> {
+        let selected = Self::select_spawn(wild_pokemon_pool, time, rng)?;
+        let level = rng.gen_range(selected.level_min..=selected.level_max);
+        crate::pokemon_generator::generator::generate_pokemon(selected.pokemon_id, level)
+    }
+
+    /// 按时段筛选宝可梦池后加权随机选择，传入固定种子的 rng 时结果可复现
+    pub fn select_spawn(
+        pool: &[crate::game::WildPokemonSpawn],
+        time: TimeOfDay,
+        rng: &mut impl Rng,
+    ) -> Result<crate::game::WildPokemonSpawn, String> {
+        let available: Vec<_> = pool.iter().filter(|spawn| spawn.appears_at(time)).cloned().collect();
+        if available.is_empty() {
+            return Err(format!("{}没有野生宝可梦出现", time.name()));
+        }
+        Self::weighted_random_selection(&available, rng)
+    }
+
+    /// 加权随机选择 (根据 spawn_rate)，使用给定的随机数生成器
     fn weighted_random_selection(
         pool: &[crate::game::WildPokemonSpawn],
+        rng: &mut impl Rng,
     ) -> Result<crate::game::WildPokemonSpawn, String> {
         // 计算总权重
         let total_weight: f64 = pool.iter().map(|p| p.spawn_rate as f64).sum();
@@ -105,7 +143,6 @@ impl WildPokemonEncounter {
         }
 
         // 生成 0 到 total_weight 之间的随机数
-        let mut rng = rand::thread_rng();
         let mut random_value: f64 = rng.gen::<f64>() * total_weight;
 
         // 根据权重选择
@@ -149,6 +186,8 @@ impl WildPokemonEncounter {
             pokemon_id: wild_pokemon.species_id,
             pokemon_name: species.name,
             level: wild_pokemon.level,
+            gender: wild_pokemon.gender,
+            is_shiny: wild_pokemon.is_shiny,
             pokemon_type: (PokemonType::Normal, None), // TODO: 从物种获取类型
             base_stats,
             boosted_stats,
@@ -193,7 +232,7 @@ impl WildPokemonEncounter {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::game::WildPokemonSpawn;
+    use crate::game::{EncounterMethod, WildPokemonSpawn};
 
     #[test]
     fn test_weighted_selection_distribution() {
@@ -203,18 +242,24 @@ mod tests {
                 spawn_rate: 50.0,
                 level_min: 1,
                 level_max: 5,
+                times: Vec::new(),
+                method: EncounterMethod::Walk,
             },
             WildPokemonSpawn {
                 pokemon_id: 2,
                 spawn_rate: 30.0,
                 level_min: 1,
                 level_max: 5,
+                times: Vec::new(),
+                method: EncounterMethod::Walk,
             },
             WildPokemonSpawn {
                 pokemon_id: 3,
                 spawn_rate: 20.0,
                 level_min: 1,
                 level_max: 5,
+                times: Vec::new(),
+                method: EncounterMethod::Walk,
             },
         ];
 
@@ -222,7 +267,7 @@ mod tests {
         const TRIALS: u32 = 10000;
 
         for _ in 0..TRIALS {
-            let selected = WildPokemonEncounter::weighted_random_selection(&pool).unwrap();
+            let selected = WildPokemonEncounter::weighted_random_selection(&pool, &mut rand::thread_rng()).unwrap();
             if selected.pokemon_id == 1 {
                 counts[0] += 1;
             } else if selected.pokemon_id == 2 {
@@ -261,25 +306,27 @@ mod tests {
 
     #[test]
     fn test_level_range() {
-        let pool = vec![WildPokemonSpawn {
+        let spawn = WildPokemonSpawn {
             pokemon_id: 1,
             spawn_rate: 100.0,
             level_min: 5,
             level_max: 10,
-        }];
+            times: Vec::new(),
+            method: EncounterMethod::Walk,
+        };
 
         const TRIALS: u32 = 1000;
         let mut min_level = u32::MAX;
         let mut max_level = u32::MIN;
 
         for _ in 0..TRIALS {
-            let level = WildPokemonEncounter::random_level(5, 10);
+            let level = WildPokemonEncounter::random_level(spawn.level_min, spawn.level_max);
             min_level = min_level.min(level);
             max_level = max_level.max(level);
         }
 
         assert!(
-            min_level >= 5 && max_level <= 10,
+            min_level >= spawn.level_min && max_level <= spawn.level_max,
             "Level out of range: {} to {}",
             min_level,
             max_level
@@ -289,7 +336,40 @@ mod tests {
     #[test]
     fn test_empty_pool_error() {
         let pool: Vec<WildPokemonSpawn> = vec![];
-        let result = WildPokemonEncounter::weighted_random_selection(&pool);
+        let result = WildPokemonEncounter::weighted_random_selection(&pool, &mut rand::thread_rng());
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_time_filtered_selection_is_seeded() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let spawn = |pokemon_id, times| WildPokemonSpawn {
+            pokemon_id,
+            spawn_rate: 50.0,
+            level_min: 1,
+            level_max: 5,
+            times,
+            method: EncounterMethod::Walk,
+        };
+        let pool = vec![
+            spawn(10, vec![TimeOfDay::Morning, TimeOfDay::Day]),
+            spawn(63, vec![TimeOfDay::Night]),
+            spawn(25, vec![]),
+        ];
+
+        let roll = |time, seed| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            (0..20)
+                .map(|_| WildPokemonEncounter::select_spawn(&pool, time, &mut rng).unwrap().pokemon_id)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(roll(TimeOfDay::Night, 7), roll(TimeOfDay::Night, 7));
+        assert!(roll(TimeOfDay::Night, 7).iter().all(|&id| id != 10));
+        assert!(roll(TimeOfDay::Day, 7).iter().all(|&id| id != 63));
+
+        let day_only = vec![spawn(10, vec![TimeOfDay::Day])];
+        assert!(WildPokemonEncounter::select_spawn(&day_only, TimeOfDay::Night, &mut StdRng::seed_from_u64(1)).is_err());
+    }
 }
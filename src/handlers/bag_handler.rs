@@ -0,0 +1,117 @@
+//! BagHandler - The bag outside battle
+//!
+//! Responsible for:
+//! - Browsing the bag pocket by pocket, each with its own sort order
+//! - Using medicine, vitamins, berries, evolution stones and machines on a team member
+//! - Giving held items to a team member
+//! - Starting repel and lure effects
+//! - Tossing items that are not key items
+
+use crate::cli::{BagMenu, Menu, MoveLearnMenu};
+use crate::game::{BagSort, Item, ItemType, Player, Pocket};
+
+/// Handles the bag opened from the main menu
+pub struct BagHandler;
+
+impl BagHandler {
+    /// Show the bag and handle pocket switching, sorting, using and tossing until the player leaves
+    pub fn open_bag(player: &mut Player) {
+        let mut pocket_index = 0;
+        let mut sorts = [BagSort::default(); Pocket::ALL.len()];
+
+        loop {
+            let pocket = Pocket::ALL[pocket_index];
+            let items = player.items.pocket(pocket, sorts[pocket_index]);
+            BagMenu::display_pocket(pocket, sorts[pocket_index], &items, player.money);
+
+            match Menu::get_input().as_str() {
+                "0" => return,
+                "a" | "A" => pocket_index = (pocket_index + Pocket::ALL.len() - 1) % Pocket::ALL.len(),
+                "d" | "D" => pocket_index = (pocket_index + 1) % Pocket::ALL.len(),
+                "s" | "S" => sorts[pocket_index] = sorts[pocket_index].next(),
+                choice => match choice.parse::<usize>() {
+                    Ok(idx) if idx > 0 && idx <= items.len() => {
+                        let (item, count) = &items[idx - 1];
+                        Self::handle_item(player, item, *count);
+                    }
+                    _ => println!("无效的选择，请重试"),
+                },
+            }
+        }
+    }
+
+    /// Use or toss the selected item
+    fn handle_item(player: &mut Player, item: &Item, count: u32) {
+        BagMenu::display_item_actions(item, count);
+        let result = match Menu::get_input().as_str() {
+            "1" => Self::use_item(player, item),
+            "2" if item.can_toss() => {
+                BagMenu::prompt_toss_quantity(&item.name, count);
+                match Menu::get_input().parse::<u32>() {
+                    Ok(quantity) if quantity > 0 => player.toss_item(item.id, quantity).map(Some),
+                    _ => Ok(None),
+                }
+            }
+            _ => Ok(None),
+        };
+        match result {
+            Ok(Some(message)) => println!("{}", message),
+            Ok(None) => {}
+            Err(e) => println!("✗ {}", e),
+        }
+    }
+
+    /// Use an item; repels and lures take effect right away, the rest are used on
+    /// a team member. Balls and key items cannot be used here
+    fn use_item(player: &mut Player, item: &Item) -> Result<Option<String>, String> {
+        if matches!(item.item_type, ItemType::Repel | ItemType::Lure) {
+            return player.use_encounter_item(item.id).map(Some);
+        }
+
+        let usable = item.is_recovery_item()
+            || matches!(
+                item.item_type,
+                ItemType::Vitamin
+                    | ItemType::EVBerry
+                    | ItemType::EvolutionStone
+                    | ItemType::TM
+                    | ItemType::HM
+                    | ItemType::HeldItem
+                    | ItemType::BreedingItem
+            );
+        if !usable || player.pokemons.is_empty() {
+            BagMenu::display_cannot_use(&item.name);
+            return Ok(None);
+        }
+
+        player.display_team();
+        println!("0. 返回");
+        let pokemon_index = match Menu::get_input().parse::<usize>() {
+            Ok(idx) if idx > 0 && idx <= player.pokemons.len() => idx - 1,
+            _ => return Ok(None),
+        };
+
+        match item.item_type {
+            ItemType::TM | ItemType::HM => {
+                Self::use_machine(player, pokemon_index, item.id);
+                Ok(None)
+            }
+            ItemType::EvolutionStone => player.use_evolution_item(pokemon_index, item.id).map(Some),
+            ItemType::Vitamin | ItemType::EVBerry => player.use_ev_item(pokemon_index, item.id).map(Some),
+            ItemType::HeldItem | ItemType::BreedingItem => player.give_held_item(pokemon_index, item.id).map(Some),
+            _ => player.revive_pokemon_with_item(pokemon_index, item.id).map(Some),
+        }
+    }
+
+    /// Teach the move of a TM / HM through the move replacement flow
+    fn use_machine(player: &mut Player, pokemon_index: usize, item_id: u32) {
+        match player.check_machine_move(pokemon_index, item_id) {
+            Ok(move_data) => {
+                if MoveLearnMenu::learn_move(&mut player.pokemons[pokemon_index], move_data) {
+                    player.consume_machine(item_id);
+                }
+            }
+            Err(e) => println!("✗ {}", e),
+        }
+    }
+}
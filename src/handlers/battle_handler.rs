@@ -5,9 +5,11 @@
 //! - Turn coordination and move execution
 //! - Opponent AI decision making
 //! - Experience and reward calculation
+//! - Battle statistics for the trainer card
 
-use crate::game::{Player, Pokemon, Battle, BattleStatus};
-use crate::cli::BattleMenu;
+use crate::game::{Player, Pokemon, Battle, BattleStatus, EvolutionTrigger};
+use crate::game::evolution;
+use crate::cli::{BattleMenu, EvolutionMenu, MoveLearnMenu};
 use rand::Rng;
 
 /// Represents a player action during battle
@@ -48,6 +50,9 @@ impl BattleHandler {
         }
 
         let mut battle = Battle::new_team_battle(player.pokemons.clone(), opponent_team, true);
+        battle.player_bonus = player.badge_stat_bonus();
+        battle.trainer_name = player.name.clone();
+        battle.obedience_level_cap = player.obedience_level_cap();
 
         // Display battle start
         if let Some(opponent) = battle.get_opponent_pokemon() {
@@ -55,20 +60,28 @@ impl BattleHandler {
         }
 
         // Main battle loop
-        Self::battle_loop(&mut battle)?;
+        let exp_gained = Self::battle_loop(&mut battle)?;
 
         // Calculate results
-        let result = Self::calculate_battle_result(&battle);
+        let result = Self::calculate_battle_result(&battle, exp_gained);
+        Self::record_stats(player, &battle);
 
         // Update player team
+        let levels_before: Vec<u32> = player.pokemons.iter().map(|p| p.level).collect();
         player.pokemons = battle.player_team.clone();
 
+        if result.won {
+            Self::handle_post_battle_moves(player, &levels_before);
+            Self::handle_post_battle_evolutions(player, &levels_before);
+        }
+
         Ok(result)
     }
 
-    /// Execute an NPC trainer battle
+    /// Execute an NPC trainer battle; a victory records the trainer as defeated
     pub fn execute_npc_battle(
         player: &mut Player,
+        trainer_id: u32,
         opponent_team: Vec<Pokemon>,
     ) -> Result<BattleResult, String> {
         if !player.has_active_pokemon() {
@@ -76,6 +89,9 @@ impl BattleHandler {
         }
 
         let mut battle = Battle::new_team_battle(player.pokemons.clone(), opponent_team, false);
+        battle.player_bonus = player.badge_stat_bonus();
+        battle.trainer_name = player.name.clone();
+        battle.obedience_level_cap = player.obedience_level_cap();
 
         // Display battle start
         if let Some(opponent) = battle.get_opponent_pokemon() {
@@ -83,24 +99,49 @@ impl BattleHandler {
         }
 
         // Main battle loop
-        Self::battle_loop(&mut battle)?;
+        let exp_gained = Self::battle_loop(&mut battle)?;
 
         // Calculate results
-        let result = Self::calculate_battle_result(&battle);
+        let result = Self::calculate_battle_result(&battle, exp_gained);
+        Self::record_stats(player, &battle);
+        Self::record_seen_opponents(player, &battle);
 
         // Update player team
+        let levels_before: Vec<u32> = player.pokemons.iter().map(|p| p.level).collect();
         player.pokemons = battle.player_team.clone();
 
+        if result.won {
+            player.defeated_trainers.insert(trainer_id);
+            player.quests.record_defeat(trainer_id, crate::game::stats::now_timestamp());
+            Self::handle_post_battle_moves(player, &levels_before);
+            Self::handle_post_battle_evolutions(player, &levels_before);
+        }
+
         Ok(result)
     }
 
-    /// Main battle loop
-    fn battle_loop(battle: &mut Battle) -> Result<(), String> {
+    /// Mark every opponent the trainer sent out as seen in the Pokédex
+    fn record_seen_opponents(player: &mut Player, battle: &Battle) {
+        for pokemon in battle.opponent_team.iter().take(battle.opponent_current_index + 1) {
+            player.pokedex.mark_seen(pokemon.id);
+        }
+    }
+
+    /// Main battle loop, returns the experience gained by each participant
+    fn battle_loop(battle: &mut Battle) -> Result<u32, String> {
         loop {
             // Check if battle has ended
             if battle.check_battle_end() {
+                let mut exp_gained = 0;
+                if battle.status == BattleStatus::PlayerWon {
+                    let log_start = battle.battle_log.len();
+                    exp_gained = battle.award_experience_for_defeated();
+                    for message in &battle.battle_log[log_start..] {
+                        println!("{}", message);
+                    }
+                }
                 BattleMenu::display_battle_result(battle, battle.calculate_reward_money());
-                break;
+                return Ok(exp_gained);
             }
 
             // Display current status
@@ -183,7 +224,64 @@ impl BattleHandler {
             }
         }
 
-        Ok(())
+        Ok(0)
+    }
+
+    /// Offer the learnset moves of every level gained in battle
+    fn handle_post_battle_moves(player: &mut Player, levels_before: &[u32]) {
+        for (index, pokemon) in player.pokemons.iter_mut().enumerate() {
+            if let Some(&before) = levels_before.get(index) {
+                Self::learn_level_up_moves(pokemon, before + 1, pokemon.level);
+            }
+        }
+    }
+
+    /// Teach the moves learned between two levels (inclusive), asking which move
+    /// to forget when four are already known
+    fn learn_level_up_moves(pokemon: &mut Pokemon, from_level: u32, to_level: u32) {
+        for level in from_level..=to_level {
+            for new_move in crate::data::pokemon_data::get_moves_learned_at(pokemon.id, level) {
+                if !pokemon.knows_move(new_move.id) {
+                    MoveLearnMenu::learn_move(pokemon, new_move);
+                }
+            }
+        }
+    }
+
+    /// Offer level-up and friendship evolutions to Pokemon that leveled up in battle
+    fn handle_post_battle_evolutions(player: &mut Player, levels_before: &[u32]) {
+        let time = player.clock.time_of_day();
+        for (index, pokemon) in player.pokemons.iter_mut().enumerate() {
+            let leveled_up = levels_before
+                .get(index)
+                .map(|&before| pokemon.level > before)
+                .unwrap_or(false);
+            if !leveled_up {
+                continue;
+            }
+
+            let Some(target_id) = evolution::check_evolution(pokemon, EvolutionTrigger::LevelUp, time) else {
+                continue;
+            };
+            let target_name = crate::data::pokemon_data::get_pokemon_by_id(target_id)
+                .map(|p| p.name)
+                .unwrap_or_default();
+
+            if !EvolutionMenu::confirm_evolution(&pokemon.name, &target_name) {
+                EvolutionMenu::display_evolution_cancelled(&pokemon.name);
+                continue;
+            }
+
+            match evolution::evolve(pokemon, target_id) {
+                Ok(msg) => {
+                    player.pokedex.mark_caught(target_id);
+                    EvolutionMenu::display_evolution_result(&msg);
+                    // 进化后的物种在当前等级可能还有新招式
+                    Self::learn_level_up_moves(pokemon, pokemon.level, pokemon.level);
+                }
+                Err(e) => println!("进化失败: {}", e),
+            }
+        }
     }
 
     /// Handle opponent's action (simple AI)
@@ -198,10 +296,23 @@ impl BattleHandler {
         Ok(())
     }
 
+    /// Record a finished battle in the player's lifetime stats; escapes are not counted
+    fn record_stats(player: &mut Player, battle: &Battle) {
+        let won = match battle.status {
+            BattleStatus::PlayerWon => true,
+            BattleStatus::PlayerLost => false,
+            BattleStatus::Active | BattleStatus::Escaped => return,
+        };
+        player.stats.record_battle(battle.is_wild_battle, won);
+        if battle.is_wild_battle {
+            player.stats.wild_pokemon_defeated +=
+                battle.opponent_team.iter().filter(|p| p.is_fainted()).count() as u32;
+        }
+    }
+
     /// Calculate battle result and rewards
-    fn calculate_battle_result(battle: &Battle) -> BattleResult {
+    fn calculate_battle_result(battle: &Battle, exp_gained: u32) -> BattleResult {
         let won = battle.status == BattleStatus::PlayerWon;
-        let exp_gained = if won { 50 } else { 0 };
         let money_gained = if won {
             battle.calculate_reward_money()
         } else {
@@ -0,0 +1,108 @@
+//! DayCareHandler - Day-care breeding
+//!
+//! Responsible for:
+//! - Depositing and withdrawing Pokemon (optionally handing over a breeding item to hold first)
+//! - Collecting eggs produced at the day care
+//! - Egg production and hatching as the player walks between locations
+
+use crate::cli::{DayCareMenu, Menu};
+use crate::game::item::POKE_BALL_ID;
+use crate::game::{Item, Player};
+
+/// Handles the day-care service and egg hatching
+pub struct DayCareHandler;
+
+impl DayCareHandler {
+    /// Show the day care and dispatch the chosen action until the player leaves
+    pub fn handle_day_care(player: &mut Player) {
+        loop {
+            DayCareMenu::display_day_care_menu(&player.day_care, &player.eggs);
+            let result = match Menu::get_input().as_str() {
+                "1" => Self::handle_deposit(player),
+                "2" => Self::handle_withdraw(player),
+                "3" => player.collect_egg().map(Some),
+                "0" => return,
+                _ => {
+                    println!("无效的选择，请重试");
+                    continue;
+                }
+            };
+            match result {
+                Ok(Some(message)) => println!("{}", message),
+                Ok(None) => {}
+                Err(e) => println!("✗ {}", e),
+            }
+        }
+    }
+
+    /// Deposit a team member, optionally giving it a breeding item from the bag to hold
+    fn handle_deposit(player: &mut Player) -> Result<Option<String>, String> {
+        if player.day_care.is_full() {
+            return Err("饲育屋已经寄放满了".to_string());
+        }
+        player.display_team();
+        println!("0. 返回");
+        let team_index = match Menu::get_input().parse::<usize>() {
+            Ok(idx) if idx > 0 && idx <= player.pokemons.len() => idx - 1,
+            _ => return Ok(None),
+        };
+
+        let items = player.get_breeding_items();
+        if !items.is_empty() {
+            DayCareMenu::display_held_item_menu(&player.pokemons[team_index], &items);
+            if let Ok(idx) = Menu::get_input().parse::<usize>() {
+                if idx > 0 && idx <= items.len() {
+                    println!("{}", player.give_held_item(team_index, items[idx - 1].0.id)?);
+                }
+            }
+        }
+
+        player.deposit_to_day_care(team_index).map(Some)
+    }
+
+    /// Withdraw a Pokemon from the day care back to the team
+    fn handle_withdraw(player: &mut Player) -> Result<Option<String>, String> {
+        if player.day_care.slots.is_empty() {
+            return Err("饲育屋里没有寄放的宝可梦".to_string());
+        }
+        DayCareMenu::display_withdraw_menu(&player.day_care);
+        match Menu::get_input().parse::<usize>() {
+            Ok(idx) if idx > 0 && idx <= player.day_care.slots.len() => {
+                player.withdraw_from_day_care(idx - 1).map(Some)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Advance the day care and carried eggs by one step; hatched Pokemon join the team or storage
+    pub fn on_player_step(player: &mut Player, location_id: u32) {
+        if player.day_care.step() {
+            DayCareMenu::display_egg_found();
+        }
+
+        for egg in player.advance_eggs() {
+            let mut pokemon = match egg.hatch() {
+                Some(pokemon) => pokemon,
+                None => continue,
+            };
+            DayCareMenu::display_egg_hatched(&pokemon);
+            Menu::name_new_pokemon(&mut pokemon);
+
+            let timestamp = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0);
+            let pokemon = pokemon.set_catch_info(Item::name_of(POKE_BALL_ID), location_id, timestamp);
+            player.pokedex.mark_caught(pokemon.id);
+
+            if player.add_pokemon(pokemon.clone()) {
+                println!("{} 加入了你的队伍！", pokemon.display_name());
+            } else {
+                match player.storage.add_pokemon(pokemon.clone()) {
+                    Ok((box_id, _)) => println!("队伍已满，{} 被送到了箱子 {}。", pokemon.display_name(), box_id),
+                    Err(e) => println!("✗ {}", e),
+                }
+            }
+        }
+    }
+}
@@ -1,22 +1,25 @@
 //! EncounterManager - Wild Pokemon encounter handling
 //!
 //! Responsible for:
-//! - Generating wild Pokemon encounters
+//! - Generating wild Pokemon encounters from the spawn table of each encounter method
 //! - Encounter preview display
 //! - User action handling (fight/capture/flee)
 //! - Capture rate calculation
+//! - Encounter rate modifiers (lures, held items) and repels
 
-use crate::game::{Player, Pokemon, WildPokemonEncounter, Location};
-use crate::cli::LocationMenu;
+use crate::game::{EncounterMethod, EncounterRateModifier, Item, Player, Pokemon, WildPokemonEncounter, Location};
+use crate::game::item::POKE_BALL_ID;
+use crate::cli::{LocationMenu, Menu};
 use crate::handlers::BattleHandler;
 use crate::pokemon_generator::PokemonInstance;
 use rand::Rng;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 /// Result of a wild Pokemon encounter
 #[derive(Debug, Clone)]
 pub enum EncounterResult {
     /// Pokemon was successfully captured
-    Captured(Pokemon),
+    Captured(Box<Pokemon>),
     /// Battle was initiated
     BattleInitiated,
     /// Player successfully escaped
@@ -29,25 +32,54 @@ pub enum EncounterResult {
 pub struct EncounterManager;
 
 impl EncounterManager {
-    /// Handle an encounter in a location
-    pub fn handle_encounter(player: &mut Player, location: &Location) -> Result<EncounterResult, String> {
-        // Check encounter chance
+    /// Handle an encounter in a location using the given encounter method
+    pub fn handle_encounter(
+        player: &mut Player,
+        location: &Location,
+        method: EncounterMethod,
+    ) -> Result<EncounterResult, String> {
+        // Check encounter chance, raised or lowered by lures and the lead's held item
         let mut rng = rand::thread_rng();
         let encounter_chance: f32 = rng.gen();
+        let rate = EncounterRateModifier::apply(
+            method.encounter_chance(location.encounter_rate),
+            &EncounterRateModifier::for_player(player),
+        );
 
-        if encounter_chance > location.encounter_rate {
-            println!("\n你探索了 {}，但没有遇到任何宝可梦。", location.name);
+        if encounter_chance > rate {
+            match method {
+                EncounterMethod::Walk => println!("\n你探索了 {}，但没有遇到任何宝可梦。", location.name),
+                EncounterMethod::Surf => println!("\n你在 {} 的水面上前进，但没有遇到任何宝可梦。", location.name),
+                EncounterMethod::RockSmash => println!("\n岩石被击碎了，但里面什么都没有。"),
+                _ => println!("\n你用{}钓了一会儿鱼……没有东西上钩。", method.name()),
+            }
             return Ok(EncounterResult::Failed);
         }
 
-        // Generate wild Pokemon
-        if location.wild_pokemon_pool.is_empty() {
+        // Generate wild Pokemon from the method's spawns that appear at the current time of day
+        let table = location.encounter_table(method);
+        if table.is_empty() {
             println!("\n这个地点没有野生宝可梦。");
             return Ok(EncounterResult::Failed);
         }
+        let time = player.clock.time_of_day();
+        if !table.iter().any(|spawn| spawn.appears_at(time)) {
+            println!("\n现在是{}，{} 没有野生宝可梦出没。", time.name(), location.name);
+            return Ok(EncounterResult::Failed);
+        }
+        if method.is_fishing() {
+            println!("\n有东西上钩了！");
+        }
 
-        match WildPokemonEncounter::generate_wild_pokemon(&location.wild_pokemon_pool) {
+        match WildPokemonEncounter::generate_wild_pokemon_at(&table, time, &mut rng) {
             Ok(wild_pokemon_instance) => {
+                // Repels keep away wild Pokemon weaker than the lead
+                let lead_level = player.lead_pokemon().map_or(0, |p| p.level);
+                if player.encounter_effects.repel.is_some() && wild_pokemon_instance.level < lead_level {
+                    println!("\n驱虫喷雾的效果让弱小的野生宝可梦不敢靠近。");
+                    return Ok(EncounterResult::Failed);
+                }
+
                 let environment_bonus = crate::game::EnvironmentBonus::from_environment(location.environment);
 
                 match WildPokemonEncounter::generate_preview(
@@ -56,8 +88,9 @@ impl EncounterManager {
                     location.environment_name(),
                 ) {
                     Ok(preview) => {
+                        player.pokedex.mark_seen(wild_pokemon_instance.species_id);
                         preview.display();
-                        Self::handle_encounter_choice(player, wild_pokemon_instance)
+                        Self::handle_encounter_choice(player, wild_pokemon_instance, location.id)
                     }
                     Err(e) => {
                         println!("生成预览失败: {}", e);
@@ -73,28 +106,43 @@ impl EncounterManager {
     }
 
     /// Handle player's choice during encounter
-    fn handle_encounter_choice(player: &mut Player, wild_pokemon_instance: PokemonInstance) -> Result<EncounterResult, String> {
+    fn handle_encounter_choice(
+        player: &mut Player,
+        wild_pokemon_instance: PokemonInstance,
+        location_id: u32,
+    ) -> Result<EncounterResult, String> {
         loop {
             let choice = LocationMenu::get_input();
 
             match choice.as_str() {
                 "1" => {
                     // Attempt capture
-                    println!("捕捉功能即将实现");
-                    return Ok(EncounterResult::Failed);
+                    let wild_pokemon = match crate::data::pokemon_data::get_pokemon_from_instance(&wild_pokemon_instance) {
+                        Some(pokemon) => pokemon,
+                        None => {
+                            println!("无法创建野生宝可梦");
+                            return Ok(EncounterResult::Failed);
+                        }
+                    };
+                    if !player.use_item(POKE_BALL_ID, 1) {
+                        println!("你没有精灵球了！");
+                        continue;
+                    }
+                    println!("你扔出了精灵球！");
+                    if Self::attempt_capture(&wild_pokemon) {
+                        let pokemon = Self::finish_capture(player, wild_pokemon, location_id);
+                        return Ok(EncounterResult::Captured(Box::new(pokemon)));
+                    }
+                    println!("糟糕！{} 从精灵球里挣脱出来了！", wild_pokemon.name);
                 }
                 "2" => {
-                    // Initiate battle - convert PokemonInstance to Pokemon first
-                    if let Some(mut wild_pokemon) = crate::data::pokemon_data::get_pokemon_by_id(wild_pokemon_instance.species_id) {
-                        // Update level to match generated instance
-                        wild_pokemon.level = wild_pokemon_instance.level;
-                        wild_pokemon.experience = wild_pokemon_instance.experience;
-
+                    // Initiate battle - the generated IVs, nature and talent carry over
+                    if let Some(wild_pokemon) = crate::data::pokemon_data::get_pokemon_from_instance(&wild_pokemon_instance) {
                         let opponent_team = vec![wild_pokemon];
                         match BattleHandler::execute_wild_battle(player, opponent_team) {
                             Ok(result) => {
                                 if result.won {
-                                    println!("\n你赢了！");
+                                    println!("\n你赢了！共获得 {} 经验值", result.exp_gained);
                                 } else {
                                     println!("\n你输了！");
                                 }
@@ -125,6 +173,31 @@ impl EncounterManager {
         }
     }
 
+    /// Name a freshly caught Pokemon, record where it was caught, then send it to the team or storage
+    fn finish_capture(player: &mut Player, mut pokemon: Pokemon, location_id: u32) -> Pokemon {
+        println!("\n好耶！捕捉到了 {}！", pokemon.display_label());
+        Menu::name_new_pokemon(&mut pokemon);
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        let pokemon = pokemon.set_catch_info(Item::name_of(POKE_BALL_ID), location_id, timestamp);
+        player.pokedex.mark_caught(pokemon.id);
+        player.quests.record_catch(pokemon.id, timestamp);
+        player.stats.pokemon_caught += 1;
+
+        if player.add_pokemon(pokemon.clone()) {
+            println!("{} 加入了你的队伍！", pokemon.display_name());
+        } else {
+            match player.storage.add_pokemon(pokemon.clone()) {
+                Ok((box_id, _)) => println!("队伍已满，{} 被送到了箱子 {}。", pokemon.display_name(), box_id),
+                Err(e) => println!("✗ {}，{} 被放生了。", e, pokemon.display_name()),
+            }
+        }
+        pokemon
+    }
+
     /// Attempt to escape from an encounter
     fn attempt_escape() -> bool {
         let mut rng = rand::thread_rng();
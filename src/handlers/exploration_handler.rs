@@ -4,11 +4,18 @@
 //! - Location movement logic
 //! - Movement validation
 //! - Location unlock checking
+//! - Connections gated by key items and field moves
 //! - Exploration menu handling
 //! - Location encounter triggering
+//! - Choosing the encounter method (walking, surfing, fishing, rock smash)
+//! - Field abilities (HM moves) used outside battle
+//! - Day-care egg production and hatching while walking
+//! - Step counting for the trainer card
+//! - Counting down repel and lure steps
 
-use crate::game::{Player, Location};
-use crate::cli::{LocationMenu, MapMenu};
+use crate::game::{EncounterMethod, EnvironmentType, FieldAbility, Player, Location};
+use crate::cli::{LocationMenu, MapMenu, ServiceMenu};
+use crate::handlers::DayCareHandler;
 use crate::map::GameMap;
 
 /// Result of an exploration action
@@ -35,10 +42,12 @@ impl ExplorationHandler {
         if let Some(location) = current_location {
             // Collect reachable locations
             let mut reachable = Vec::new();
+            let mut targets = Vec::new();
             for connected_id in &location.connected_locations {
                 if let Some(connected) = crate::data::locations_data::get_location_by_id(*connected_id) {
                     let is_unlocked = player.can_unlock_location(&connected.unlock_requirement);
                     reachable.push((*connected_id, connected.name.clone(), is_unlocked));
+                    targets.push(connected);
                 }
             }
 
@@ -63,10 +72,34 @@ impl ExplorationHandler {
                         return Ok(());
                     }
 
+                    // Key items and field moves declared on this edge
+                    if let Some(requirement) = location.connection_requirement(*target_id) {
+                        match player.check_connection(requirement) {
+                            Ok(Some(message)) => println!("\n{}", message),
+                            Ok(None) => {}
+                            Err(reason) => {
+                                LocationMenu::show_movement_error(&reason);
+                                return Ok(());
+                            }
+                        }
+                    }
+
                     // Move to target location
+                    let first_visit = !player.location_state.is_visited(*target_id);
                     player.location_state.current_location_id = *target_id;
                     player.location_state.mark_visited(*target_id);
+                    player.quests.record_arrival(*target_id, crate::game::stats::now_timestamp());
+                    player.location_state.record_pokemon_center(&targets[idx - 1]);
+                    player.walk_with_team();
+                    player.stats.steps += 1;
+                    player.clock.advance();
+                    Self::tick_encounter_effects(player);
+                    DayCareHandler::on_player_step(player, *target_id);
                     LocationMenu::show_movement_success(target_name);
+
+                    if first_visit {
+                        Self::receive_gift_items(player, &targets[idx - 1]);
+                    }
                 }
             }
         }
@@ -74,6 +107,16 @@ impl ExplorationHandler {
         Ok(())
     }
 
+    /// Hand out the items found on the first visit to a location
+    fn receive_gift_items(player: &mut Player, location: &Location) {
+        for item_id in &location.gift_items {
+            if let Some(item) = crate::game::Item::get(*item_id) {
+                player.add_item(item.id, 1);
+                LocationMenu::show_item_received(&location.name, &item.name);
+            }
+        }
+    }
+
     /// Handle exploration at current location
     pub fn handle_exploration(player: &mut Player, location: &Location) -> Result<ExplorationResult, String> {
         if !player.has_active_pokemon() {
@@ -81,10 +124,18 @@ impl ExplorationHandler {
             return Ok(ExplorationResult::Cancelled);
         }
 
-        // Try to generate encounter
-        match crate::handlers::EncounterManager::handle_encounter(player, location)? {
+        let Some(method) = Self::choose_encounter_method(player, location) else {
+            return Ok(ExplorationResult::Cancelled);
+        };
+        player.clock.advance();
+
+        // Try to generate encounter. Effects count down after the roll, so a repel
+        // with one step left still protects this step
+        let encounter = crate::handlers::EncounterManager::handle_encounter(player, location, method);
+        Self::tick_encounter_effects(player);
+        match encounter? {
             crate::handlers::EncounterResult::Captured(_pokemon) => {
-                // Pokemon captured - would be added to team/storage
+                // Pokemon captured - already added to team/storage
                 Ok(ExplorationResult::EncounterTriggered)
             }
             crate::handlers::EncounterResult::BattleInitiated => {
@@ -100,6 +151,96 @@ impl ExplorationHandler {
         }
     }
 
+    /// Count down the steps of active repels and lures and report the ones that wore off
+    fn tick_encounter_effects(player: &mut Player) {
+        for item_id in player.encounter_effects.step() {
+            LocationMenu::show_effect_expired(&crate::game::Item::name_of(item_id));
+        }
+    }
+
+    /// Pick how to look for wild Pokemon. Only the methods allowed by the location's
+    /// environment and the player's field moves and rods are offered; the menu is
+    /// skipped when there is a single option.
+    fn choose_encounter_method(player: &Player, location: &Location) -> Option<EncounterMethod> {
+        let methods = player.available_encounter_methods(location);
+        match methods.len() {
+            0 => Some(EncounterMethod::Walk),
+            1 => Some(methods[0]),
+            _ => {
+                LocationMenu::display_encounter_methods(&methods);
+                match LocationMenu::get_input().parse::<usize>() {
+                    Ok(idx) if idx > 0 && idx <= methods.len() => Some(methods[idx - 1]),
+                    _ => None,
+                }
+            }
+        }
+    }
+
+    /// Use a field ability (HM move) known by a team member outside battle
+    pub fn handle_field_abilities(player: &mut Player) {
+        let abilities = player.get_field_abilities();
+        if abilities.is_empty() {
+            ServiceMenu::display_no_field_abilities();
+            return;
+        }
+
+        ServiceMenu::display_field_ability_menu(&abilities);
+        let choice = crate::cli::Menu::get_input();
+        let (ability, pokemon_name) = match choice.parse::<usize>() {
+            Ok(idx) if idx > 0 && idx <= abilities.len() => abilities[idx - 1].clone(),
+            _ => return,
+        };
+
+        println!("\n{} 使用了 {}！", pokemon_name, ability.name());
+        let environment = crate::data::locations_data::get_location_by_id(player.location_state.current_location_id)
+            .map(|location| location.environment);
+
+        match ability {
+            FieldAbility::Fly => Self::fly_to_visited_location(player),
+            FieldAbility::Surf if environment == Some(EnvironmentType::Water) => {
+                println!("{} 载着你在水面上前进！", pokemon_name);
+            }
+            FieldAbility::Flash if environment == Some(EnvironmentType::Cave) => {
+                println!("洞穴被照亮了！");
+            }
+            FieldAbility::RockSmash if matches!(environment, Some(EnvironmentType::Cave | EnvironmentType::Mountain)) => {
+                println!("岩石被击碎了！可以在探索时用碎岩寻找藏在岩石里的宝可梦。");
+            }
+            _ => println!("但是这里没有可以使用{}的地方。", ability.name()),
+        }
+    }
+
+    /// Fly to any previously visited location
+    fn fly_to_visited_location(player: &mut Player) {
+        let current_id = player.location_state.current_location_id;
+        let mut destinations: Vec<(u32, String, bool)> = crate::data::locations_data::get_all_locations()
+            .into_iter()
+            .filter(|location| location.id != current_id && player.location_state.is_visited(location.id))
+            .map(|location| (location.id, location.name, true))
+            .collect();
+        destinations.sort_by_key(|(id, _, _)| *id);
+
+        if destinations.is_empty() {
+            LocationMenu::show_no_reachable_locations();
+            return;
+        }
+
+        if let Some(location) = crate::data::locations_data::get_location_by_id(current_id) {
+            LocationMenu::display_movement_menu(&location, &destinations);
+        }
+        let choice = LocationMenu::get_input();
+        if let Ok(idx) = choice.parse::<usize>() {
+            if idx > 0 && idx <= destinations.len() {
+                let (target_id, target_name, _) = &destinations[idx - 1];
+                player.location_state.current_location_id = *target_id;
+                if let Some(target) = crate::data::locations_data::get_location_by_id(*target_id) {
+                    player.location_state.record_pokemon_center(&target);
+                }
+                LocationMenu::show_movement_success(target_name);
+            }
+        }
+    }
+
     /// Handle map exploration
     pub fn explore_map(_player: &mut Player, game_map: &mut GameMap) {
         loop {
@@ -6,8 +6,11 @@
 //! - Menu routing and user input delegation
 //! - Player state management
 
-use crate::game::Player;
-use crate::cli::{Menu, TeamListMenu, PokemonDetailMenu};
+use crate::game::achievement::{self, Achievement};
+use crate::game::{PokedexEntry, Player, Quest, TrainerCard};
+use crate::cli::{AchievementMenu, Menu, PokedexMenu, QuestMenu, TeamListMenu, TrainerCardMenu, PokemonDetailMenu};
+use crate::utils::showdown;
+use std::time::{Duration, Instant};
 
 /// Central game controller for orchestrating the main game flow
 pub struct GameController;
@@ -34,9 +37,11 @@ impl GameController {
     fn start_new_game() {
         let player_name = Menu::get_player_name();
         let mut player = Player::new(player_name.clone());
+        player.hardcore = Menu::get_hardcore_mode();
 
         // Give player a starter Pokemon
         if let Some(starter_pokemon) = crate::data::pokemon_data::get_pokemon_by_id(25) {
+            player.pokedex.mark_caught(starter_pokemon.id);
             let _ = player.add_pokemon(starter_pokemon);
         }
 
@@ -51,8 +56,19 @@ impl GameController {
     /// Main game loop
     fn game_loop(player: &mut Player) {
         let all_locations = crate::data::locations_data::get_all_locations();
+        let mut last_tick = Instant::now();
 
         loop {
+            // Playtime advances in whole seconds; the remainder carries over to the next tick
+            let elapsed = last_tick.elapsed().as_secs();
+            player.stats.playtime_seconds += elapsed;
+            last_tick += Duration::from_secs(elapsed);
+
+            // Announce achievements earned by the last action
+            for achievement in achievement::unlock_new(player, &Achievement::all(), all_locations.len()) {
+                AchievementMenu::display_unlocked(&achievement);
+            }
+
             // Check for new unlocked locations
             player.check_new_unlocks(&all_locations);
 
@@ -71,7 +87,13 @@ impl GameController {
 
             // Display menu
             if let Some(location) = &current_location {
-                Menu::print_game_menu_with_location(&location.name, visited_count, all_locations.len());
+                Menu::print_game_menu_with_location(
+                    &location.name,
+                    &player.clock.display(),
+                    &player.encounter_effects.descriptions(),
+                    visited_count,
+                    all_locations.len(),
+                );
             } else {
                 Menu::print_game_menu();
             }
@@ -92,7 +114,7 @@ impl GameController {
                     // Display team with detail viewing capability
                     Self::view_team_details(player);
                 }
-                "4" => player.display_items(),
+                "4" => crate::handlers::BagHandler::open_bag(player),
                 "5" => {
                     use crate::map::{GameMap, create_locations};
                     let mut game_map = GameMap::new();
@@ -100,6 +122,14 @@ impl GameController {
                     crate::handlers::ExplorationHandler::explore_map(player, &mut game_map);
                 }
                 "6" => {
+                    if let Some(location) = &current_location {
+                        crate::handlers::ServiceHandler::handle_services(player, location);
+                    }
+                }
+                "7" => crate::handlers::ExplorationHandler::handle_field_abilities(player),
+                "8" => Self::view_pokedex(player),
+                "9" => Self::view_trainer_card(player),
+                "10" => {
                     println!("\n感谢游玩!");
                     break;
                 }
@@ -108,14 +138,88 @@ impl GameController {
         }
     }
 
+    /// Show the trainer card and export it as JSON on request
+    fn view_trainer_card(player: &Player) {
+        let card = TrainerCard::from_player(player);
+        loop {
+            TrainerCardMenu::display_trainer_card(&card);
+            match Menu::get_input().as_str() {
+                "0" => break,
+                "a" | "A" => Self::view_achievements(player),
+                "j" | "J" => Self::view_quest_journal(player),
+                "e" | "E" => match card.to_json() {
+                    Ok(json) => TrainerCardMenu::display_export(&json),
+                    Err(e) => println!("✗ {}", e),
+                },
+                _ => println!("无效的选择，请重试"),
+            }
+        }
+    }
+
+    /// List every achievement with its progress
+    fn view_achievements(player: &Player) {
+        let total_locations = crate::data::locations_data::get_all_locations().len();
+        let achievements: Vec<_> = Achievement::all()
+            .into_iter()
+            .map(|achievement| {
+                let progress = achievement.condition.progress(player, total_locations);
+                let unlocked = player.achievements.contains_key(&achievement.id);
+                (achievement, progress, unlocked)
+            })
+            .collect();
+        AchievementMenu::display_achievements(&achievements);
+    }
+
+    /// Show the quest journal: active quests with their progress and completed quests
+    fn view_quest_journal(player: &Player) {
+        let active: Vec<(Quest, (u32, u32))> = player
+            .quests
+            .active
+            .iter()
+            .filter_map(|(&id, &accepted_at)| {
+                let quest = Quest::get(id)?;
+                let progress = quest.objective.progress(player, accepted_at);
+                Some((quest, progress))
+            })
+            .collect();
+        let completed: Vec<Quest> = player.quests.completed.iter().filter_map(|&id| Quest::get(id)).collect();
+        QuestMenu::display_journal(&active, &completed);
+    }
+
+    /// Browse the Pokédex and show the entries the player picks
+    fn view_pokedex(player: &Player) {
+        let entries = PokedexEntry::all();
+        loop {
+            PokedexMenu::display_pokedex(&entries, &player.pokedex);
+            match Menu::get_input().parse::<usize>() {
+                Ok(0) => break,
+                Ok(idx) if idx <= entries.len() => PokedexMenu::display_entry(&entries[idx - 1], &player.pokedex),
+                _ => println!("无效的选择，请重试"),
+            }
+        }
+    }
+
     /// View team details with Pokemon selection and detailed information
-    fn view_team_details(player: &Player) {
+    fn view_team_details(player: &mut Player) {
         loop {
             TeamListMenu::display_team_list(player);
             let choice = TeamListMenu::get_input();
 
-            if choice == "0" {
-                break; // Return to main menu
+            match choice.as_str() {
+                "0" => break, // Return to main menu
+                "e" | "E" => {
+                    TeamListMenu::display_export("队伍", &showdown::export_team(&player.pokemons));
+                    continue;
+                }
+                "b" | "B" => {
+                    Self::export_box(player);
+                    continue;
+                }
+                "i" | "I" => {
+                    Self::import_pokemon(player);
+                    continue;
+                }
+                _ => {}
             }
 
             if let Ok(idx) = choice.parse::<usize>() {
@@ -130,6 +234,41 @@ impl GameController {
         }
     }
 
+    /// Export every Pokemon in one storage box as Showdown text
+    fn export_box(player: &Player) {
+        print!("要导出哪个箱子 (编号): ");
+        let _ = std::io::Write::flush(&mut std::io::stdout());
+        let result = Menu::get_input()
+            .parse::<u32>()
+            .map_err(|_| "无效的箱子编号".to_string())
+            .and_then(|box_id| player.storage.get_box_pokemon(box_id).map(|pokemon| (box_id, pokemon)));
+        match result {
+            Ok((_, [])) => println!("✗ 这个箱子是空的"),
+            Ok((box_id, pokemon)) => {
+                TeamListMenu::display_export(&format!("箱子 {}", box_id), &showdown::export_team(pokemon))
+            }
+            Err(e) => println!("✗ {}", e),
+        }
+    }
+
+    /// Import a Showdown paste as a trade: imported Pokemon can trade-evolve,
+    /// then join the team, overflow goes to storage
+    fn import_pokemon(player: &mut Player) {
+        let imported = match showdown::import_team(&TeamListMenu::read_paste()) {
+            Ok(imported) => imported,
+            Err(e) => {
+                println!("✗ 导入失败: {}", e);
+                return;
+            }
+        };
+        for pokemon in imported {
+            match player.receive_traded_pokemon(pokemon) {
+                Ok(message) => println!("✓ {}", message),
+                Err(e) => println!("✗ {}", e),
+            }
+        }
+    }
+
     /// Show detailed information for a specific Pokemon with navigation
     fn show_pokemon_detail(player: &Player, pokemon_index: usize) {
         loop {
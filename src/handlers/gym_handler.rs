@@ -0,0 +1,82 @@
+//! GymHandler - Gym challenges
+//!
+//! Responsible for:
+//! - Listing the gym leaders at the current location from trainers.json
+//! - Battling a leader with their configured team
+//! - Awarding the leader's badge on the first victory
+//! - Counting victories without a faint for achievements
+
+use crate::cli::{GymMenu, Menu};
+use crate::game::{BadgeInfo, Location, Player};
+use crate::handlers::BattleHandler;
+use crate::npc::TrainerData;
+
+/// Handles challenging the gym leaders at a location
+pub struct GymHandler;
+
+impl GymHandler {
+    /// Show the gym leaders and battle the chosen one
+    pub fn handle_gym(player: &mut Player, location: &Location) {
+        let leaders: Vec<TrainerData> = TrainerData::at_location(location.id)
+            .into_iter()
+            .filter(TrainerData::is_gym_leader)
+            .collect();
+        if leaders.is_empty() {
+            GymMenu::display_no_leader(&location.name);
+            return;
+        }
+
+        let defeated: Vec<bool> = leaders
+            .iter()
+            .map(|leader| player.defeated_trainers.contains(&leader.id))
+            .collect();
+        GymMenu::display_gym_menu(&location.name, &leaders, &defeated);
+        let leader = match Menu::get_input().parse::<usize>() {
+            Ok(idx) if idx > 0 && idx <= leaders.len() => &leaders[idx - 1],
+            _ => return,
+        };
+
+        Self::challenge(player, leader);
+    }
+
+    /// Battle a gym leader; the first victory records the leader and awards the badge
+    fn challenge(player: &mut Player, leader: &TrainerData) {
+        let team = leader.build_team();
+        if team.is_empty() {
+            GymMenu::display_no_leader(&leader.name);
+            return;
+        }
+
+        GymMenu::display_challenge(leader);
+        let fainted_before = player.get_fainted_pokemon_count();
+        let first_victory = !player.defeated_trainers.contains(&leader.id);
+        let result = match BattleHandler::execute_npc_battle(player, leader.id, team) {
+            Ok(result) => result,
+            Err(e) => {
+                println!("✗ {}", e);
+                return;
+            }
+        };
+
+        if !result.won {
+            GymMenu::display_defeat(leader);
+            return;
+        }
+
+        player.add_money(result.money_gained);
+        if player.get_fainted_pokemon_count() == fainted_before {
+            player.stats.flawless_gym_wins += 1;
+        }
+        GymMenu::display_victory(leader, result.money_gained);
+        if !first_victory {
+            return;
+        }
+        if let Some(badge_id) = leader.badge_id {
+            if player.award_badge(badge_id).is_some() {
+                if let Some(badge) = BadgeInfo::get(badge_id) {
+                    GymMenu::display_badge_awarded(&badge);
+                }
+            }
+        }
+    }
+}
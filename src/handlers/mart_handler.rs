@@ -0,0 +1,86 @@
+//! MartHandler - Poké Mart
+//!
+//! Responsible for:
+//! - Listing the stock of the location's mart, unlocked by badge count
+//! - Buying items with quantity selection and a receipt
+//! - Selling bag items at half price
+
+use crate::cli::{MartMenu, Menu};
+use crate::game::mart::{self, Mart, MAX_QUANTITY};
+use crate::game::{Location, Player};
+
+/// Handles buying and selling at a location's Poké Mart
+pub struct MartHandler;
+
+impl MartHandler {
+    /// Show the mart and dispatch buy/sell until the player leaves
+    pub fn handle_mart(player: &mut Player, location: &Location) {
+        if location.mart.is_empty() {
+            MartMenu::display_empty_mart(&location.name);
+            return;
+        }
+        let mart = Mart::new(location.mart.clone());
+
+        loop {
+            MartMenu::display_mart_menu(&location.name, player.money);
+            match Menu::get_input().as_str() {
+                "1" => Self::handle_buy(player, &mart),
+                "2" => Self::handle_sell(player),
+                "0" => return,
+                _ => println!("无效的选择，请重试"),
+            }
+        }
+    }
+
+    /// Pick an item from the unlocked stock and buy the chosen quantity
+    fn handle_buy(player: &mut Player, mart: &Mart) {
+        let items = mart.available_items(player);
+        MartMenu::display_buy_menu(&items, mart.locked_count(player), player.money);
+        let item = match Menu::get_input().parse::<usize>() {
+            Ok(idx) if idx > 0 && idx <= items.len() => &items[idx - 1],
+            _ => return,
+        };
+
+        let unit_price = item.price.unwrap_or(0);
+        let affordable = player.money.checked_div(unit_price).unwrap_or(MAX_QUANTITY);
+        MartMenu::prompt_quantity(&item.name, unit_price, affordable.max(1));
+        let quantity = match Self::read_quantity() {
+            Some(quantity) => quantity,
+            None => return,
+        };
+
+        match player.buy_item(item, quantity) {
+            Ok(receipt) => println!("{}", receipt),
+            Err(e) => println!("✗ {}", e),
+        }
+    }
+
+    /// Pick an item from the bag and sell the chosen quantity
+    fn handle_sell(player: &mut Player) {
+        let items = Mart::sellable_items(player);
+        MartMenu::display_sell_menu(&items, player.money);
+        let (item, count) = match Menu::get_input().parse::<usize>() {
+            Ok(idx) if idx > 0 && idx <= items.len() => &items[idx - 1],
+            _ => return,
+        };
+
+        MartMenu::prompt_quantity(&item.name, mart::sell_price(item).unwrap_or(0), *count);
+        let quantity = match Self::read_quantity() {
+            Some(quantity) => quantity,
+            None => return,
+        };
+
+        match player.sell_item(item, quantity) {
+            Ok(receipt) => println!("{}", receipt),
+            Err(e) => println!("✗ {}", e),
+        }
+    }
+
+    /// Read a quantity; 0 or invalid input cancels
+    fn read_quantity() -> Option<u32> {
+        match Menu::get_input().parse::<u32>() {
+            Ok(0) | Err(_) => None,
+            Ok(quantity) => Some(quantity),
+        }
+    }
+}
@@ -6,12 +6,24 @@
 //! - BattleHandler: Battle execution and turn management
 //! - RevivalHandler: Pokemon faint detection and revival mechanics
 //! - ExplorationHandler: Location movement and exploration
+//! - ServiceHandler: Location services such as the move tutor and relearner
+//! - DayCareHandler: Day-care breeding and egg hatching
+//! - MartHandler: Poké Mart buying and selling
+//! - BagHandler: Bag pockets, using and tossing items outside battle
+//! - GymHandler: Gym leader challenges and badge awards
+//! - QuestHandler: NPC quests offered by location residents
 
 pub mod game_controller;
 pub mod encounter_manager;
 pub mod battle_handler;
 pub mod revival_handler;
 pub mod exploration_handler;
+pub mod service_handler;
+pub mod day_care_handler;
+pub mod mart_handler;
+pub mod bag_handler;
+pub mod gym_handler;
+pub mod quest_handler;
 
 // Re-export public APIs
 pub use game_controller::GameController;
@@ -19,3 +31,9 @@ pub use encounter_manager::{EncounterManager, EncounterResult};
 pub use battle_handler::{BattleHandler, BattleResult, BattleAction};
 pub use revival_handler::RevivalHandler;
 pub use exploration_handler::{ExplorationHandler, ExplorationResult};
+pub use service_handler::ServiceHandler;
+pub use day_care_handler::DayCareHandler;
+pub use mart_handler::MartHandler;
+pub use bag_handler::BagHandler;
+pub use gym_handler::GymHandler;
+pub use quest_handler::QuestHandler;
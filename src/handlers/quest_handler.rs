@@ -0,0 +1,74 @@
+//! QuestHandler - NPC quests
+//!
+//! Responsible for:
+//! - Listing the quest-giving residents at the current location from quests.json
+//! - Offering a resident's quests once their prerequisites are completed
+//! - Handing in quests whose objectives are met and granting the rewards
+
+use crate::cli::{Menu, QuestMenu};
+use crate::game::{Location, Player, Quest, QuestStatus};
+use crate::npc::QuestNpc;
+
+/// Handles talking to the residents who give quests
+pub struct QuestHandler;
+
+impl QuestHandler {
+    /// Show the residents at the location and talk to the chosen one
+    pub fn handle_residents(player: &mut Player, location: &Location) {
+        let npcs = QuestNpc::at_location(location.id);
+        if npcs.is_empty() {
+            QuestMenu::display_no_residents(&location.name);
+            return;
+        }
+
+        QuestMenu::display_residents(&location.name, &npcs);
+        if let Ok(idx) = Menu::get_input().parse::<usize>() {
+            if idx > 0 && idx <= npcs.len() {
+                Self::talk(player, &npcs[idx - 1]);
+            }
+        }
+    }
+
+    /// List the NPC's unlocked quests and offer, report on or hand in the chosen one
+    fn talk(player: &mut Player, npc: &QuestNpc) {
+        let quests: Vec<(Quest, QuestStatus, bool)> = npc
+            .quests()
+            .into_iter()
+            .map(|quest| {
+                let status = player.quest_status(&quest);
+                let met = player.quest_objective_met(&quest);
+                (quest, status, met)
+            })
+            .filter(|(_, status, _)| *status != QuestStatus::Locked)
+            .collect();
+
+        QuestMenu::display_npc_quests(npc, &quests);
+        let (quest, status, met) = match Menu::get_input().parse::<usize>() {
+            Ok(idx) if idx > 0 && idx <= quests.len() => &quests[idx - 1],
+            _ => return,
+        };
+
+        match status {
+            QuestStatus::Available => {
+                QuestMenu::display_offer(quest);
+                if Menu::get_input() == "1" {
+                    match player.accept_quest(quest) {
+                        Ok(message) => println!("{}", message),
+                        Err(e) => println!("✗ {}", e),
+                    }
+                }
+            }
+            QuestStatus::Active if *met => match player.complete_quest(quest) {
+                Ok(message) => QuestMenu::display_completed(&message, quest),
+                Err(e) => println!("✗ {}", e),
+            },
+            QuestStatus::Active => {
+                let accepted_at = player.quests.active.get(&quest.id).copied().unwrap_or(0);
+                QuestMenu::display_in_progress(quest, quest.objective.progress(player, accepted_at));
+            }
+            QuestStatus::Completed | QuestStatus::Locked => {
+                println!("\n{}: 「谢谢你帮了大忙！」", npc.name);
+            }
+        }
+    }
+}
@@ -2,6 +2,7 @@
 //!
 //! Responsible for:
 //! - Pokemon faint detection
+//! - Blackout: money penalty and respawn at the last Pokemon Center
 //! - Item-based revival
 //! - Pokemon center mechanics
 //! - Cost calculation and payment
@@ -14,11 +15,19 @@ pub struct RevivalHandler;
 
 impl RevivalHandler {
     /// Check if all Pokemon are fainted and handle revival if needed
+    ///
+    /// Normally the player blacks out and respawns at the last Pokemon Center;
+    /// in hardcore mode they must revive with items or pay, or end the game.
     pub fn check_and_handle_faint(player: &mut Player) -> bool {
         if !player.all_pokemon_fainted() {
             return true;
         }
 
+        if !player.hardcore {
+            Self::handle_blackout(player);
+            return true;
+        }
+
         // All Pokemon are fainted - need to revive
         loop {
             RevivalMenu::print_all_pokemon_fainted_menu();
@@ -47,6 +56,15 @@ impl RevivalHandler {
         }
     }
 
+    /// Black out: lose money, return to the last Pokemon Center and heal the team
+    fn handle_blackout(player: &mut Player) {
+        let money_lost = player.black_out();
+        let center_name = crate::data::locations_data::get_location_by_id(player.location_state.current_location_id)
+            .map(|location| location.name)
+            .unwrap_or_default();
+        RevivalMenu::print_blackout(money_lost, &center_name);
+    }
+
     /// Handle revival using items
     fn handle_item_revival(player: &mut Player) -> bool {
         RevivalMenu::print_select_pokemon_to_revive_menu(player);
@@ -79,21 +97,18 @@ impl RevivalHandler {
                         return false;
                     }
 
-                    let recovery_items = vec!["恢复药", "超级恢复药", "全复活", "完全恢复"];
-                    let mut available_idx = 0;
-                    for item_name in recovery_items {
-                        if player.items.get(item_name).copied().unwrap_or(0) > 0 {
-                            available_idx += 1;
-                            if item_choice == available_idx.to_string() {
-                                match player.revive_pokemon_with_item(pokemon_index, item_name) {
-                                    Ok(msg) => {
-                                        RevivalMenu::print_revival_success(&msg);
-                                        return true;
-                                    }
-                                    Err(e) => {
-                                        RevivalMenu::print_revival_failed(&e);
-                                        return false;
-                                    }
+                    let recovery_items = player.get_recovery_items();
+                    if let Ok(item_idx) = item_choice.parse::<usize>() {
+                        if item_idx > 0 && item_idx <= recovery_items.len() {
+                            let item_id = recovery_items[item_idx - 1].0.id;
+                            match player.revive_pokemon_with_item(pokemon_index, item_id) {
+                                Ok(msg) => {
+                                    RevivalMenu::print_revival_success(&msg);
+                                    return true;
+                                }
+                                Err(e) => {
+                                    RevivalMenu::print_revival_failed(&e);
+                                    return false;
                                 }
                             }
                         }
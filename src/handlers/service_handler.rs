@@ -0,0 +1,121 @@
+//! ServiceHandler - Location services
+//!
+//! Responsible for:
+//! - Listing the services offered at the current location
+//! - Move tutor: teaching species tutor moves for money
+//! - Move relearner: restoring forgotten learnset moves for money
+//! - Day care: handed off to DayCareHandler
+//! - Poké Mart: handed off to MartHandler
+//! - Pokemon Center: free full heal of the team
+//! - Gym: handed off to GymHandler
+//! - Residents: handed off to QuestHandler
+
+use crate::cli::{Menu, MoveLearnMenu, ServiceMenu};
+use crate::data::{loader, pokemon_data};
+use crate::game::{Location, LocationService, Player};
+use crate::handlers::{DayCareHandler, GymHandler, MartHandler, QuestHandler};
+
+/// Default move tutor price when game_constants.json does not set one
+const DEFAULT_TUTOR_PRICE: u32 = 3000;
+/// Default move relearner price when game_constants.json does not set one
+const DEFAULT_RELEARNER_PRICE: u32 = 1000;
+
+/// Handles the services offered at locations
+pub struct ServiceHandler;
+
+impl ServiceHandler {
+    /// Show the services at the current location and dispatch the chosen one
+    pub fn handle_services(player: &mut Player, location: &Location) {
+        if location.services.is_empty() {
+            ServiceMenu::display_no_services(&location.name);
+            return;
+        }
+
+        ServiceMenu::display_service_menu(&location.name, &location.services);
+        let choice = Menu::get_input();
+        if let Ok(idx) = choice.parse::<usize>() {
+            if idx > 0 && idx <= location.services.len() {
+                match location.services[idx - 1] {
+                    LocationService::DayCare => DayCareHandler::handle_day_care(player),
+                    LocationService::PokeMart => MartHandler::handle_mart(player, location),
+                    LocationService::Gym => GymHandler::handle_gym(player, location),
+                    LocationService::Residents => QuestHandler::handle_residents(player, location),
+                    LocationService::PokemonCenter => {
+                        player.location_state.record_pokemon_center(location);
+                        player.heal_at_pokemon_center();
+                        ServiceMenu::display_pokemon_center_healed();
+                    }
+                    service => Self::handle_move_service(player, service),
+                }
+            }
+        }
+    }
+
+    /// Price of a move service, from game_constants.json (other services are free)
+    pub fn service_price(service: LocationService) -> u32 {
+        let (key, default) = match service {
+            LocationService::MoveTutor => ("tutor_price", DEFAULT_TUTOR_PRICE),
+            LocationService::MoveRelearner => ("relearner_price", DEFAULT_RELEARNER_PRICE),
+            LocationService::DayCare
+            | LocationService::PokeMart
+            | LocationService::PokemonCenter
+            | LocationService::Gym
+            | LocationService::Residents => return 0,
+        };
+        loader::get_game_constant("move_services", key)
+            .map(|price| price as u32)
+            .unwrap_or(default)
+    }
+
+    /// Teach a tutor or relearner move to a team member, charging only when it is learned
+    fn handle_move_service(player: &mut Player, service: LocationService) {
+        if player.pokemons.is_empty() {
+            return;
+        }
+
+        player.display_team();
+        println!("0. 返回");
+        let pokemon_index = match Menu::get_input().parse::<usize>() {
+            Ok(idx) if idx > 0 && idx <= player.pokemons.len() => idx - 1,
+            _ => return,
+        };
+
+        let pokemon = &player.pokemons[pokemon_index];
+        let moves: Vec<_> = match service {
+            LocationService::MoveTutor => pokemon_data::get_tutor_moves(pokemon.id)
+                .into_iter()
+                .filter(|m| !pokemon.knows_move(m.id))
+                .collect(),
+            LocationService::MoveRelearner => pokemon_data::get_relearnable_moves(pokemon),
+            LocationService::DayCare
+            | LocationService::PokeMart
+            | LocationService::PokemonCenter
+            | LocationService::Gym
+            | LocationService::Residents => return,
+        };
+        if moves.is_empty() {
+            ServiceMenu::display_no_moves(service, &pokemon.name);
+            return;
+        }
+
+        let price = Self::service_price(service);
+        ServiceMenu::display_move_options(service, &pokemon.name, &moves, price, player.money);
+        let new_move = match Menu::get_input().parse::<usize>() {
+            Ok(idx) if idx > 0 && idx <= moves.len() => moves[idx - 1].clone(),
+            _ => return,
+        };
+
+        if player.money < price {
+            println!("✗ 金币不足。需要 ¥{}，但只有 ¥{}", price, player.money);
+            return;
+        }
+
+        if MoveLearnMenu::learn_move(&mut player.pokemons[pokemon_index], new_move) {
+            if let Err(e) = player.spend_money(price) {
+                println!("✗ {}", e);
+            } else {
+                println!("✓ 支付了 ¥{} (剩余金币: ¥{})", price, player.money);
+            }
+        }
+    }
+}
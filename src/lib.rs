@@ -13,5 +13,5 @@ pub mod handlers;
 pub use game::{Player, Pokemon, Battle};
 pub use map::{GameMap, Location, Region};
 pub use npc::{NPCTrainer, Difficulty};
-pub use pokemon_generator::{IndividualValues, Talent, Nature, PokemonInstance};
+pub use pokemon_generator::{IndividualValues, EffortValues, Talent, Nature, PokemonInstance};
 pub use handlers::{GameController, EncounterManager, BattleHandler, RevivalHandler, ExplorationHandler};
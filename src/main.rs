@@ -16,14 +16,37 @@ use handlers::GameController;
 
 fn main() {
     // Load game data from JSON files
-    match data::loader::load_all_data() {
-        Ok(()) => {
+    if let Err(e) = data::loader::load_all_data() {
+        eprintln!("Failed to load game data: {}", e);
+        std::process::exit(1);
+    }
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.as_slice() {
+        [] => {
             println!("Game data loaded successfully!\n");
             GameController::run();
         }
+        [command, save_file] if command == "check-save" => check_save(save_file),
+        _ => {
+            eprintln!("Usage: poke [check-save <save-file>]");
+            std::process::exit(2);
+        }
+    }
+}
+
+/// Run the legality checker on a save file and exit non-zero if anything is illegal
+fn check_save(save_file: &str) {
+    match utils::save::SaveManager::check_save(save_file) {
+        Ok(report) => {
+            println!("{}", report);
+            if !report.is_legal() {
+                std::process::exit(1);
+            }
+        }
         Err(e) => {
-            eprintln!("Failed to load game data: {}", e);
-            std::process::exit(1);
+            eprintln!("Failed to read save file {}: {}", save_file, e);
+            std::process::exit(2);
         }
     }
 }
@@ -1,7 +1,11 @@
 use serde::{Deserialize, Serialize};
 
 pub mod trainers;
+pub mod trainer_data;
+pub mod quest_npc;
 pub use trainers::{create_all_npcs, get_npc_by_id, get_all_npcs, get_npcs_by_location};
+pub use trainer_data::TrainerData;
+pub use quest_npc::QuestNpc;
 
 /// 对战难度等级
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -0,0 +1,43 @@
+use crate::data::loader;
+use crate::game::Quest;
+use serde::{Deserialize, Serialize};
+
+/// quests.json 中发布任务的 NPC
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestNpc {
+    pub id: u32,
+    pub name: String,
+    pub location_id: u32,
+    #[serde(default)]
+    pub greeting: Option<String>,
+    pub quest_ids: Vec<u32>,
+}
+
+impl QuestNpc {
+    /// quests.json 中的所有 NPC (加载数据时已经校验过，每一条都能解析)
+    pub fn all() -> Vec<QuestNpc> {
+        loader::get_game_data()
+            .map(|data| {
+                data.quest_npcs
+                    .iter()
+                    .map(|npc| serde_json::from_value(npc.clone()).expect("任务 NPC 数据在加载时已校验"))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// 按 ID 查找 NPC
+    pub fn get(id: u32) -> Option<QuestNpc> {
+        Self::all().into_iter().find(|npc| npc.id == id)
+    }
+
+    /// 某个地点的所有 NPC
+    pub fn at_location(location_id: u32) -> Vec<QuestNpc> {
+        Self::all().into_iter().filter(|npc| npc.location_id == location_id).collect()
+    }
+
+    /// 这个 NPC 发布的任务
+    pub fn quests(&self) -> Vec<Quest> {
+        self.quest_ids.iter().filter_map(|&id| Quest::get(id)).collect()
+    }
+}
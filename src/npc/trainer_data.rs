@@ -0,0 +1,62 @@
+use crate::data::{loader, pokemon_data};
+use crate::game::Pokemon;
+use crate::pokemon_generator::PokemonInstance;
+use serde::{Deserialize, Serialize};
+
+/// 训练师队伍中的一只宝可梦
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrainerPokemon {
+    pub pokemon_id: u32,
+    pub level: u32,
+}
+
+/// trainers.json 中的一名训练师
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrainerData {
+    pub id: u32,
+    pub name: String,
+    pub title: String,
+    pub location_id: u32,
+    pub team: Vec<TrainerPokemon>,
+    /// 第一次被击败时颁发的徽章 (只有馆主有)
+    #[serde(default)]
+    pub badge_id: Option<u32>,
+}
+
+impl TrainerData {
+    /// 按 ID 从 trainers.json 查找训练师
+    pub fn get(id: u32) -> Option<TrainerData> {
+        loader::get_trainer_by_id(id).and_then(|trainer| serde_json::from_value(trainer).ok())
+    }
+
+    /// 某个地点的所有训练师
+    pub fn at_location(location_id: u32) -> Vec<TrainerData> {
+        loader::get_game_data()
+            .map(|data| {
+                data.trainers
+                    .iter()
+                    .filter_map(|trainer| serde_json::from_value::<TrainerData>(trainer.clone()).ok())
+                    .filter(|trainer| trainer.location_id == location_id)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// 是否为颁发徽章的馆主
+    pub fn is_gym_leader(&self) -> bool {
+        self.badge_id.is_some()
+    }
+
+    /// 生成对战用的队伍 (找不到的宝可梦会被跳过)
+    pub fn build_team(&self) -> Vec<Pokemon> {
+        self.team
+            .iter()
+            .map(|member| PokemonInstance::new(member.pokemon_id, member.level))
+            .filter_map(|instance| pokemon_data::get_pokemon_from_instance(&instance))
+            .map(|mut pokemon| {
+                pokemon.original_trainer = Some(self.name.clone());
+                pokemon
+            })
+            .collect()
+    }
+}
@@ -1,4 +1,4 @@
-use super::{PokemonInstance, IndividualValues, Talent, Nature};
+use super::{PokemonInstance, IndividualValues, EffortValues, Talent, Nature, Gender, roll_shiny};
 use std::collections::HashMap;
 
 /// Pokémon 物种信息
@@ -22,31 +22,34 @@ pub struct BaseStats {
 }
 
 impl BaseStats {
-    /// 计算最终属性值 (包含 IV 和性格)
+    /// 计算最终属性值 (包含 IV、EV 和性格)
     pub fn calculate_stat(
         &self,
         base_value: u32,
         iv_value: u32,
+        ev_value: u32,
         level: u32,
         multiplier: f32,
     ) -> u32 {
         let base = base_value as f32;
         let iv = iv_value as f32;
+        let ev = (ev_value / 4) as f32;
         let lvl = level as f32;
 
-        // 标准公式: ((2 * Base + IV) * Level / 100 + 5) * Nature_Multiplier
-        let raw_stat = ((2.0 * base + iv) * lvl / 100.0 + 5.0) * multiplier;
+        // 标准公式: ((2 * Base + IV + EV / 4) * Level / 100 + 5) * Nature_Multiplier
+        let raw_stat = ((2.0 * base + iv + ev) * lvl / 100.0 + 5.0) * multiplier;
         raw_stat.max(1.0) as u32
     }
 
     /// 计算 HP (特殊公式)
-    pub fn calculate_hp(&self, iv_hp: u32, level: u32) -> u32 {
+    pub fn calculate_hp(&self, iv_hp: u32, ev_hp: u32, level: u32) -> u32 {
         let base = self.hp as f32;
         let iv = iv_hp as f32;
+        let ev = (ev_hp / 4) as f32;
         let lvl = level as f32;
 
-        // HP 公式: (2 * Base + IV) * Level / 100 + Level + 5
-        ((2.0 * base + iv) * lvl / 100.0 + lvl + 5.0) as u32
+        // HP 公式: (2 * Base + IV + EV / 4) * Level / 100 + Level + 5
+        ((2.0 * base + iv + ev) * lvl / 100.0 + lvl + 5.0) as u32
     }
 }
 
@@ -253,11 +256,14 @@ pub fn generate_pokemon_with_ivs(
     Ok(PokemonInstance {
         species_id,
         level,
-        experience: level as u32 * 100,
+        experience: crate::data::pokemon_data::get_growth_rate(species_id).experience_for_level(level),
         individual_values: ivs,
+        effort_values: EffortValues::default(),
         talent: Talent::random(),
         nature: Nature::random(),
         unique_id: Uuid::new_v4().to_string(),
+        gender: Gender::random_for_species(species_id),
+        is_shiny: roll_shiny(crate::data::pokemon_data::get_shiny_odds()),
     })
 }
 
@@ -297,29 +303,28 @@ pub fn calculate_pokemon_stats(
     let nature_mults = pokemon.get_nature_multipliers();
     let level = pokemon.level;
     let ivs = &pokemon.individual_values;
-
-    let hp = species.base_stats.calculate_hp(ivs.hp, level);
-    let attack = species
-        .base_stats
-        .calculate_stat(species.base_stats.attack, ivs.attack, level, nature_mults.attack);
-    let defense = species
-        .base_stats
-        .calculate_stat(species.base_stats.defense, ivs.defense, level, nature_mults.defense);
-    let sp_attack = species.base_stats.calculate_stat(
-        species.base_stats.sp_attack,
+    let evs = &pokemon.effort_values;
+    let base = &species.base_stats;
+
+    let hp = base.calculate_hp(ivs.hp, evs.hp, level);
+    let attack = base.calculate_stat(base.attack, ivs.attack, evs.attack, level, nature_mults.attack);
+    let defense =
+        base.calculate_stat(base.defense, ivs.defense, evs.defense, level, nature_mults.defense);
+    let sp_attack = base.calculate_stat(
+        base.sp_attack,
         ivs.sp_attack,
+        evs.sp_attack,
         level,
         nature_mults.sp_attack,
     );
-    let sp_defense = species.base_stats.calculate_stat(
-        species.base_stats.sp_defense,
+    let sp_defense = base.calculate_stat(
+        base.sp_defense,
         ivs.sp_defense,
+        evs.sp_defense,
         level,
         nature_mults.sp_defense,
     );
-    let speed = species
-        .base_stats
-        .calculate_stat(species.base_stats.speed, ivs.speed, level, nature_mults.speed);
+    let speed = base.calculate_stat(base.speed, ivs.speed, evs.speed, level, nature_mults.speed);
 
     PokemonStats {
         hp,
@@ -366,3 +371,21 @@ impl PokemonStats {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_effort_values_raise_stats() {
+        let species = get_species(25).unwrap();
+        let mut pokemon = PokemonInstance::new(25, 100);
+        pokemon.nature = Nature::Hardy;
+        let before = calculate_pokemon_stats(&species, &pokemon);
+
+        pokemon.effort_values.add("speed", 252);
+        let after = calculate_pokemon_stats(&species, &pokemon);
+        assert_eq!(after.speed, before.speed + 63);
+        assert_eq!(after.attack, before.attack);
+    }
+}
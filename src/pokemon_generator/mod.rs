@@ -48,6 +48,33 @@ impl IndividualValues {
     pub fn total(&self) -> u32 {
         self.hp + self.attack + self.defense + self.sp_attack + self.sp_defense + self.speed
     }
+
+    /// 按属性名获取个体值
+    pub fn get(&self, stat: &str) -> u32 {
+        match stat {
+            "hp" => self.hp,
+            "attack" => self.attack,
+            "defense" => self.defense,
+            "sp_attack" => self.sp_attack,
+            "sp_defense" => self.sp_defense,
+            "speed" => self.speed,
+            _ => 0,
+        }
+    }
+
+    /// 按属性名设置个体值 (超过 31 时取 31)
+    pub fn set(&mut self, stat: &str, value: u32) {
+        let value = value.min(31);
+        match stat {
+            "hp" => self.hp = value,
+            "attack" => self.attack = value,
+            "defense" => self.defense = value,
+            "sp_attack" => self.sp_attack = value,
+            "sp_defense" => self.sp_defense = value,
+            "speed" => self.speed = value,
+            _ => {}
+        }
+    }
 }
 
 impl Default for IndividualValues {
@@ -63,6 +90,105 @@ impl Default for IndividualValues {
     }
 }
 
+/// 努力值 (Effort Values)，通过击败宝可梦或使用道具积累
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct EffortValues {
+    pub hp: u32,              // 0-252
+    pub attack: u32,          // 0-252
+    pub defense: u32,         // 0-252
+    pub sp_attack: u32,       // 0-252
+    pub sp_defense: u32,      // 0-252
+    pub speed: u32,           // 0-252
+}
+
+impl EffortValues {
+    /// 单项努力值上限
+    pub const MAX_PER_STAT: u32 = 252;
+    /// 努力值总和上限
+    pub const MAX_TOTAL: u32 = 510;
+    /// 所有属性的键名 (与 species.json / items.json 中一致)
+    pub const STAT_KEYS: [&'static str; 6] =
+        ["hp", "attack", "defense", "sp_attack", "sp_defense", "speed"];
+
+    /// 计算总点数
+    pub fn total(&self) -> u32 {
+        self.hp + self.attack + self.defense + self.sp_attack + self.sp_defense + self.speed
+    }
+
+    /// 按属性名获取努力值
+    pub fn get(&self, stat: &str) -> u32 {
+        match stat {
+            "hp" => self.hp,
+            "attack" => self.attack,
+            "defense" => self.defense,
+            "sp_attack" => self.sp_attack,
+            "sp_defense" => self.sp_defense,
+            "speed" => self.speed,
+            _ => 0,
+        }
+    }
+
+    fn get_mut(&mut self, stat: &str) -> Option<&mut u32> {
+        match stat {
+            "hp" => Some(&mut self.hp),
+            "attack" => Some(&mut self.attack),
+            "defense" => Some(&mut self.defense),
+            "sp_attack" => Some(&mut self.sp_attack),
+            "sp_defense" => Some(&mut self.sp_defense),
+            "speed" => Some(&mut self.speed),
+            _ => None,
+        }
+    }
+
+    /// 增加某项努力值 (受单项与总和上限限制)，返回实际增加的点数
+    pub fn add(&mut self, stat: &str, amount: u32) -> u32 {
+        let remaining_total = Self::MAX_TOTAL.saturating_sub(self.total());
+        match self.get_mut(stat) {
+            Some(value) => {
+                let gained = amount
+                    .min(Self::MAX_PER_STAT.saturating_sub(*value))
+                    .min(remaining_total);
+                *value += gained;
+                gained
+            }
+            None => 0,
+        }
+    }
+
+    /// 减少某项努力值，返回实际减少的点数
+    pub fn reduce(&mut self, stat: &str, amount: u32) -> u32 {
+        match self.get_mut(stat) {
+            Some(value) => {
+                let lost = amount.min(*value);
+                *value -= lost;
+                lost
+            }
+            None => 0,
+        }
+    }
+
+    /// 累加击败宝可梦获得的努力值，返回实际增加的总点数
+    pub fn add_yield(&mut self, ev_yield: &EffortValues) -> u32 {
+        Self::STAT_KEYS
+            .iter()
+            .map(|stat| self.add(stat, ev_yield.get(stat)))
+            .sum()
+    }
+
+    /// 获取属性的中文名称
+    pub fn stat_name(stat: &str) -> &'static str {
+        match stat {
+            "hp" => "HP",
+            "attack" => "攻击",
+            "defense" => "防守",
+            "sp_attack" => "特攻",
+            "sp_defense" => "特防",
+            "speed" => "速度",
+            _ => "未知",
+        }
+    }
+}
+
 /// 天赋系统，代表宝可梦的特殊能力
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Talent {
@@ -99,6 +225,61 @@ impl Default for Talent {
     }
 }
 
+/// 性别
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Gender {
+    Male,
+    Female,
+    #[default]
+    Genderless,
+}
+
+impl Gender {
+    /// 获取性别的中文名称
+    pub fn name(&self) -> &str {
+        match self {
+            Gender::Male => "雄性",
+            Gender::Female => "雌性",
+            Gender::Genderless => "无性别",
+        }
+    }
+
+    /// 获取性别符号 (无性别时为空)
+    pub fn symbol(&self) -> &str {
+        match self {
+            Gender::Male => "♂",
+            Gender::Female => "♀",
+            Gender::Genderless => "",
+        }
+    }
+
+    /// 按雌性比例随机性别，None 表示无性别物种
+    pub fn random(female_ratio: Option<f32>) -> Self {
+        use rand::Rng;
+        match female_ratio {
+            None => Gender::Genderless,
+            Some(ratio) => {
+                if rand::thread_rng().gen::<f32>() < ratio {
+                    Gender::Female
+                } else {
+                    Gender::Male
+                }
+            }
+        }
+    }
+
+    /// 按物种的性别比例随机性别
+    pub fn random_for_species(species_id: u32) -> Self {
+        Gender::random(crate::data::pokemon_data::get_female_ratio(species_id))
+    }
+}
+
+/// 按 1/odds 的概率判定是否为异色宝可梦 (odds 为 0 时不会出现异色)
+pub fn roll_shiny(odds: u32) -> bool {
+    use rand::Rng;
+    odds > 0 && rand::thread_rng().gen_range(0..odds) == 0
+}
+
 /// 性格系统，影响宝可梦的属性倾向
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Nature {
@@ -173,6 +354,38 @@ impl Nature {
         }
     }
 
+    /// 获取属性加成倍数
+    pub fn multipliers(&self) -> NatureMultipliers {
+        match self {
+            Nature::Lonely => NatureMultipliers { attack: 1.1, defense: 0.9, ..Default::default() },
+            Nature::Brave => NatureMultipliers { attack: 1.1, speed: 0.9, ..Default::default() },
+            Nature::Adamant => NatureMultipliers { attack: 1.1, sp_attack: 0.9, ..Default::default() },
+            Nature::Naughty => NatureMultipliers { attack: 1.1, sp_defense: 0.9, ..Default::default() },
+
+            Nature::Bold => NatureMultipliers { defense: 1.1, attack: 0.9, ..Default::default() },
+            Nature::Relaxed => NatureMultipliers { defense: 1.1, speed: 0.9, ..Default::default() },
+            Nature::Impish => NatureMultipliers { defense: 1.1, sp_attack: 0.9, ..Default::default() },
+            Nature::Lax => NatureMultipliers { defense: 1.1, sp_defense: 0.9, ..Default::default() },
+
+            Nature::Timid => NatureMultipliers { speed: 1.1, attack: 0.9, ..Default::default() },
+            Nature::Hasty => NatureMultipliers { speed: 1.1, defense: 0.9, ..Default::default() },
+            Nature::Jolly => NatureMultipliers { speed: 1.1, sp_attack: 0.9, ..Default::default() },
+            Nature::Naive => NatureMultipliers { speed: 1.1, sp_defense: 0.9, ..Default::default() },
+
+            Nature::Modest => NatureMultipliers { sp_attack: 1.1, attack: 0.9, ..Default::default() },
+            Nature::Mild => NatureMultipliers { sp_attack: 1.1, defense: 0.9, ..Default::default() },
+            Nature::Rash => NatureMultipliers { sp_attack: 1.1, sp_defense: 0.9, ..Default::default() },
+            Nature::Quiet => NatureMultipliers { sp_attack: 1.1, speed: 0.9, ..Default::default() },
+
+            Nature::Calm => NatureMultipliers { sp_defense: 1.1, attack: 0.9, ..Default::default() },
+            Nature::Gentle => NatureMultipliers { sp_defense: 1.1, defense: 0.9, ..Default::default() },
+            Nature::Sassy => NatureMultipliers { sp_defense: 1.1, speed: 0.9, ..Default::default() },
+            Nature::Careful => NatureMultipliers { sp_defense: 1.1, sp_attack: 0.9, ..Default::default() },
+
+            _ => NatureMultipliers::default(),
+        }
+    }
+
     /// 获取随机性格
     pub fn random() -> Self {
         use rand::Rng;
@@ -202,9 +415,15 @@ pub struct PokemonInstance {
     pub level: u32,
     pub experience: u32,
     pub individual_values: IndividualValues,
+    #[serde(default)]
+    pub effort_values: EffortValues,
     pub talent: Talent,
     pub nature: Nature,
     pub unique_id: String,             // 唯一 ID，用于防止重复
+    #[serde(default)]
+    pub gender: Gender,
+    #[serde(default)]
+    pub is_shiny: bool,                // 是否为异色
 }
 
 impl PokemonInstance {
@@ -214,11 +433,14 @@ impl PokemonInstance {
         PokemonInstance {
             species_id,
             level,
-            experience: level as u32 * 100,
+            experience: crate::data::pokemon_data::get_growth_rate(species_id).experience_for_level(level),
             individual_values: IndividualValues::random(),
+            effort_values: EffortValues::default(),
             talent: Talent::random(),
             nature: Nature::random(),
             unique_id: Uuid::new_v4().to_string(),
+            gender: Gender::random_for_species(species_id),
+            is_shiny: roll_shiny(crate::data::pokemon_data::get_shiny_odds()),
         }
     }
 
@@ -228,44 +450,20 @@ impl PokemonInstance {
         PokemonInstance {
             species_id,
             level,
-            experience: level as u32 * 100,
+            experience: crate::data::pokemon_data::get_growth_rate(species_id).experience_for_level(level),
             individual_values: IndividualValues::perfect(),
+            effort_values: EffortValues::default(),
             talent: Talent::Hidden,
             nature: Nature::random(),
             unique_id: Uuid::new_v4().to_string(),
+            gender: Gender::random_for_species(species_id),
+            is_shiny: roll_shiny(crate::data::pokemon_data::get_shiny_odds()),
         }
     }
 
     /// 获取属性加成倍数 (基于性格)
     pub fn get_nature_multipliers(&self) -> NatureMultipliers {
-        match self.nature {
-            Nature::Lonely => NatureMultipliers { attack: 1.1, defense: 0.9, ..Default::default() },
-            Nature::Brave => NatureMultipliers { attack: 1.1, speed: 0.9, ..Default::default() },
-            Nature::Adamant => NatureMultipliers { attack: 1.1, sp_attack: 0.9, ..Default::default() },
-            Nature::Naughty => NatureMultipliers { attack: 1.1, sp_defense: 0.9, ..Default::default() },
-
-            Nature::Bold => NatureMultipliers { defense: 1.1, attack: 0.9, ..Default::default() },
-            Nature::Relaxed => NatureMultipliers { defense: 1.1, speed: 0.9, ..Default::default() },
-            Nature::Impish => NatureMultipliers { defense: 1.1, sp_attack: 0.9, ..Default::default() },
-            Nature::Lax => NatureMultipliers { defense: 1.1, sp_defense: 0.9, ..Default::default() },
-
-            Nature::Timid => NatureMultipliers { speed: 1.1, attack: 0.9, ..Default::default() },
-            Nature::Hasty => NatureMultipliers { speed: 1.1, defense: 0.9, ..Default::default() },
-            Nature::Jolly => NatureMultipliers { speed: 1.1, sp_attack: 0.9, ..Default::default() },
-            Nature::Naive => NatureMultipliers { speed: 1.1, sp_defense: 0.9, ..Default::default() },
-
-            Nature::Modest => NatureMultipliers { sp_attack: 1.1, attack: 0.9, ..Default::default() },
-            Nature::Mild => NatureMultipliers { sp_attack: 1.1, defense: 0.9, ..Default::default() },
-            Nature::Rash => NatureMultipliers { sp_attack: 1.1, sp_defense: 0.9, ..Default::default() },
-            Nature::Quiet => NatureMultipliers { sp_attack: 1.1, speed: 0.9, ..Default::default() },
-
-            Nature::Calm => NatureMultipliers { sp_defense: 1.1, attack: 0.9, ..Default::default() },
-            Nature::Gentle => NatureMultipliers { sp_defense: 1.1, defense: 0.9, ..Default::default() },
-            Nature::Sassy => NatureMultipliers { sp_defense: 1.1, speed: 0.9, ..Default::default() },
-            Nature::Careful => NatureMultipliers { sp_defense: 1.1, sp_attack: 0.9, ..Default::default() },
-
-            _ => NatureMultipliers::default(),
-        }
+        self.nature.multipliers()
     }
 }
 
@@ -280,6 +478,21 @@ pub struct NatureMultipliers {
     pub speed: f32,
 }
 
+impl NatureMultipliers {
+    /// 按属性名获取倍数
+    pub fn get(&self, stat: &str) -> f32 {
+        match stat {
+            "hp" => self.hp,
+            "attack" => self.attack,
+            "defense" => self.defense,
+            "sp_attack" => self.sp_attack,
+            "sp_defense" => self.sp_defense,
+            "speed" => self.speed,
+            _ => 1.0,
+        }
+    }
+}
+
 impl Default for NatureMultipliers {
     fn default() -> Self {
         NatureMultipliers {
@@ -292,3 +505,44 @@ impl Default for NatureMultipliers {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_effort_values_per_stat_cap() {
+        let mut evs = EffortValues::default();
+        assert_eq!(evs.add("attack", 300), 252);
+        assert_eq!(evs.add("attack", 10), 0);
+        assert_eq!(evs.attack, 252);
+    }
+
+    #[test]
+    fn test_effort_values_total_cap() {
+        let mut evs = EffortValues::default();
+        evs.add("attack", 252);
+        evs.add("speed", 252);
+        assert_eq!(evs.add("hp", 10), 6);
+        assert_eq!(evs.total(), EffortValues::MAX_TOTAL);
+    }
+
+    #[test]
+    fn test_effort_values_reduce() {
+        let mut evs = EffortValues::default();
+        evs.add("defense", 15);
+        assert_eq!(evs.reduce("defense", 10), 10);
+        assert_eq!(evs.reduce("defense", 10), 5);
+        assert_eq!(evs.defense, 0);
+        assert_eq!(evs.reduce("unknown", 10), 0);
+    }
+
+    #[test]
+    fn test_gender_and_shiny_rolls() {
+        assert_eq!(Gender::random(None), Gender::Genderless);
+        assert_eq!(Gender::random(Some(0.0)), Gender::Male);
+        assert_eq!(Gender::random(Some(1.0)), Gender::Female);
+        assert!(!roll_shiny(0));
+        assert!(roll_shiny(1));
+    }
+}
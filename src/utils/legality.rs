@@ -0,0 +1,287 @@
+//! 宝可梦与存档的合法性检查
+//!
+//! 检查队伍、仓库和饲育屋中的每一只宝可梦，以及携带的蛋和饲育屋里等待领取的蛋:
+//! - 种类存在，属性与种族值与物种数据一致
+//! - 等级在 1-100 之间，经验值与等级相符，HP 不超过最大 HP
+//! - 个体值在 0-31 之间，努力值不超过上限
+//! - 招式存在、不重复、不超过 4 个，且能通过升级、招式机或教学习得
+//! - PP 不超过最大 PP，最大 PP 与招式数据一致
+//! - 携带的道具存在并且可以携带
+//! - 蛋中是进化链第一阶段的宝可梦，剩余步数不超过孵化所需步数
+//!
+//! 报告中的每一条问题都指向具体的宝可梦和字段，如
+//! `仓库 箱子 2 #3 (皮卡丘).individual_values.speed: 个体值 40 超出范围 0-31`。
+
+use crate::data::pokemon_data;
+use crate::game::friendship::MAX_FRIENDSHIP;
+use crate::game::learnset::MAX_MOVES;
+use crate::game::pokemon::MAX_LEVEL;
+use crate::game::{Egg, Item, Player, Pokemon};
+use crate::pokemon_generator::EffortValues;
+use std::collections::HashSet;
+use std::fmt;
+
+/// 个体值上限
+const MAX_IV: u32 = 31;
+
+/// 一条不合法的记录
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LegalityIssue {
+    /// 宝可梦所在位置，如 "队伍 #1 (皮卡丘)"
+    pub location: String,
+    /// 出问题的字段，如 "moves[2].pp"
+    pub field: String,
+    pub message: String,
+}
+
+impl fmt::Display for LegalityIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}: {}", self.location, self.field, self.message)
+    }
+}
+
+/// 合法性检查报告
+#[derive(Debug, Clone, Default)]
+pub struct LegalityReport {
+    /// 检查过的宝可梦数量
+    pub checked: usize,
+    pub issues: Vec<LegalityIssue>,
+}
+
+impl fmt::Display for LegalityReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_legal() {
+            return write!(f, "✓ 检查了 {} 只宝可梦，全部合法", self.checked);
+        }
+        writeln!(f, "✗ 检查了 {} 只宝可梦，发现 {} 个问题:", self.checked, self.issues.len())?;
+        for issue in &self.issues {
+            writeln!(f, "  - {}", issue)?;
+        }
+        Ok(())
+    }
+}
+
+/// 检查存档中的所有宝可梦 (队伍、仓库、饲育屋) 和蛋
+pub fn check_player(player: &Player) -> LegalityReport {
+    let mut report = LegalityReport::default();
+    for (i, pokemon) in player.pokemons.iter().enumerate() {
+        report.check(&format!("队伍 #{}", i + 1), pokemon);
+    }
+    for pokemon_box in &player.storage.boxes {
+        for (i, pokemon) in pokemon_box.pokemon.iter().enumerate() {
+            report.check(&format!("仓库 箱子 {} #{}", pokemon_box.box_id, i + 1), pokemon);
+        }
+    }
+    for (i, slot) in player.day_care.slots.iter().enumerate() {
+        report.check(&format!("饲育屋 #{}", i + 1), &slot.pokemon);
+    }
+    for (i, egg) in player.eggs.iter().enumerate() {
+        report.check_egg(&format!("蛋 #{}", i + 1), egg);
+    }
+    if let Some(egg) = &player.day_care.egg {
+        report.check_egg("饲育屋的蛋", egg);
+    }
+    report
+}
+
+impl LegalityReport {
+    pub fn is_legal(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// 检查一只蛋：按孵化后的宝可梦检查，再检查蛋特有的字段
+    fn check_egg(&mut self, location: &str, egg: &Egg) {
+        let species_id = egg.instance.species_id;
+        let pokemon = match pokemon_data::get_pokemon_from_instance(&egg.instance) {
+            Some(pokemon) => pokemon,
+            None => {
+                self.checked += 1;
+                self.issues.push(LegalityIssue {
+                    location: location.to_string(),
+                    field: "instance.species_id".to_string(),
+                    message: format!("不存在编号为 {} 的宝可梦", species_id),
+                });
+                return;
+            }
+        };
+        self.check(location, &pokemon);
+
+        let location = format!("{} ({})", location, pokemon.display_name());
+        if pokemon_data::get_base_species(species_id) != species_id {
+            self.issues.push(LegalityIssue {
+                location: location.clone(),
+                field: "instance.species_id".to_string(),
+                message: format!("蛋里只能是进化链第一阶段的宝可梦，{} 不是", pokemon.name),
+            });
+        }
+        let hatch_steps = pokemon_data::get_hatch_steps(species_id);
+        if egg.steps_remaining > hatch_steps {
+            self.issues.push(LegalityIssue {
+                location,
+                field: "steps_remaining".to_string(),
+                message: format!("剩余步数 {} 超过孵化所需的 {} 步", egg.steps_remaining, hatch_steps),
+            });
+        }
+    }
+
+    /// 检查一只宝可梦，问题记录在 location 之下
+    fn check(&mut self, location: &str, pokemon: &Pokemon) {
+        self.checked += 1;
+        let location = format!("{} ({})", location, pokemon.display_name());
+        let mut issue = |field: &str, message: String| {
+            self.issues.push(LegalityIssue { location: location.clone(), field: field.to_string(), message });
+        };
+
+        let species = match pokemon_data::get_pokemon_by_id(pokemon.id) {
+            Some(species) => species,
+            None => {
+                issue("id", format!("不存在编号为 {} 的宝可梦", pokemon.id));
+                return;
+            }
+        };
+        if pokemon.name != species.name {
+            issue("name", format!("种类名称 {} 与物种数据 {} 不符", pokemon.name, species.name));
+        }
+        if pokemon.pokemon_type != species.pokemon_type {
+            issue("pokemon_type", "属性与物种数据不符".to_string());
+        }
+        let base_stats = [
+            ("hp", pokemon.stats.hp, species.stats.hp),
+            ("attack", pokemon.stats.attack, species.stats.attack),
+            ("defense", pokemon.stats.defense, species.stats.defense),
+            ("sp_attack", pokemon.stats.sp_attack, species.stats.sp_attack),
+            ("sp_defense", pokemon.stats.sp_defense, species.stats.sp_defense),
+            ("speed", pokemon.stats.speed, species.stats.speed),
+        ];
+        for (stat, actual, expected) in base_stats {
+            if actual != expected {
+                issue(&format!("stats.{}", stat), format!("种族值 {} 与物种数据 {} 不符", actual, expected));
+            }
+        }
+
+        let level_is_legal = (1..=MAX_LEVEL).contains(&pokemon.level);
+        if !level_is_legal {
+            issue("level", format!("等级 {} 超出范围 1-{}", pokemon.level, MAX_LEVEL));
+        } else {
+            let floor = pokemon.growth_rate.experience_for_level(pokemon.level);
+            let ceiling = if pokemon.level < MAX_LEVEL {
+                pokemon.growth_rate.experience_for_level(pokemon.level + 1) - 1
+            } else {
+                floor
+            };
+            if !(floor..=ceiling).contains(&pokemon.experience) {
+                issue(
+                    "experience",
+                    format!("经验值 {} 与等级 {} 不符 (应在 {}-{} 之间)", pokemon.experience, pokemon.level, floor, ceiling),
+                );
+            }
+        }
+
+        for stat in EffortValues::STAT_KEYS {
+            let iv = pokemon.individual_values.get(stat);
+            if iv > MAX_IV {
+                issue(&format!("individual_values.{}", stat), format!("个体值 {} 超出范围 0-{}", iv, MAX_IV));
+            }
+            let ev = pokemon.effort_values.get(stat);
+            if ev > EffortValues::MAX_PER_STAT {
+                issue(
+                    &format!("effort_values.{}", stat),
+                    format!("努力值 {} 超过单项上限 {}", ev, EffortValues::MAX_PER_STAT),
+                );
+            }
+        }
+        if pokemon.effort_values.total() > EffortValues::MAX_TOTAL {
+            issue(
+                "effort_values",
+                format!("努力值总和 {} 超过上限 {}", pokemon.effort_values.total(), EffortValues::MAX_TOTAL),
+            );
+        }
+
+        if level_is_legal {
+            let (_, max_hp) = Pokemon::calculate_hp(
+                species.stats.hp,
+                pokemon.individual_values.hp,
+                pokemon.effort_values.hp,
+                pokemon.level,
+            );
+            if pokemon.max_hp != max_hp {
+                issue("max_hp", format!("最大 HP {} 与计算值 {} 不符", pokemon.max_hp, max_hp));
+            }
+        }
+        if pokemon.hp > pokemon.max_hp {
+            issue("hp", format!("HP {} 超过最大 HP {}", pokemon.hp, pokemon.max_hp));
+        }
+        if pokemon.friendship > MAX_FRIENDSHIP {
+            issue("friendship", format!("亲密度 {} 超过上限 {}", pokemon.friendship, MAX_FRIENDSHIP));
+        }
+        if let Some(item_id) = pokemon.held_item {
+            match Item::get(item_id) {
+                Some(item) if !item.can_be_held() => {
+                    issue("held_item", format!("{} 不能让宝可梦携带", item.name));
+                }
+                Some(_) => {}
+                None => issue("held_item", format!("不存在编号为 {} 的道具", item_id)),
+            }
+        }
+
+        if pokemon.moves.len() > MAX_MOVES {
+            issue("moves", format!("有 {} 个招式，最多只能有 {} 个", pokemon.moves.len(), MAX_MOVES));
+        }
+        let mut seen = HashSet::new();
+        for (i, mv) in pokemon.moves.iter().enumerate() {
+            let field = |name: &str| format!("moves[{}]{}", i, name);
+            let data = match pokemon_data::get_move_by_id(mv.id) {
+                Some(data) => data,
+                None => {
+                    issue(&field(".id"), format!("不存在编号为 {} 的招式", mv.id));
+                    continue;
+                }
+            };
+            if !seen.insert(mv.id) {
+                issue(&field(".id"), format!("招式 {} 重复", data.name));
+            }
+            if !pokemon_data::can_learn_move(pokemon.id, mv.id) {
+                issue(&field(".id"), format!("{} 学不会 {}", species.name, data.name));
+            }
+            if mv.max_pp != data.max_pp {
+                issue(&field(".max_pp"), format!("最大 PP {} 与招式数据 {} 不符", mv.max_pp, data.max_pp));
+            }
+            if mv.pp > mv.max_pp {
+                issue(&field(".pp"), format!("PP {} 超过最大 PP {}", mv.pp, mv.max_pp));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_issue_points_to_field() {
+        let issue = LegalityIssue {
+            location: "队伍 #1 (小电)".to_string(),
+            field: "individual_values.speed".to_string(),
+            message: "个体值 99 超出范围 0-31".to_string(),
+        };
+        assert_eq!(issue.to_string(), "队伍 #1 (小电).individual_values.speed: 个体值 99 超出范围 0-31");
+    }
+
+    #[test]
+    fn test_unknown_species_is_reported() {
+        // 测试中没有加载游戏数据，任何种类都查不到
+        let pokemon = Pokemon::new(
+            9999,
+            "不存在".to_string(),
+            (crate::game::PokemonType::Normal, None),
+            crate::game::Stat { hp: 1, attack: 1, defense: 1, sp_attack: 1, sp_defense: 1, speed: 1 },
+            45,
+        );
+        let mut report = LegalityReport::default();
+        report.check("队伍 #1", &pokemon);
+        assert!(!report.is_legal());
+        assert_eq!(report.checked, 1);
+        assert_eq!(report.issues[0].field, "id");
+        assert!(report.to_string().contains("发现 1 个问题"));
+    }
+}
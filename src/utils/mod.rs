@@ -1 +1,3 @@
 pub mod save;
+pub mod showdown;
+pub mod legality;
@@ -1,4 +1,5 @@
 use crate::game::Player;
+use crate::utils::legality::{self, LegalityReport};
 use std::fs;
 use std::path::Path;
 
@@ -13,14 +14,29 @@ impl SaveManager {
     }
 
     pub fn load_game(filename: &str) -> Result<Player, Box<dyn std::error::Error>> {
+        let player = Self::read_save(filename)?;
+        let report = legality::check_player(&player);
+        if !report.is_legal() {
+            println!("⚠ 存档中有不合法的宝可梦:\n{}", report);
+        }
+        println!("游戏已加载!");
+        Ok(player)
+    }
+
+    /// 只检查存档的合法性，不进入游戏
+    pub fn check_save(filename: &str) -> Result<LegalityReport, Box<dyn std::error::Error>> {
+        let player = Self::read_save(filename)?;
+        Ok(legality::check_player(&player))
+    }
+
+    fn read_save(filename: &str) -> Result<Player, Box<dyn std::error::Error>> {
         if !Path::new(filename).exists() {
             return Err("存档文件不存在".into());
         }
 
         let json = fs::read_to_string(filename)?;
-        let player = serde_json::from_str(&json)?;
-        println!("游戏已加载!");
-        Ok(player)
+        let player: Player = serde_json::from_str(&json)?;
+        Ok(player.migrate_from_old_save())
     }
 
     pub fn delete_save(filename: &str) -> Result<(), Box<dyn std::error::Error>> {
@@ -0,0 +1,453 @@
+//! Showdown 格式的宝可梦导入与导出
+//!
+//! 每只宝可梦一段，段与段之间用空行分隔:
+//!
+//! ```text
+//! 小电 (Pikachu) (M)
+//! Ability: Lightning Rod
+//! Level: 50
+//! Shiny: Yes
+//! Happiness: 255
+//! EVs: 252 Atk / 4 SpD / 252 Spe
+//! Jolly Nature
+//! IVs: 0 SpA
+//! - Thunderbolt
+//! - Quick Attack
+//! ```
+//!
+//! 种类、招式和道具优先使用英文名，导入时也接受中文名。
+//! Ability 一行写特性名，导入时必须是该种类的普通或隐藏特性，由此决定天赋 (也接受旧版导出的 Normal / Hidden)；
+//! "@ 道具" 为携带的道具，导入时必须是可以携带的道具。
+//! 导入的宝可梦视为交换得来，等级超过徽章允许的上限时可能不听指挥。
+
+use crate::data::pokemon_data;
+use crate::game::learnset::MAX_MOVES;
+use crate::game::pokemon::MAX_LEVEL;
+use crate::game::{Ability, Item, Pokemon};
+use crate::pokemon_generator::{EffortValues, Gender, IndividualValues, Nature, PokemonInstance, Talent};
+
+/// 导入的宝可梦记录的初训家
+pub const IMPORTED_TRAINER: &str = "Showdown";
+
+/// 属性键名与 Showdown 缩写
+const STAT_ABBREVIATIONS: [(&str, &str); 6] = [
+    ("hp", "HP"),
+    ("attack", "Atk"),
+    ("defense", "Def"),
+    ("sp_attack", "SpA"),
+    ("sp_defense", "SpD"),
+    ("speed", "Spe"),
+];
+
+/// 一只宝可梦的 Showdown 配置
+#[derive(Debug, Clone)]
+pub struct ShowdownSet {
+    pub species: String,
+    pub nickname: Option<String>,
+    pub gender: Option<Gender>,
+    pub item: Option<String>,
+    pub ability: Option<String>,
+    pub level: u32,
+    pub shiny: bool,
+    pub happiness: Option<u32>,
+    pub nature: Option<String>,
+    pub evs: EffortValues,
+    pub ivs: IndividualValues,
+    pub moves: Vec<String>,
+}
+
+impl ShowdownSet {
+    /// 从宝可梦生成配置
+    pub fn from_pokemon(pokemon: &Pokemon) -> ShowdownSet {
+        ShowdownSet {
+            species: pokemon_data::get_species_english_name(pokemon.id).unwrap_or_else(|| pokemon.name.clone()),
+            nickname: pokemon.nickname.clone(),
+            gender: match pokemon.gender {
+                Gender::Genderless => None,
+                gender => Some(gender),
+            },
+            item: pokemon
+                .held_item
+                .map(|id| Item::get(id).map_or_else(|| Item::name_of(id), |item| item.english_name)),
+            ability: Some(
+                Ability::of(pokemon)
+                    .map(|ability| ability.english_name)
+                    .unwrap_or_else(|| format!("{:?}", pokemon.talent)),
+            ),
+            level: pokemon.level,
+            shiny: pokemon.is_shiny,
+            happiness: Some(pokemon.friendship),
+            nature: Some(format!("{:?}", pokemon.nature)),
+            evs: pokemon.effort_values,
+            ivs: pokemon.individual_values,
+            moves: pokemon
+                .moves
+                .iter()
+                .map(|m| pokemon_data::get_move_english_name(m.id).unwrap_or_else(|| m.name.clone()))
+                .collect(),
+        }
+    }
+
+    /// 解析一段 Showdown 文本
+    pub fn parse(text: &str) -> Result<ShowdownSet, String> {
+        let mut lines = text.lines().map(|line| line.trim()).filter(|line| !line.is_empty());
+        let header = lines.next().ok_or("配置为空")?;
+
+        let (header, item) = match header.split_once(" @ ") {
+            Some((left, item)) => (left.trim(), Some(item.trim().to_string())),
+            None => (header, None),
+        };
+        let (header, gender) = if let Some(left) = header.strip_suffix(" (M)") {
+            (left, Some(Gender::Male))
+        } else if let Some(left) = header.strip_suffix(" (F)") {
+            (left, Some(Gender::Female))
+        } else {
+            (header, None)
+        };
+        let (nickname, species) = match (header.rfind(" ("), header.strip_suffix(')')) {
+            (Some(start), Some(_)) => (
+                Some(header[..start].trim().to_string()),
+                header[start + 2..header.len() - 1].trim().to_string(),
+            ),
+            _ => (None, header.trim().to_string()),
+        };
+        if species.is_empty() {
+            return Err("缺少宝可梦种类".to_string());
+        }
+
+        let mut set = ShowdownSet {
+            species,
+            nickname,
+            gender,
+            item,
+            ability: None,
+            level: MAX_LEVEL,
+            shiny: false,
+            happiness: None,
+            nature: None,
+            evs: EffortValues::default(),
+            ivs: IndividualValues::perfect(),
+            moves: Vec::new(),
+        };
+
+        for line in lines {
+            if let Some(name) = line.strip_prefix("- ") {
+                set.moves.push(name.trim().to_string());
+            } else if let Some(ability) = line.strip_prefix("Ability:") {
+                set.ability = Some(ability.trim().to_string());
+            } else if let Some(level) = line.strip_prefix("Level:") {
+                set.level = level.trim().parse().map_err(|_| format!("无效的等级: {}", level.trim()))?;
+            } else if let Some(shiny) = line.strip_prefix("Shiny:") {
+                set.shiny = shiny.trim().eq_ignore_ascii_case("yes");
+            } else if let Some(happiness) = line.strip_prefix("Happiness:") {
+                set.happiness = Some(
+                    happiness.trim().parse().map_err(|_| format!("无效的亲密度: {}", happiness.trim()))?,
+                );
+            } else if let Some(evs) = line.strip_prefix("EVs:") {
+                set.evs = parse_effort_values(evs)?;
+            } else if let Some(ivs) = line.strip_prefix("IVs:") {
+                set.ivs = parse_individual_values(ivs)?;
+            } else if let Some(nature) = line.strip_suffix(" Nature") {
+                set.nature = Some(nature.trim().to_string());
+            }
+            // 其他行 (如 Tera Type) 与本游戏无关，直接忽略
+        }
+
+        Ok(set)
+    }
+
+    /// 生成 Showdown 文本
+    pub fn to_text(&self) -> String {
+        let mut header = match &self.nickname {
+            Some(nickname) => format!("{} ({})", nickname, self.species),
+            None => self.species.clone(),
+        };
+        match self.gender {
+            Some(Gender::Male) => header.push_str(" (M)"),
+            Some(Gender::Female) => header.push_str(" (F)"),
+            _ => {}
+        }
+        if let Some(item) = &self.item {
+            header.push_str(&format!(" @ {}", item));
+        }
+
+        let mut lines = vec![header];
+        if let Some(ability) = &self.ability {
+            lines.push(format!("Ability: {}", ability));
+        }
+        lines.push(format!("Level: {}", self.level));
+        if self.shiny {
+            lines.push("Shiny: Yes".to_string());
+        }
+        if let Some(happiness) = self.happiness {
+            lines.push(format!("Happiness: {}", happiness));
+        }
+        let evs = format_stats(|stat| self.evs.get(stat), |value| value > 0);
+        if !evs.is_empty() {
+            lines.push(format!("EVs: {}", evs));
+        }
+        if let Some(nature) = &self.nature {
+            lines.push(format!("{} Nature", nature));
+        }
+        let ivs = format_stats(|stat| self.ivs.get(stat), |value| value < 31);
+        if !ivs.is_empty() {
+            lines.push(format!("IVs: {}", ivs));
+        }
+        for name in &self.moves {
+            lines.push(format!("- {}", name));
+        }
+        lines.join("\n")
+    }
+
+    /// 按本游戏的种类与招式数据校验配置，生成宝可梦
+    pub fn to_pokemon(&self) -> Result<Pokemon, String> {
+        let species_id = pokemon_data::find_species_id_by_name(&self.species)
+            .ok_or_else(|| format!("未知的宝可梦: {}", self.species))?;
+        if self.level == 0 || self.level > MAX_LEVEL {
+            return Err(format!("等级必须在 1-{} 之间", MAX_LEVEL));
+        }
+        if self.moves.len() > MAX_MOVES {
+            return Err(format!("最多只能有 {} 个招式", MAX_MOVES));
+        }
+
+        let mut instance = PokemonInstance::new(species_id, self.level);
+        instance.individual_values = self.ivs;
+        instance.effort_values = self.evs;
+        instance.is_shiny = self.shiny;
+        instance.talent = match self.ability.as_deref() {
+            None | Some("Normal") => Talent::Normal,
+            Some("Hidden") => Talent::Hidden,
+            Some(name) => [Talent::Normal, Talent::Hidden]
+                .into_iter()
+                .find(|&talent| {
+                    pokemon_data::get_ability_id(species_id, talent)
+                        .and_then(Ability::get)
+                        .is_some_and(|ability| ability.matches_name(name))
+                })
+                .ok_or_else(|| format!("{} 不会有特性 {}", self.species, name))?,
+        };
+        if let Some(nature) = &self.nature {
+            instance.nature = serde_json::from_value::<Nature>(serde_json::Value::String(nature.clone()))
+                .map_err(|_| format!("未知的性格: {}", nature))?;
+        }
+        instance.gender = match (self.gender, pokemon_data::get_female_ratio(species_id)) {
+            (None, _) => Gender::random_for_species(species_id),
+            (Some(_), None) => return Err(format!("{} 没有性别", self.species)),
+            (Some(Gender::Male), Some(ratio)) if ratio >= 1.0 => {
+                return Err(format!("{} 只有雌性", self.species))
+            }
+            (Some(Gender::Female), Some(ratio)) if ratio <= 0.0 => {
+                return Err(format!("{} 只有雄性", self.species))
+            }
+            (Some(gender), Some(_)) => gender,
+        };
+
+        let mut pokemon = pokemon_data::get_pokemon_from_instance(&instance)
+            .ok_or_else(|| format!("无法创建宝可梦: {}", self.species))?;
+
+        if !self.moves.is_empty() {
+            let mut moves = Vec::new();
+            for name in &self.moves {
+                let mv = pokemon_data::get_move_by_name(name)
+                    .ok_or_else(|| format!("未知的招式: {}", name))?;
+                if moves.iter().any(|m: &crate::game::Move| m.id == mv.id) {
+                    return Err(format!("招式重复: {}", name));
+                }
+                if !pokemon_data::can_learn_move(species_id, mv.id) {
+                    return Err(format!("{} 学不会 {}", pokemon.name, name));
+                }
+                moves.push(mv);
+            }
+            pokemon.moves = moves;
+        }
+
+        if let Some(name) = &self.item {
+            let item = Item::find_by_name(name).ok_or_else(|| format!("未知的道具: {}", name))?;
+            if !item.can_be_held() {
+                return Err(format!("{} 不能让宝可梦携带", item.name));
+            }
+            pokemon.held_item = Some(item.id);
+        }
+
+        pokemon.original_trainer = Some(IMPORTED_TRAINER.to_string());
+        if let Some(nickname) = &self.nickname {
+            pokemon.set_nickname(nickname)?;
+        }
+        if let Some(happiness) = self.happiness {
+            if happiness > crate::game::friendship::MAX_FRIENDSHIP {
+                return Err(format!("亲密度不能超过 {}", crate::game::friendship::MAX_FRIENDSHIP));
+            }
+            pokemon.friendship = happiness;
+        }
+        Ok(pokemon)
+    }
+}
+
+/// 把多段 Showdown 文本拆分为配置
+pub fn parse_team(text: &str) -> Result<Vec<ShowdownSet>, String> {
+    let mut sets = Vec::new();
+    let mut block = Vec::new();
+    for line in text.lines().chain(std::iter::once("")) {
+        if line.trim().is_empty() {
+            if !block.is_empty() {
+                let set = ShowdownSet::parse(&block.join("\n"))
+                    .map_err(|e| format!("第 {} 只宝可梦: {}", sets.len() + 1, e))?;
+                sets.push(set);
+                block.clear();
+            }
+        } else {
+            block.push(line);
+        }
+    }
+    Ok(sets)
+}
+
+/// 导出一只宝可梦
+pub fn export_pokemon(pokemon: &Pokemon) -> String {
+    ShowdownSet::from_pokemon(pokemon).to_text()
+}
+
+/// 导出多只宝可梦 (队伍或箱子)，段与段之间空一行
+pub fn export_team(team: &[Pokemon]) -> String {
+    team.iter().map(export_pokemon).collect::<Vec<_>>().join("\n\n")
+}
+
+/// 导入 Showdown 文本，任何一只校验失败时整体失败
+pub fn import_team(text: &str) -> Result<Vec<Pokemon>, String> {
+    let sets = parse_team(text)?;
+    if sets.is_empty() {
+        return Err("没有找到宝可梦".to_string());
+    }
+    sets.iter()
+        .enumerate()
+        .map(|(i, set)| set.to_pokemon().map_err(|e| format!("第 {} 只宝可梦: {}", i + 1, e)))
+        .collect()
+}
+
+/// 按 "252 Atk / 4 SpD" 的格式列出满足条件的属性
+fn format_stats(value_of: impl Fn(&str) -> u32, include: impl Fn(u32) -> bool) -> String {
+    STAT_ABBREVIATIONS
+        .iter()
+        .map(|(stat, abbreviation)| (value_of(stat), abbreviation))
+        .filter(|(value, _)| include(*value))
+        .map(|(value, abbreviation)| format!("{} {}", value, abbreviation))
+        .collect::<Vec<_>>()
+        .join(" / ")
+}
+
+/// 解析 "252 Atk / 4 SpD" 为 (属性键名, 数值) 列表
+fn parse_stat_list(text: &str) -> Result<Vec<(&'static str, u32)>, String> {
+    text.split('/')
+        .map(|part| part.trim())
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let (value, abbreviation) = part
+                .split_once(' ')
+                .ok_or_else(|| format!("无法解析: {}", part))?;
+            let value = value.parse::<u32>().map_err(|_| format!("无效的数值: {}", part))?;
+            let stat = STAT_ABBREVIATIONS
+                .iter()
+                .find(|(_, abbr)| abbr.eq_ignore_ascii_case(abbreviation.trim()))
+                .map(|(stat, _)| *stat)
+                .ok_or_else(|| format!("未知的属性: {}", abbreviation.trim()))?;
+            Ok((stat, value))
+        })
+        .collect()
+}
+
+fn parse_effort_values(text: &str) -> Result<EffortValues, String> {
+    let mut evs = EffortValues::default();
+    let mut total = 0;
+    for (stat, value) in parse_stat_list(text)? {
+        if value > EffortValues::MAX_PER_STAT {
+            return Err(format!("单项努力值不能超过 {}", EffortValues::MAX_PER_STAT));
+        }
+        total += value;
+        evs.add(stat, value);
+    }
+    if total > EffortValues::MAX_TOTAL {
+        return Err(format!("努力值总和不能超过 {}", EffortValues::MAX_TOTAL));
+    }
+    Ok(evs)
+}
+
+/// 未列出的个体值视为 31
+fn parse_individual_values(text: &str) -> Result<IndividualValues, String> {
+    let mut ivs = IndividualValues::perfect();
+    for (stat, value) in parse_stat_list(text)? {
+        if value > 31 {
+            return Err("个体值不能超过 31".to_string());
+        }
+        ivs.set(stat, value);
+    }
+    Ok(ivs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PASTE: &str = "小电 (Pikachu) (F) @ Light Ball
+Ability: Lightning Rod
+Level: 50
+Shiny: Yes
+Happiness: 255
+EVs: 252 Atk / 4 SpD / 252 Spe
+Jolly Nature
+IVs: 0 SpA
+- Thunderbolt
+- Quick Attack
+
+Bulbasaur
+Level: 5
+- Tackle";
+
+    #[test]
+    fn test_parse_team() {
+        let sets = parse_team(PASTE).unwrap();
+        assert_eq!(sets.len(), 2);
+
+        let pikachu = &sets[0];
+        assert_eq!(pikachu.species, "Pikachu");
+        assert_eq!(pikachu.nickname.as_deref(), Some("小电"));
+        assert_eq!(pikachu.gender, Some(Gender::Female));
+        assert_eq!(pikachu.item.as_deref(), Some("Light Ball"));
+        assert_eq!(pikachu.level, 50);
+        assert!(pikachu.shiny);
+        assert_eq!(pikachu.happiness, Some(255));
+        assert_eq!(pikachu.nature.as_deref(), Some("Jolly"));
+        assert_eq!(pikachu.evs.attack, 252);
+        assert_eq!(pikachu.evs.sp_defense, 4);
+        assert_eq!(pikachu.ivs.sp_attack, 0);
+        assert_eq!(pikachu.ivs.speed, 31);
+        assert_eq!(pikachu.moves, vec!["Thunderbolt", "Quick Attack"]);
+
+        let bulbasaur = &sets[1];
+        assert_eq!(bulbasaur.species, "Bulbasaur");
+        assert_eq!(bulbasaur.nickname, None);
+        assert_eq!(bulbasaur.gender, None);
+        assert_eq!(bulbasaur.ivs.total(), 186);
+    }
+
+    #[test]
+    fn test_text_roundtrip() {
+        let sets = parse_team(PASTE).unwrap();
+        let text = sets[0].to_text();
+        assert!(text.starts_with("小电 (Pikachu) (F) @ Light Ball"));
+        assert!(text.contains("EVs: 252 Atk / 4 SpD / 252 Spe"));
+        assert!(text.contains("IVs: 0 SpA"));
+
+        let reparsed = ShowdownSet::parse(&text).unwrap();
+        assert_eq!(reparsed.moves, sets[0].moves);
+        assert_eq!(reparsed.evs, sets[0].evs);
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_stats() {
+        assert!(parse_team("Pikachu\nEVs: 300 Atk").is_err());
+        assert!(parse_team("Pikachu\nEVs: 252 Atk / 252 Def / 252 Spe").is_err());
+        assert!(parse_team("Pikachu\nIVs: 32 Spe").is_err());
+        assert!(parse_team("Pikachu\nEVs: 4 Luck").is_err());
+        assert!(parse_team("Pikachu\nLevel: high").is_err());
+    }
+}
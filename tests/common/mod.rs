@@ -0,0 +1,18 @@
+// 集成测试共用的辅助函数
+#![allow(dead_code)]
+
+use poke::game::{Pokemon, PokemonType, Stat};
+use std::sync::Once;
+
+static LOAD_DATA: Once = Once::new();
+
+/// 加载 assets 中的游戏数据 (整个测试进程只加载一次)
+pub fn load_game_data() {
+    LOAD_DATA.call_once(|| poke::data::loader::load_all_data().expect("无法加载游戏数据"));
+}
+
+/// 测试用的 Lv.1 皮卡丘 (不依赖 species.json)
+pub fn create_pikachu() -> Pokemon {
+    let stats = Stat { hp: 35, attack: 55, defense: 40, sp_attack: 50, sp_defense: 50, speed: 90 };
+    Pokemon::new(25, "皮卡丘".to_string(), (PokemonType::Electric, None), stats, 190)
+}
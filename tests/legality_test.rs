@@ -0,0 +1,102 @@
+// 合法性检查测试 (按 assets 中的种类、招式与道具数据检查)
+
+mod common;
+
+use common::load_game_data;
+use poke::data::pokemon_data;
+use poke::game::{Egg, Player, Pokemon};
+use poke::pokemon_generator::PokemonInstance;
+use poke::utils::legality::{check_player, LegalityReport};
+
+fn player_with(pokemon: Pokemon) -> Player {
+    let mut player = Player::new("测试".to_string());
+    player.add_pokemon(pokemon);
+    player
+}
+
+fn fields(report: &LegalityReport) -> Vec<&str> {
+    report.issues.iter().map(|issue| issue.field.as_str()).collect()
+}
+
+#[test]
+fn test_generated_pokemon_is_legal() {
+    load_game_data();
+    let report = check_player(&player_with(pokemon_data::get_pokemon_by_id(25).unwrap()));
+    assert!(report.is_legal(), "{}", report);
+    assert_eq!(report.checked, 1);
+}
+
+#[test]
+fn test_individual_values_out_of_range() {
+    load_game_data();
+    let mut pokemon = pokemon_data::get_pokemon_by_id(25).unwrap();
+    pokemon.individual_values.speed = 40;
+
+    let report = check_player(&player_with(pokemon));
+    assert!(fields(&report).contains(&"individual_values.speed"));
+    assert!(report.to_string().contains("队伍 #1 (皮卡丘).individual_values.speed: 个体值 40 超出范围 0-31"));
+}
+
+#[test]
+fn test_unlearnable_move_is_reported() {
+    load_game_data();
+    let mut pokemon = pokemon_data::get_pokemon_by_id(25).unwrap();
+    pokemon.moves.push(pokemon_data::get_move_by_id(22).unwrap());
+
+    let report = check_player(&player_with(pokemon));
+    let issue = report.issues.iter().find(|issue| issue.message.contains("学不会")).unwrap();
+    assert!(issue.field.starts_with("moves["));
+    assert!(issue.message.contains("藤鞭"));
+}
+
+#[test]
+fn test_pp_above_cap_is_reported() {
+    load_game_data();
+    let mut pokemon = pokemon_data::get_pokemon_by_id(25).unwrap();
+    pokemon.moves[0].pp = pokemon.moves[0].max_pp + 1;
+    pokemon.moves[0].max_pp += 1;
+
+    let report = check_player(&player_with(pokemon));
+    assert_eq!(fields(&report), vec!["moves[0].max_pp"]);
+
+    let mut pokemon = pokemon_data::get_pokemon_by_id(25).unwrap();
+    pokemon.moves[0].pp = pokemon.moves[0].max_pp + 1;
+    let report = check_player(&player_with(pokemon));
+    assert_eq!(fields(&report), vec!["moves[0].pp"]);
+}
+
+#[test]
+fn test_held_item_must_be_holdable() {
+    load_game_data();
+    let mut pokemon = pokemon_data::get_pokemon_by_id(25).unwrap();
+    pokemon.held_item = Some(31);
+    assert!(check_player(&player_with(pokemon.clone())).is_legal());
+
+    pokemon.held_item = Some(poke::game::item::POTION_ID);
+    assert_eq!(fields(&check_player(&player_with(pokemon.clone()))), vec!["held_item"]);
+
+    pokemon.held_item = Some(9999);
+    assert_eq!(fields(&check_player(&player_with(pokemon))), vec!["held_item"]);
+}
+
+#[test]
+fn test_eggs_are_checked() {
+    load_game_data();
+    let mut player = Player::new("测试".to_string());
+    player.eggs.push(Egg::new(PokemonInstance::new(172, 1)));
+    assert!(check_player(&player).is_legal(), "{}", check_player(&player));
+
+    // 皮卡丘不是进化链的第一阶段，个体值也超出范围
+    let mut instance = PokemonInstance::new(25, 1);
+    instance.individual_values.attack = 99;
+    player.eggs.push(Egg { instance, steps_remaining: u32::MAX });
+    player.day_care.egg = Some(Egg::new(PokemonInstance::new(9999, 1)));
+
+    let report = check_player(&player);
+    assert_eq!(report.checked, 3);
+    assert_eq!(
+        fields(&report),
+        vec!["individual_values.attack", "instance.species_id", "steps_remaining", "instance.species_id"]
+    );
+    assert!(report.issues[3].location.starts_with("饲育屋的蛋"));
+}
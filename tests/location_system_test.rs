@@ -1,17 +1,23 @@
 // Phase 7: Location System Tests
 // Tests for location binding system, environment bonuses, unlock conditions, and wild Pokémon
 
+mod common;
+
+use common::load_game_data;
 use poke::game::{
-    Player, EnvironmentType, EnvironmentBonus, WildPokemonSpawn, WildPokemonEncounter,
+    Player, EncounterMethod, EncounterRateModifier, EnvironmentType, EnvironmentBonus, WildPokemonSpawn,
+    WildPokemonEncounter,
 };
 use poke::game::player::Badge;
-use poke::data::locations_data;
+use poke::data::{locations_data, pokemon_data};
 use poke::pokemon_generator::generator::PokemonStats;
+use poke::pokemon_generator::Talent;
 
 // ==================== Location System Tests (Task 7.1) ====================
 
 #[test]
 fn test_location_creation() {
+    load_game_data();
     let locations = locations_data::get_all_locations();
     assert!(!locations.is_empty(), "Should have at least one location");
     assert_eq!(locations.len(), 10, "Should have exactly 10 locations");
@@ -19,6 +25,7 @@ fn test_location_creation() {
 
 #[test]
 fn test_pallet_town_exists() {
+    load_game_data();
     let pallet_town = locations_data::get_location_by_id(101);
     assert!(pallet_town.is_some(), "Pallet Town should exist");
 
@@ -29,6 +36,7 @@ fn test_pallet_town_exists() {
 
 #[test]
 fn test_location_connections() {
+    load_game_data();
     let pallet_town = locations_data::get_location_by_id(101).unwrap();
     assert!(!pallet_town.connected_locations.is_empty(), "Pallet Town should have connections");
 
@@ -41,6 +49,7 @@ fn test_location_connections() {
 
 #[test]
 fn test_location_unlock_requirement_default() {
+    load_game_data();
     let pallet_town = locations_data::get_location_by_id(101).unwrap();
     assert_eq!(
         pallet_town.unlock_requirement.required_level, 1,
@@ -54,6 +63,7 @@ fn test_location_unlock_requirement_default() {
 
 #[test]
 fn test_location_unlock_requirement_progression() {
+    load_game_data();
     // Cerulean City requires Lv15 + 1 badge
     let cerulean = locations_data::get_location_by_id(105).unwrap();
     assert_eq!(cerulean.unlock_requirement.required_level, 15);
@@ -62,6 +72,7 @@ fn test_location_unlock_requirement_progression() {
 
 #[test]
 fn test_wild_pokemon_pool_not_empty() {
+    load_game_data();
     let all_locations = locations_data::get_all_locations();
     let locations_with_pokemon: Vec<_> = all_locations
         .iter()
@@ -88,6 +99,7 @@ fn test_encounter_rate_valid_range() {
 
 #[test]
 fn test_environment_bonus_types() {
+    load_game_data();
     let all_locations = locations_data::get_all_locations();
     let mut environments = Vec::new();
     for location in &all_locations {
@@ -101,6 +113,7 @@ fn test_environment_bonus_types() {
 
 #[test]
 fn test_npc_trainers_assigned() {
+    load_game_data();
     let all_locations = locations_data::get_all_locations();
     let locations_with_npcs: Vec<_> = all_locations
         .iter()
@@ -184,6 +197,7 @@ fn test_pokemon_stats_apply_environment_bonus() {
 
 #[test]
 fn test_player_can_unlock_starting_location() {
+    load_game_data();
     let mut player = Player::new("Test".to_string());
     let pallet_town = locations_data::get_location_by_id(101).unwrap();
 
@@ -192,6 +206,7 @@ fn test_player_can_unlock_starting_location() {
 
 #[test]
 fn test_player_cannot_unlock_without_level() {
+    load_game_data();
     let player = Player::new("Test".to_string());
     // Cerulean City requires Lv15
     let cerulean = locations_data::get_location_by_id(105).unwrap();
@@ -201,6 +216,7 @@ fn test_player_cannot_unlock_without_level() {
 
 #[test]
 fn test_player_unlock_location_with_sufficient_level() {
+    load_game_data();
     let mut player = Player::new("Test".to_string());
 
     // Add a high-level Pokémon
@@ -220,6 +236,7 @@ fn test_player_unlock_location_with_sufficient_level() {
 
 #[test]
 fn test_player_unlock_location_with_badges() {
+    load_game_data();
     let mut player = Player::new("Test".to_string());
 
     // Add a high-level Pokémon
@@ -255,6 +272,7 @@ fn test_player_location_state_visited_tracking() {
 
 #[test]
 fn test_player_get_max_level() {
+    load_game_data();
     let mut player = Player::new("Test".to_string());
     let starter = poke::data::pokemon_data::get_pokemon_by_id(25).unwrap();
     player.add_pokemon(starter);
@@ -265,6 +283,7 @@ fn test_player_get_max_level() {
 
 #[test]
 fn test_player_check_new_unlocks() {
+    load_game_data();
     let mut player = Player::new("Test".to_string());
     let starter = poke::data::pokemon_data::get_pokemon_by_id(25).unwrap();
     player.add_pokemon(starter);
@@ -288,12 +307,16 @@ fn test_wild_pokemon_pool_weighted_selection() {
             spawn_rate: 50.0,
             level_min: 1,
             level_max: 5,
+            times: Vec::new(),
+            method: EncounterMethod::Walk,
         },
         WildPokemonSpawn {
             pokemon_id: 27, // Sandshrew
             spawn_rate: 50.0,
             level_min: 1,
             level_max: 5,
+            times: Vec::new(),
+            method: EncounterMethod::Walk,
         },
     ];
 
@@ -323,6 +346,8 @@ fn test_wild_pokemon_level_range() {
         spawn_rate: 100.0,
         level_min: 10,
         level_max: 15,
+        times: Vec::new(),
+        method: EncounterMethod::Walk,
     }];
 
     for _ in 0..50 {
@@ -339,6 +364,8 @@ fn test_wild_pokemon_encounter_preview_generation() {
         spawn_rate: 100.0,
         level_min: 5,
         level_max: 5,
+        times: Vec::new(),
+        method: EncounterMethod::Walk,
     }];
 
     if let Ok(wild_pokemon) = WildPokemonEncounter::generate_wild_pokemon(&pool) {
@@ -355,10 +382,29 @@ fn test_wild_pokemon_encounter_preview_generation() {
     }
 }
 
+#[test]
+fn test_lead_ability_changes_encounter_rate() {
+    load_game_data();
+    let mut player = Player::new("测试".to_string());
+    let mut growlithe = pokemon_data::get_pokemon_by_id(58).unwrap();
+    growlithe.talent = Talent::Normal;
+    player.add_pokemon(growlithe);
+
+    // 卡拉卡拉的普通特性威吓让遭遇率减半
+    let modifiers = EncounterRateModifier::for_player(&player);
+    assert_eq!(modifiers.len(), 1);
+    assert_eq!(modifiers[0].source, "威吓");
+    assert!((EncounterRateModifier::apply(0.4, &modifiers) - 0.2).abs() < 1e-6);
+
+    player.pokemons[0].talent = Talent::Hidden;
+    assert!(EncounterRateModifier::for_player(&player).is_empty());
+}
+
 // ==================== Integration Tests (Task 7.2) ====================
 
 #[test]
 fn test_game_progression_sequence() {
+    load_game_data();
     let mut player = Player::new("TestPlayer".to_string());
     let starter = poke::data::pokemon_data::get_pokemon_by_id(25).unwrap();
     player.add_pokemon(starter);
@@ -376,6 +422,7 @@ fn test_game_progression_sequence() {
 
 #[test]
 fn test_location_unlock_progression() {
+    load_game_data();
     let mut player = Player::new("TestPlayer".to_string());
     let starter = poke::data::pokemon_data::get_pokemon_by_id(25).unwrap();
     player.add_pokemon(starter);
@@ -397,6 +444,7 @@ fn test_location_unlock_progression() {
 
 #[test]
 fn test_encounter_system_respects_location_pool() {
+    load_game_data();
     let pallet_town = locations_data::get_location_by_id(101).unwrap();
 
     // Generate multiple encounters and verify they're from Pallet Town's pool
@@ -412,6 +460,7 @@ fn test_encounter_system_respects_location_pool() {
 
 #[test]
 fn test_environment_bonus_applied_in_preview() {
+    load_game_data();
     let pallet_town = locations_data::get_location_by_id(101).unwrap();
 
     if let Ok(wild_pokemon) = WildPokemonEncounter::generate_wild_pokemon(&pallet_town.wild_pokemon_pool) {
@@ -431,6 +480,7 @@ fn test_environment_bonus_applied_in_preview() {
 
 #[test]
 fn test_multiple_location_connections_network() {
+    load_game_data();
     let locations = locations_data::get_all_locations();
 
     // Build a connectivity map
@@ -455,6 +505,7 @@ fn test_multiple_location_connections_network() {
 
 #[test]
 fn test_player_migration_from_old_save() {
+    load_game_data();
     let mut old_player = Player::new("OldPlayer".to_string());
     let starter = poke::data::pokemon_data::get_pokemon_by_id(25).unwrap();
     old_player.add_pokemon(starter);
@@ -474,6 +525,7 @@ fn test_player_migration_from_old_save() {
 
 #[test]
 fn test_player_migration_preserves_team() {
+    load_game_data();
     let mut old_player = Player::new("OldPlayer".to_string());
     let starter = poke::data::pokemon_data::get_pokemon_by_id(25).unwrap();
     old_player.add_pokemon(starter);
@@ -492,25 +544,55 @@ fn test_player_migration_preserves_team() {
     assert_eq!(migrated_player.pokemons[0].experience, 5000);
 }
 
+#[test]
+fn test_player_migration_converts_linear_experience() {
+    let mut old_player = Player::new("OldPlayer".to_string());
+    old_player.add_pokemon(common::create_pikachu());
+    old_player.pokemons[0].level = 10;
+    // 旧存档: Lv.10 的经验值在 900 到 1000 之间，当前进度一半
+    old_player.pokemons[0].experience = 950;
+    old_player.save_version = 0;
+
+    let migrated_player = old_player.migrate_from_old_save();
+    let pikachu = &migrated_player.pokemons[0];
+
+    assert_eq!(migrated_player.save_version, poke::game::player::SAVE_VERSION);
+    assert_eq!(pikachu.level, 10);
+    assert_eq!(pikachu.growth_rate, poke::game::GrowthRate::MediumFast);
+    // 较快曲线: Lv.10 = 1000, Lv.11 = 1331
+    assert_eq!(pikachu.experience, 1000 + 331 / 2);
+}
+
 #[test]
 fn test_player_migration_preserves_items() {
+    load_game_data();
     let mut old_player = Player::new("OldPlayer".to_string());
-    old_player.items.insert("Poké Ball".to_string(), 20);
     old_player.money = 5000;
 
-    // Simulate old save
+    // Simulate old save: the bag is keyed by item name
     old_player.location_state.current_location_id = 0;
+    old_player.save_version = 1;
+    let mut json = serde_json::to_value(&old_player).unwrap();
+    json["items"] = serde_json::json!({ "Poké Ball": 20, "恢复药": 3 });
+    let old_player: Player = serde_json::from_value(json).unwrap();
 
     // Migrate
     let migrated_player = old_player.migrate_from_old_save();
 
-    // Verify items and money are preserved
-    assert_eq!(migrated_player.items.get("Poké Ball"), Some(&20));
+    // Verify items and money are preserved, now keyed by item ID
+    assert_eq!(migrated_player.items.count(poke::game::item::POKE_BALL_ID), 20);
+    assert_eq!(migrated_player.items.count(poke::game::item::POTION_ID), 3);
     assert_eq!(migrated_player.money, 5000);
+
+    // The migrated bag round-trips through a new save unchanged
+    let saved = serde_json::to_string(&migrated_player).unwrap();
+    let reloaded: Player = serde_json::from_str(&saved).unwrap();
+    assert_eq!(reloaded.items.count(poke::game::item::POKE_BALL_ID), 20);
 }
 
 #[test]
 fn test_player_migration_auto_unlock_locations() {
+    load_game_data();
     let mut old_player = Player::new("OldPlayer".to_string());
     let starter = poke::data::pokemon_data::get_pokemon_by_id(25).unwrap();
     old_player.add_pokemon(starter);
@@ -529,6 +611,7 @@ fn test_player_migration_auto_unlock_locations() {
 
 #[test]
 fn test_player_migration_idempotent() {
+    load_game_data();
     let player = Player::new("TestPlayer".to_string());
     let starter = poke::data::pokemon_data::get_pokemon_by_id(25).unwrap();
     let mut player = player;
@@ -545,6 +628,7 @@ fn test_player_migration_idempotent() {
 
 #[test]
 fn test_backward_compatibility_new_player_same_as_migrated() {
+    load_game_data();
     // Create a new player the normal way
     let mut new_player = Player::new("NewPlayer".to_string());
     let starter = poke::data::pokemon_data::get_pokemon_by_id(25).unwrap();
@@ -267,7 +267,8 @@ mod pokemon_generator_tests {
         let pokemon = PokemonInstance::new(25, 5);
         assert_eq!(pokemon.species_id, 25);
         assert_eq!(pokemon.level, 5);
-        assert_eq!(pokemon.experience, 500);
+        // 皮卡丘为较快经验曲线: Lv.5 = 5³
+        assert_eq!(pokemon.experience, 125);
     }
 
     #[test]
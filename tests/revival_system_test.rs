@@ -1,5 +1,11 @@
 // 复活系统测试
 
+mod common;
+
+use common::{create_pikachu, load_game_data};
+use poke::game::item::{POTION_ID, REVIVE_ID};
+use poke::game::Player;
+
 #[test]
 fn test_pokemon_revive_basic() {
     // 测试宝可梦复活的基本功能
@@ -122,3 +128,40 @@ fn test_multiple_pokemon_revival() {
     money -= total_cost;
     assert_eq!(money, 400);
 }
+
+#[test]
+fn test_revive_item_rejected_on_healthy_pokemon() {
+    load_game_data();
+    let mut player = Player::new("测试".to_string());
+    player.add_pokemon(create_pikachu());
+    player.pokemons[0].hp = player.pokemons[0].max_hp * 9 / 10;
+    let hp = player.pokemons[0].hp;
+    player.add_item(REVIVE_ID, 1);
+    let revives = player.item_count(REVIVE_ID);
+
+    assert!(player.revive_pokemon_with_item(0, REVIVE_ID).is_err());
+    assert_eq!(player.pokemons[0].hp, hp);
+    assert_eq!(player.item_count(REVIVE_ID), revives);
+
+    player.pokemons[0].hp = 0;
+    assert!(player.revive_pokemon_with_item(0, REVIVE_ID).is_ok());
+    assert!(player.pokemons[0].hp > 0);
+    assert_eq!(player.item_count(REVIVE_ID), revives - 1);
+}
+
+#[test]
+fn test_healing_item_rejected_at_full_hp() {
+    load_game_data();
+    let mut player = Player::new("测试".to_string());
+    player.add_pokemon(create_pikachu());
+    player.add_item(POTION_ID, 1);
+    let potions = player.item_count(POTION_ID);
+
+    assert!(player.revive_pokemon_with_item(0, POTION_ID).unwrap_err().contains("满"));
+    assert_eq!(player.item_count(POTION_ID), potions);
+
+    player.pokemons[0].hp -= 1;
+    assert!(player.revive_pokemon_with_item(0, POTION_ID).is_ok());
+    assert_eq!(player.pokemons[0].hp, player.pokemons[0].max_hp);
+    assert_eq!(player.item_count(POTION_ID), potions - 1);
+}
@@ -0,0 +1,63 @@
+// Showdown 导入测试 (按 assets 中的种类、招式与道具数据校验)
+
+mod common;
+
+use common::load_game_data;
+use poke::game::Ability;
+use poke::pokemon_generator::Talent;
+use poke::utils::showdown::{import_team, ShowdownSet};
+
+#[test]
+fn test_to_pokemon_validates_against_loaded_data() {
+    load_game_data();
+
+    let set = ShowdownSet::parse("小电 (Pikachu) (F) @ Cleanse Tag\nLevel: 30\n- Thunderbolt\n- Quick Attack").unwrap();
+    let pokemon = set.to_pokemon().unwrap();
+    assert_eq!(pokemon.id, 25);
+    assert_eq!(pokemon.level, 30);
+    assert_eq!(pokemon.display_name(), "小电");
+    assert_eq!(pokemon.held_item, Some(42));
+    assert_eq!(pokemon.moves.iter().map(|m| m.id).collect::<Vec<_>>(), vec![85, 98]);
+
+    // 中文名同样可以识别
+    assert_eq!(ShowdownSet::parse("皮卡丘\nLevel: 5").unwrap().to_pokemon().unwrap().id, 25);
+
+    assert!(import_team("Mewtwo\nLevel: 70").unwrap_err().contains("未知的宝可梦"));
+    assert!(import_team("Pikachu\nLevel: 101").unwrap_err().contains("等级"));
+    assert!(import_team("Pikachu\nLevel: 0").unwrap_err().contains("等级"));
+    assert!(import_team("Pikachu\n- Hyper Beam").unwrap_err().contains("未知的招式"));
+    assert!(import_team("Bulbasaur\n- Thunderbolt").unwrap_err().contains("学不会"));
+    assert!(import_team("Pikachu\n- Quick Attack\n- Quick Attack").unwrap_err().contains("招式重复"));
+}
+
+#[test]
+fn test_held_item_import_and_export() {
+    load_game_data();
+
+    assert!(import_team("Pikachu @ Light Ball").unwrap_err().contains("未知的道具"));
+    assert!(import_team("Pikachu @ Potion").unwrap_err().contains("不能让宝可梦携带"));
+
+    let pokemon = ShowdownSet::parse("Pikachu @ 不变之石").unwrap().to_pokemon().unwrap();
+    assert_eq!(pokemon.held_item, Some(31));
+
+    let exported = ShowdownSet::from_pokemon(&pokemon).to_text();
+    assert!(exported.starts_with("Pikachu"));
+    assert!(exported.lines().next().unwrap().ends_with("@ Everstone"));
+    assert_eq!(import_team(&exported).unwrap()[0].held_item, Some(31));
+}
+
+#[test]
+fn test_ability_sets_talent() {
+    load_game_data();
+
+    let pokemon = ShowdownSet::parse("Pikachu\nAbility: Lightning Rod").unwrap().to_pokemon().unwrap();
+    assert_eq!(pokemon.talent, Talent::Hidden);
+    assert_eq!(Ability::of(&pokemon).unwrap().name, "避雷针");
+    assert_eq!(import_team("Pikachu\nAbility: 静电").unwrap()[0].talent, Talent::Normal);
+    assert_eq!(import_team("Pikachu\nAbility: Hidden").unwrap()[0].talent, Talent::Hidden);
+    assert!(import_team("Pikachu\nAbility: Intimidate").unwrap_err().contains("不会有特性"));
+
+    let exported = ShowdownSet::from_pokemon(&pokemon).to_text();
+    assert!(exported.contains("Ability: Lightning Rod"));
+    assert_eq!(import_team(&exported).unwrap()[0].talent, Talent::Hidden);
+}